@@ -0,0 +1,142 @@
+//! Converting `ValUnc`s to and from Arrow `StructArray`s
+//!
+//! A `ValUnc<f64, U>` is stored as a `Float64` column for the value followed by one `Float64`
+//! column per uncertainty component, e.g. `val,unc_stat,unc_sys`, so that columnar tools can
+//! read it without knowing about this crate. The uncertainty types flatten to their components
+//! through [`UncComponents`].
+//!
+//! ```
+//! use val_unc::{arrow, Unc, ValUnc};
+//!
+//! let v = vec![
+//!     ValUnc::new(18.7, (Unc(5.0), Unc(2.5))),
+//!     ValUnc::new(10.2, (Unc(4.0), Unc(1.0))),
+//! ];
+//! let array = arrow::to_struct_array(&v, &["stat", "sys"]).unwrap();
+//!
+//! assert_eq!(array.column_names(), vec!["val", "unc_stat", "unc_sys"]);
+//! assert_eq!(arrow::from_struct_array(&array).unwrap(), v);
+//! ```
+
+use crate::{traits::UncComponents, val_unc::ValUnc};
+use arrow_array::{Array, ArrayRef, Float64Array, StructArray};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use std::sync::Arc;
+
+/// Returns the fields of a `ValUnc<f64, U>` with uncertainty components labeled `labels`, e.g.
+/// `val,unc_stat,unc_sys`.
+///
+/// With no labels, a single component is named `unc`, and several are numbered, e.g.
+/// `unc_0,unc_1`. Otherwise, there must be a label for every component.
+pub fn fields<U>(labels: &[&str]) -> Result<Fields, ArrowError>
+where
+    U: UncComponents,
+{
+    let names: Vec<String> = match (labels.len(), U::LEN) {
+        (0, 1) => vec!["unc".to_string()],
+        (0, n) => (0..n).map(|i| format!("unc_{}", i)).collect(),
+        (n, len) if n == len => labels.iter().map(|l| format!("unc_{}", l)).collect(),
+        (n, len) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected {} uncertainty labels, got {}",
+                len, n
+            )))
+        }
+    };
+    Ok(std::iter::once("val".to_string())
+        .chain(names)
+        .map(|name| Field::new(name, DataType::Float64, false))
+        .collect())
+}
+
+/// Converts `values` to a `StructArray`, with the fields given by [`fields`].
+pub fn to_struct_array<U>(
+    values: &[ValUnc<f64, U>],
+    labels: &[&str],
+) -> Result<StructArray, ArrowError>
+where
+    U: UncComponents,
+{
+    let fields = fields::<U>(labels)?;
+    let mut columns = vec![Vec::with_capacity(values.len()); 1 + U::LEN];
+    let mut components = Vec::with_capacity(U::LEN);
+    for v in values {
+        components.clear();
+        v.unc.push_components(&mut components);
+        columns[0].push(v.val);
+        for (column, c) in columns[1..].iter_mut().zip(&components) {
+            column.push(*c);
+        }
+    }
+    let columns = columns
+        .into_iter()
+        .map(|c| Arc::new(Float64Array::from(c)) as ArrayRef)
+        .collect();
+    StructArray::try_new(fields, columns, None)
+}
+
+/// Converts a `StructArray` to `ValUnc`s.
+///
+/// The columns are read by position, so the field names don't matter, but they must be
+/// non-null `Float64` columns, one for the value and then one per uncertainty component.
+pub fn from_struct_array<U>(array: &StructArray) -> Result<Vec<ValUnc<f64, U>>, ArrowError>
+where
+    U: UncComponents,
+{
+    if array.num_columns() != 1 + U::LEN {
+        return Err(ArrowError::SchemaError(format!(
+            "expected {} columns, got {}",
+            1 + U::LEN,
+            array.num_columns()
+        )));
+    }
+    let columns = array
+        .columns()
+        .iter()
+        .map(|c| match c.as_any().downcast_ref::<Float64Array>() {
+            Some(c) if c.null_count() == 0 => Ok(c),
+            Some(_) => Err(ArrowError::InvalidArgumentError(
+                "null values aren't supported".to_string(),
+            )),
+            None => Err(ArrowError::CastError(format!(
+                "expected a Float64 column, got {}",
+                c.data_type()
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((0..array.len())
+        .map(|i| {
+            let unc = U::from_components(&mut columns[1..].iter().map(|c| c.value(i)))
+                .expect("the number of columns was checked");
+            ValUnc::new(columns[0].value(i), unc)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unc::Unc;
+
+    #[test]
+    fn round_trip() {
+        let v = vec![
+            ValUnc::new(1.0, [Unc(0.1), Unc(0.2)]),
+            ValUnc::new(2.0, [Unc(0.3), Unc(0.4)]),
+        ];
+        let array = to_struct_array(&v, &[]).unwrap();
+
+        assert_eq!(array.column_names(), vec!["val", "unc_0", "unc_1"]);
+        assert_eq!(from_struct_array(&array).unwrap(), v);
+    }
+
+    #[test]
+    fn errors() {
+        let v = vec![ValUnc::new(1.0, Unc(0.1))];
+        assert!(to_struct_array(&v, &["a", "b"]).is_err());
+
+        let array = to_struct_array(&v, &[]).unwrap();
+        assert!(from_struct_array::<(Unc<f64>, Unc<f64>)>(&array).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+//! Records of how results were obtained, e.g. for quality assurance audits
+//!
+//! With the `diagnostics` feature, a [`Certificate`] also records the first invalid operation on
+//! the thread (see `diagnostics::report`), so that a result computed from invalid intermediate
+//! values can't be certified silently.
+
+use crate::val_unc::ValUnc;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A record of a result, the inputs and method it was obtained from, and when and with which
+/// version of this crate it was obtained
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Certificate<V, U> {
+    pub result: ValUnc<V, U>,
+    /// The named inputs
+    pub inputs: Vec<(String, ValUnc<V, U>)>,
+    /// A description of how the result was obtained from the inputs
+    pub method: String,
+    /// The version of this crate
+    pub version: String,
+    /// The time of certification, in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// The first invalid operation on this thread at the time of certification, if any (see
+    /// [`diagnostics::report`](crate::diagnostics::report))
+    #[cfg(feature = "diagnostics")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub diagnostic: Option<String>,
+}
+
+/// Creates a [`Certificate`] for `result`.
+///
+/// ```
+/// use val_unc::{audit, Unc, ValUnc};
+///
+/// let a = ValUnc::new(1.0, Unc(0.1));
+/// let b = ValUnc::new(2.0, Unc(0.2));
+/// let cert = audit::certify(&(a + b), &[("a", &a), ("b", &b)], "sum");
+///
+/// assert_eq!(cert.inputs[1], ("b".to_string(), b));
+/// assert_eq!(cert.version, env!("CARGO_PKG_VERSION"));
+/// ```
+pub fn certify<V, U>(
+    result: &ValUnc<V, U>,
+    inputs: &[(&str, &ValUnc<V, U>)],
+    method: &str,
+) -> Certificate<V, U>
+where
+    V: Clone,
+    U: Clone,
+{
+    Certificate {
+        result: result.clone(),
+        inputs: inputs
+            .iter()
+            .map(|(name, input)| (name.to_string(), (*input).clone()))
+            .collect(),
+        method: method.to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        // The clock is only before the epoch if it is badly misconfigured
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        #[cfg(feature = "diagnostics")]
+        diagnostic: crate::diagnostics::report().map(|r| r.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unc;
+
+    #[test]
+    fn certificate() {
+        let a = ValUnc::new(1.0, Unc(0.3));
+        let b = ValUnc::new(2.0, Unc(0.4));
+        let cert = certify(&(a + b), &[("a", &a), ("b", &b)], "a + b");
+
+        assert_eq!(cert.result, ValUnc::new(3.0, Unc(0.5)));
+        assert_eq!(
+            cert.inputs,
+            vec![("a".to_string(), a), ("b".to_string(), b)]
+        );
+        assert_eq!(cert.method, "a + b");
+        assert!(cert.timestamp > 0);
+    }
+
+    #[test]
+    #[cfg(feature = "diagnostics")]
+    fn diagnostics() {
+        use crate::diagnostics::{self, Traced};
+
+        diagnostics::clear();
+        let a = ValUnc::new(1.0, Unc(0.1));
+        assert_eq!(certify(&a, &[("a", &a)], "a").diagnostic, None);
+
+        let b = ValUnc::new(2.0, Unc(f64::NAN));
+        let Traced(c) = Traced(a) + Traced(b);
+        let cert = certify(&c, &[("a", &a), ("b", &b)], "a + b");
+        assert_eq!(
+            cert.diagnostic,
+            Some(diagnostics::report().unwrap().to_string())
+        );
+        diagnostics::clear();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let a = ValUnc::new(1.0, Unc(0.1));
+        let cert = certify(&a, &[("a", &a)], "a");
+        let json = serde_json::to_string(&cert).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Certificate<_, _>>(&json).unwrap(),
+            cert
+        );
+    }
+}
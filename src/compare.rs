@@ -0,0 +1,109 @@
+//! Comparisons that take the uncertainties into account
+
+use crate::{traits::*, val_unc::ValUnc};
+
+/// Statistical comparison of two measurements
+pub trait UncCompare {
+    type Val;
+
+    /// Returns the difference between `self` and `other`, in units of their combined
+    /// uncertainty.
+    fn sigma_from(&self, other: &Self) -> Self::Val;
+
+    /// Returns `true` if `self` and `other` differ by at most `n_sigma` combined uncertainties.
+    fn is_compatible_with(&self, other: &Self, n_sigma: Self::Val) -> bool;
+}
+
+impl<V, U> ValUnc<V, U>
+where
+    V: Float,
+    U: UncTotal<V>,
+{
+    /// Returns the combined total uncertainty of `self` and `other`, assuming they are
+    /// independent.
+    fn combined_unc(&self, other: &Self) -> V {
+        self.total_unc().hypot(other.total_unc())
+    }
+
+    /// Returns `true` if the values agree within the combined uncertainties.
+    ///
+    /// This is `approx_eq_within(other, 1)`.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_within(other, V::one())
+    }
+
+    /// Returns `true` if the values agree within `k` times the combined uncertainties.
+    ///
+    /// ```
+    /// use val_unc::{Unc, ValUnc};
+    ///
+    /// let a = ValUnc::new(10.0, Unc(0.3));
+    /// let b = ValUnc::new(10.6, Unc(0.4));
+    ///
+    /// assert!(!a.approx_eq(&b));
+    /// assert!(a.approx_eq_within(&b, 2.0));
+    /// ```
+    pub fn approx_eq_within(&self, other: &Self, k: V) -> bool {
+        (self.val - other.val).abs() <= k * self.combined_unc(other)
+    }
+}
+
+impl<V, U> UncCompare for ValUnc<V, U>
+where
+    V: Float,
+    U: UncTotal<V>,
+{
+    type Val = V;
+
+    /// ```
+    /// use val_unc::{Unc, UncCompare, ValUnc};
+    ///
+    /// let a = ValUnc::new(10.0, Unc(0.3));
+    /// let b = ValUnc::new(11.0, Unc(0.4));
+    ///
+    /// assert!(f64::abs(a.sigma_from(&b) + 2.0) <= f64::EPSILON);
+    /// assert!(a.is_compatible_with(&b, 3.0));
+    /// ```
+    fn sigma_from(&self, other: &Self) -> V {
+        (self.val - other.val) / self.combined_unc(other)
+    }
+
+    fn is_compatible_with(&self, other: &Self, n_sigma: V) -> bool {
+        self.approx_eq_within(other, n_sigma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Unc, ZeroUnc};
+
+    #[test]
+    fn approx_eq() {
+        let a = ValUnc::new(1.0, (Unc(0.3), Unc(0.4)));
+        let b = ValUnc::new(1.5, (Unc(0.0), Unc(0.0)));
+        let c = ValUnc::new(1.6, (Unc(0.0), Unc(0.0)));
+
+        assert!(a.approx_eq(&a));
+        assert!(a.approx_eq(&b));
+        assert!(!a.approx_eq(&c));
+        assert!(a.approx_eq_within(&c, 2.0));
+
+        // Without uncertainties, this is equality
+        assert!(ValUnc::new(1.0, ZeroUnc).approx_eq(&ValUnc::new(1.0, ZeroUnc)));
+        assert!(!ValUnc::new(1.0, ZeroUnc).approx_eq(&ValUnc::new(1.0 + 1e-12, ZeroUnc)));
+    }
+
+    #[test]
+    fn sigma_from() {
+        let a = ValUnc::new(1.0, (Unc(0.3), Unc(0.4)));
+        let b = ValUnc::new(2.5, (Unc(0.0), Unc(0.0)));
+
+        assert!(f64::abs(a.sigma_from(&b) + 3.0) <= 1e-12);
+        assert!(f64::abs(b.sigma_from(&a) - 3.0) <= 1e-12);
+        assert!(!a.is_compatible_with(&b, 2.0));
+        assert!(a.is_compatible_with(&b, 3.5));
+        // Identical values are compatible, even without uncertainties
+        assert!(ValUnc::new(1.0, ZeroUnc).is_compatible_with(&ValUnc::new(1.0, ZeroUnc), 1.0));
+    }
+}
@@ -0,0 +1,149 @@
+//! Reading and writing `ValUnc`s as flat CSV columns
+//!
+//! A `ValUnc` is written as one column for the value followed by one column per uncertainty
+//! component, e.g. `energy,energy_unc_stat,energy_unc_sys`. The `csv` crate can't write headers
+//! for nested fields, so the header is written by [`writer`] and the records are (de)serialized
+//! by position. The `ValUnc` fields must use [`serde_repr::fixed`](crate::serde_repr::fixed), so
+//! that every record has the same columns.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use val_unc::{csv, Unc, ValUnc};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Row {
+//!     run: u32,
+//!     #[serde(with = "val_unc::serde_repr::fixed")]
+//!     energy: ValUnc<f64, (Unc<f64>, Unc<f64>)>,
+//! }
+//!
+//! let mut header = vec!["run".to_string()];
+//! header.extend(csv::columns::<(Unc<f64>, Unc<f64>)>("energy", &["stat", "sys"]).unwrap());
+//!
+//! let mut w = csv::writer(vec![], &header).unwrap();
+//! let row = Row { run: 1, energy: ValUnc::new(18.7, (Unc(5.0), Unc(2.5))) };
+//! w.serialize(&row).unwrap();
+//! let data = w.into_inner().unwrap();
+//!
+//! assert_eq!(
+//!     String::from_utf8(data.clone()).unwrap(),
+//!     "run,energy,energy_unc_stat,energy_unc_sys\n1,18.7,5.0,2.5\n"
+//! );
+//!
+//! let mut r = ::csv::Reader::from_reader(&data[..]);
+//! let rows = csv::deserialize::<_, Row>(&mut r).collect::<Result<Vec<_>, _>>().unwrap();
+//! assert_eq!(rows, vec![row]);
+//! ```
+
+use crate::traits::UncComponents;
+use serde::de::DeserializeOwned;
+use std::{error, fmt, io};
+
+/// The number of labels given to [`columns`] doesn't match the number of uncertainty components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LabelsError {
+    /// The number of uncertainty components
+    pub expected: usize,
+    /// The number of labels
+    pub got: usize,
+}
+
+impl fmt::Display for LabelsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} uncertainty labels, got {}",
+            self.expected, self.got
+        )
+    }
+}
+
+impl error::Error for LabelsError {}
+
+/// Returns the column names of a `ValUnc<f64, U>` named `name` with uncertainty components
+/// labeled `labels`, e.g. `energy,energy_unc_stat,energy_unc_sys`.
+///
+/// With no labels, a single component is named like `energy_unc`, and several are numbered, e.g.
+/// `energy_unc_0,energy_unc_1`. Otherwise, there must be a label for every component (see
+/// [`UncComponents`]).
+pub fn columns<U>(name: &str, labels: &[&str]) -> Result<Vec<String>, LabelsError>
+where
+    U: UncComponents,
+{
+    let suffixes: Vec<String> = match (labels.len(), U::LEN) {
+        (0, 1) => vec![String::new()],
+        (0, n) => (0..n).map(|i| format!("_{}", i)).collect(),
+        (n, len) if n == len => labels.iter().map(|l| format!("_{}", l)).collect(),
+        (got, expected) => return Err(LabelsError { expected, got }),
+    };
+    Ok(std::iter::once(name.to_string())
+        .chain(suffixes.iter().map(|s| format!("{}_unc{}", name, s)))
+        .collect())
+}
+
+/// Creates a CSV writer that has written `header`, and writes records by position.
+pub fn writer<W, I, T>(w: W, header: I) -> ::csv::Result<::csv::Writer<W>>
+where
+    W: io::Write,
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let mut writer = ::csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(w);
+    writer.write_record(header)?;
+    Ok(writer)
+}
+
+/// Deserializes the records of `reader` by position, ignoring the header.
+pub fn deserialize<R, T>(
+    reader: &mut ::csv::Reader<R>,
+) -> impl Iterator<Item = ::csv::Result<T>> + '_
+where
+    R: io::Read,
+    T: DeserializeOwned,
+{
+    reader.records().map(|record| record?.deserialize(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::unc::Unc;
+
+    #[test]
+    fn column_names() {
+        assert_eq!(columns::<Unc<f64>>("x", &[]).unwrap(), vec!["x", "x_unc"]);
+        assert_eq!(
+            columns::<[Unc<f64>; 2]>("x", &[]).unwrap(),
+            vec!["x", "x_unc_0", "x_unc_1"]
+        );
+    }
+
+    #[test]
+    fn labels() {
+        type U = (Unc<f64>, Unc<f64>);
+        assert_eq!(
+            columns::<U>("x", &["a", "b"]).unwrap(),
+            vec!["x", "x_unc_a", "x_unc_b"]
+        );
+        assert_eq!(
+            columns::<Unc<f64>>("x", &["a"]).unwrap(),
+            vec!["x", "x_unc_a"]
+        );
+        assert_eq!(
+            columns::<U>("x", &["a"]),
+            Err(LabelsError {
+                expected: 2,
+                got: 1
+            })
+        );
+        assert_eq!(
+            columns::<Unc<f64>>("x", &["a", "b"])
+                .unwrap_err()
+                .to_string(),
+            "expected 1 uncertainty labels, got 2"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! [`BigFloat`], a wrapper around `FBig`, an arbitrary precision float from [`dashu_float`], that
+//! implements [`Real`], and implementations of [`Sqrt`] and [`Pow`] for
+//! `ValUnc<BigFloat, Unc<BigFloat>>`
+//!
+//! With [`Real`], the bundled uncertainty types, e.g. `Unc<BigFloat>`, propagate like those of
+//! `f64`, but are calculated at the precision of the operands. As with any `FBig`, the precision
+//! must be limited, e.g. with `with_precision`, for the square roots to be calculated.
+//!
+//! ```
+//! use core::convert::TryFrom;
+//! use dashu_float::{ops::Abs, FBig};
+//! use val_unc::{BigFloat, Unc, ValUnc};
+//!
+//! let big = |x: f64| {
+//!     BigFloat(
+//!         FBig::<dashu_float::round::mode::HalfEven>::try_from(x)
+//!             .unwrap()
+//!             .with_precision(256)
+//!             .value(),
+//!     )
+//! };
+//!
+//! let x = ValUnc::new(big(1.0), Unc(big(3.0e-30)));
+//! let y = ValUnc::new(big(1.0e-20), Unc(big(4.0e-30)));
+//! let z = &x + &y;
+//!
+//! // Both the value and the uncertainty are beyond the precision of an `f64`
+//! assert_eq!(z.val - x.val, y.val);
+//! assert!((z.unc.0 - big(5.0e-30)).0.abs() < big(1.0e-40).0);
+//! ```
+
+use crate::{
+    traits::{Pow, Real, Signed, Sqrt},
+    unc::Unc,
+    val_unc::ValUnc,
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+use dashu_float::{
+    ops::{Abs, SquareRoot},
+    round::{mode, Round},
+    FBig, Word,
+};
+use num_traits::{FromPrimitive, Num, One, ToPrimitive, Zero};
+
+/// An `FBig` that implements [`Real`]
+///
+/// `Real` is implemented for every [`Float`](crate::traits::Float), so it can't also be
+/// implemented for `FBig`, which `dashu_float` could make a `Float` in the future. The wrapper
+/// implements the arithmetic and `num_traits` traits of `FBig` that `Real` requires.
+pub struct BigFloat<R: Round = mode::Zero, const B: Word = 2>(pub FBig<R, B>);
+
+// These are implemented by hand, since deriving them would require them of the rounding mode.
+impl<R: Round, const B: Word> Clone for BigFloat<R, B> {
+    fn clone(&self) -> Self {
+        BigFloat(self.0.clone())
+    }
+}
+
+impl<R: Round, const B: Word> fmt::Debug for BigFloat<R, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BigFloat").field(&self.0).finish()
+    }
+}
+
+impl<R: Round, const B: Word> Default for BigFloat<R, B> {
+    fn default() -> Self {
+        BigFloat(FBig::default())
+    }
+}
+
+impl<R: Round, const B: Word> PartialEq for BigFloat<R, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<R: Round, const B: Word> Eq for BigFloat<R, B> {}
+
+impl<R: Round, const B: Word> PartialOrd for BigFloat<R, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R: Round, const B: Word> Ord for BigFloat<R, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<R: Round, const B: Word> From<FBig<R, B>> for BigFloat<R, B> {
+    fn from(x: FBig<R, B>) -> Self {
+        BigFloat(x)
+    }
+}
+
+impl<R: Round, const B: Word> fmt::Display for BigFloat<R, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+// This implements the arithmetic operators in terms of those of `FBig`.
+macro_rules! big_float_ops {
+    ($(($Tr:ident, $f:ident)),+) => {$(
+        impl<R: Round, const B: Word> $Tr for BigFloat<R, B> {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self {
+                BigFloat(self.0.$f(other.0))
+            }
+        }
+    )+}
+}
+
+big_float_ops!((Add, add), (Div, div), (Mul, mul), (Rem, rem), (Sub, sub));
+
+impl<R: Round, const B: Word> Neg for BigFloat<R, B> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        BigFloat(-self.0)
+    }
+}
+
+impl<R: Round, const B: Word> Zero for BigFloat<R, B> {
+    fn zero() -> Self {
+        BigFloat(FBig::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(&self.0)
+    }
+}
+
+impl<R: Round, const B: Word> One for BigFloat<R, B> {
+    fn one() -> Self {
+        BigFloat(FBig::ONE)
+    }
+}
+
+impl<R: Round, const B: Word> Num for BigFloat<R, B> {
+    type FromStrRadixErr = <FBig<R, B> as Num>::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        FBig::from_str_radix(s, radix).map(BigFloat)
+    }
+}
+
+impl<R: Round, const B: Word> Signed for BigFloat<R, B> {
+    fn abs(&self) -> Self {
+        BigFloat(Abs::abs(self.0.clone()))
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        BigFloat(Signed::abs_sub(&self.0, &other.0))
+    }
+
+    fn signum(&self) -> Self {
+        BigFloat(Signed::signum(&self.0))
+    }
+
+    fn is_positive(&self) -> bool {
+        Signed::is_positive(&self.0)
+    }
+
+    fn is_negative(&self) -> bool {
+        Signed::is_negative(&self.0)
+    }
+}
+
+impl<R: Round, const B: Word> FromPrimitive for BigFloat<R, B> {
+    fn from_i64(n: i64) -> Option<Self> {
+        FBig::from_i64(n).map(BigFloat)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        FBig::from_u64(n).map(BigFloat)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        FBig::from_f64(n).map(BigFloat)
+    }
+}
+
+impl<R: Round, const B: Word> ToPrimitive for BigFloat<R, B> {
+    fn to_i64(&self) -> Option<i64> {
+        ToPrimitive::to_i64(&self.0)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        ToPrimitive::to_u64(&self.0)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        ToPrimitive::to_f64(&self.0)
+    }
+}
+
+/// A `BigFloat` is never NaN.
+impl<R: Round, const B: Word> Real for BigFloat<R, B> {
+    fn sqrt(self) -> Self {
+        BigFloat(SquareRoot::sqrt(&self.0))
+    }
+
+    fn powi(self, n: i32) -> Self {
+        BigFloat(FBig::powi(&self.0, n.into()))
+    }
+
+    fn abs(self) -> Self {
+        BigFloat(Abs::abs(self.0))
+    }
+
+    fn round(self) -> Self {
+        BigFloat(FBig::round(&self.0))
+    }
+
+    fn infinity() -> Self {
+        BigFloat(FBig::INFINITY)
+    }
+
+    fn is_nan(&self) -> bool {
+        false
+    }
+
+    fn is_finite(&self) -> bool {
+        !self.0.repr().is_infinite()
+    }
+}
+
+impl<R: Round, const B: Word> Sqrt for ValUnc<BigFloat<R, B>, Unc<BigFloat<R, B>>> {
+    fn sqrt(self) -> Self {
+        let val = Real::sqrt(self.val);
+        let unc = self.unc.0 .0 / (&val.0 * FBig::from(2));
+        ValUnc::new(val, Unc(BigFloat(unc)))
+    }
+}
+
+impl<R: Round, const B: Word> Pow<i32> for ValUnc<BigFloat<R, B>, Unc<BigFloat<R, B>>> {
+    type Output = Self;
+
+    fn pow(self, n: i32) -> Self {
+        let val = Real::powi(self.val.clone(), n);
+        let unc = if n == 0 {
+            FBig::ZERO
+        } else {
+            Abs::abs(self.unc.0 .0 * FBig::from(n) * FBig::powi(&self.val.0, (n - 1).into()))
+        };
+        ValUnc::new(val, Unc(BigFloat(unc)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResolutionUnc;
+    use core::convert::TryFrom;
+    use dashu_float::round::mode::HalfEven;
+
+    type F = BigFloat<HalfEven>;
+
+    fn big(x: f64) -> F {
+        BigFloat(FBig::try_from(x).unwrap().with_precision(256).value())
+    }
+
+    fn assert_close(a: &F, b: &F) {
+        let eps = big(2.0f64.powi(-240));
+        assert!(
+            Abs::abs(&a.0 - &b.0) <= &eps.0 * Abs::abs(b.0.clone()),
+            "{} != {}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn ops() {
+        let x = ValUnc::new(big(3.0), Unc(big(0.75)));
+        let y = ValUnc::new(big(4.0), Unc(big(1.0)));
+
+        assert_close(&(&x + &y).unc.0, &big(1.25));
+        assert_close(&(&x - &y).unc.0, &big(1.25));
+        assert_close(&(&x * &y).unc.0, &(big(3.0) * Real::sqrt(big(2.0))));
+        assert_close(&(&x / &y).unc.0, &(big(0.1875) * Real::sqrt(big(2.0))));
+        assert_eq!((-&x).unc, x.unc);
+
+        // The other bundled uncertainty types work with `BigFloat` too
+        assert_close(&ResolutionUnc::new(big(2.0)).resolution(), &big(2.0));
+    }
+
+    #[test]
+    fn sqrt_pow() {
+        let two = ValUnc::new(big(2.0), Unc(big(1.0e-40)));
+
+        let root = two.clone().sqrt();
+        assert_close(&(root.val.clone() * root.val), &big(2.0));
+        assert_close(
+            &root.unc.0,
+            &(big(1.0e-40) / (big(2.0) * Real::sqrt(big(2.0)))),
+        );
+
+        let cube = two.clone().pow(3);
+        assert_eq!(cube.val, big(8.0));
+        assert_close(&cube.unc.0, &(big(12.0) * big(1.0e-40)));
+        assert!(Zero::is_zero(&two.pow(0).unc.0));
+    }
+}
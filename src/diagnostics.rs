@@ -0,0 +1,180 @@
+//! Diagnostics for invalid results of operations
+//!
+//! The operators of [`Traced`], a wrapper around a `ValUnc`, check whether their result has a NaN
+//! value or an invalid uncertainty (see [`UncValidate`]). The first offending operation on each
+//! thread is recorded, along with its operands, and can be retrieved with [`report`]. The
+//! operators of `ValUnc` itself aren't checked, so only the computations that opt in pay for the
+//! checks and require the value and uncertainty types to implement [`DiagnosticVal`] and
+//! [`DiagnosticUnc`].
+//!
+//! ```
+//! use val_unc::{diagnostics::{self, Traced}, Unc, ValUnc};
+//!
+//! let a = Traced(ValUnc::new(1.0, Unc(0.1)));
+//! let b = Traced(ValUnc::new(2.0, Unc(f64::NAN)));
+//!
+//! let _ = (a + b) * a;
+//! assert_eq!(diagnostics::report().unwrap().op, "add");
+//! ```
+
+use crate::{traits::*, unc::Unc, val_unc::ValUnc};
+use std::{
+    cell::RefCell,
+    fmt::{self, Debug},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// The requirements on a value type for it to be checked
+pub trait DiagnosticVal: Debug + Clone + PartialEq {}
+impl<T> DiagnosticVal for T where T: Debug + Clone + PartialEq {}
+
+/// The requirements on an uncertainty type for it to be checked
+pub trait DiagnosticUnc: Debug + Clone + UncValidate {}
+impl<T> DiagnosticUnc for T where T: Debug + Clone + UncValidate {}
+
+/// The first operation that produced an invalid result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The name of the operation, e.g. `"add"`
+    pub op: &'static str,
+    /// The `Debug` representation of the operands
+    pub operands: Vec<String>,
+    /// The `Debug` representation of the result
+    pub result: String,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} gave {}",
+            self.op,
+            self.operands.join(" and "),
+            self.result
+        )
+    }
+}
+
+thread_local! {
+    static REPORT: RefCell<Option<Report>> = const { RefCell::new(None) };
+}
+
+/// Returns the first operation on this thread that produced an invalid result, if any.
+pub fn report() -> Option<Report> {
+    REPORT.with(|r| r.borrow().clone())
+}
+
+/// Clears the report for this thread, so that the next invalid result is recorded.
+pub fn clear() {
+    REPORT.with(|r| *r.borrow_mut() = None)
+}
+
+/// Records the operation if `result` is invalid and nothing has been recorded yet.
+fn check<V, U>(op: &'static str, operands: &[&ValUnc<V, U>], result: &ValUnc<V, U>)
+where
+    V: DiagnosticVal,
+    U: DiagnosticUnc,
+{
+    // A NaN value is the only one that is not equal to itself
+    #[allow(clippy::eq_op)]
+    let valid = result.val == result.val && result.unc.is_valid();
+    if valid {
+        return;
+    }
+
+    REPORT.with(|r| {
+        let mut r = r.borrow_mut();
+        if r.is_none() {
+            *r = Some(Report {
+                op,
+                operands: operands.iter().map(|o| format!("{:?}", o)).collect(),
+                result: format!("{:?}", result),
+            });
+        }
+    })
+}
+
+/// A `ValUnc` whose operations are checked for invalid results
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Traced<V, U = Unc<V>>(pub ValUnc<V, U>);
+
+impl<V, U> From<ValUnc<V, U>> for Traced<V, U> {
+    fn from(v: ValUnc<V, U>) -> Self {
+        Traced(v)
+    }
+}
+
+// This implements the checked binary operators in terms of those of `ValUnc`.
+macro_rules! traced_ops {
+    ($(($Tr:ident, $f:ident)),+) => {$(
+        impl<V, U> $Tr for Traced<V, U>
+        where
+            ValUnc<V, U>: $Tr<Output = ValUnc<V, U>>,
+            V: DiagnosticVal,
+            U: DiagnosticUnc,
+        {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self {
+                let result = self.0.clone().$f(other.0.clone());
+                check(stringify!($f), &[&self.0, &other.0], &result);
+                Traced(result)
+            }
+        }
+    )+}
+}
+
+traced_ops!((Add, add), (Div, div), (Mul, mul), (Sub, sub));
+
+impl<V, U> Neg for Traced<V, U>
+where
+    ValUnc<V, U>: Neg<Output = ValUnc<V, U>>,
+    V: DiagnosticVal,
+    U: DiagnosticUnc,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let result = self.0.clone().neg();
+        check("neg", &[&self.0], &result);
+        Traced(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_first() {
+        clear();
+        let v1 = Traced(ValUnc::new(1.0, Unc(0.1)));
+        let v2 = Traced(ValUnc::new(2.0, Unc(f64::NAN)));
+
+        let _ = v1 + v1;
+        let _ = -v1;
+        assert_eq!(report(), None);
+
+        // The NaN uncertainty of v2 makes the result invalid
+        let bad = v1 * v2;
+        let _ = bad + v1;
+        let r = report().unwrap();
+        assert_eq!(r.op, "mul");
+        assert_eq!(r.operands.len(), 2);
+        assert_eq!(r.operands[1], format!("{:?}", v2.0));
+        assert!(r.to_string().starts_with("mul of ValUnc"));
+
+        clear();
+        assert_eq!(report(), None);
+    }
+
+    #[test]
+    fn untraced() {
+        clear();
+        // The operators of `ValUnc` aren't checked
+        let _ = ValUnc::new(1.0, Unc(0.1)) * ValUnc::new(2.0, Unc(f64::NAN));
+        assert_eq!(report(), None);
+    }
+}
@@ -0,0 +1,161 @@
+//! Checks for numerically unsound propagation
+//!
+//! The `checked_*` methods of [`ValUnc`] do the same as its operators, and also return a
+//! [`DimsWarning`] if
+//!
+//! - a relative uncertainty formula, e.g. for multiplying [`Unc`]s, is applied to a zero value,
+//!   since it divides by it, or
+//! - an addition or subtraction cancels catastrophically, i.e. the magnitude of the result is
+//!   nonzero but much smaller than those of the operands (see [`CANCELLATION`]), since the
+//!   relative uncertainty of the result blows up. An exactly zero result, e.g. of `x - x`, isn't
+//!   a cancellation.
+//!
+//! The operators themselves aren't checked, so only the computations that opt in require the
+//! value type to implement [`ToPrimitive`].
+//!
+//! [`Unc`]: crate::Unc
+//!
+//! ```
+//! use val_unc::{dims::DimsWarning, Unc, ValUnc};
+//!
+//! let a = ValUnc::new(1e10, Unc(0.1));
+//! let b = ValUnc::new(1e10 - 1e-3, Unc(0.1));
+//!
+//! assert_eq!(a.checked_sub(b).1, Some(DimsWarning::Cancellation));
+//! assert_eq!(a.checked_sub(a).1, None);
+//! ```
+
+use crate::val_unc::ValUnc;
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Sub},
+};
+use num_traits::ToPrimitive;
+
+/// The ratio of the magnitude of the result of an addition or subtraction to the largest
+/// magnitude of its operands, below which it is considered a catastrophic cancellation
+pub const CANCELLATION: f64 = 1e-8;
+
+/// The kind of numerically unsound propagation found by a `checked_*` method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DimsWarning {
+    /// A relative uncertainty formula was applied to a zero value.
+    ZeroValue,
+    /// An addition or subtraction cancelled catastrophically.
+    Cancellation,
+}
+
+impl fmt::Display for DimsWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DimsWarning::ZeroValue => write!(f, "relative uncertainty of a zero value"),
+            DimsWarning::Cancellation => write!(f, "catastrophic cancellation"),
+        }
+    }
+}
+
+/// Returns a warning if either of `a` and `b` is zero.
+fn zero_value<V>(a: &V, b: &V) -> Option<DimsWarning>
+where
+    V: ToPrimitive,
+{
+    if a.to_f64() == Some(0.0) || b.to_f64() == Some(0.0) {
+        Some(DimsWarning::ZeroValue)
+    } else {
+        None
+    }
+}
+
+/// Returns a warning if `result = a ± b` is a catastrophic cancellation.
+fn cancellation(a: Option<f64>, b: Option<f64>, result: Option<f64>) -> Option<DimsWarning> {
+    let (a, b, result) = (a?, b?, result?);
+    let scale = f64::max(a.abs(), b.abs());
+    if result != 0.0 && result.abs() <= CANCELLATION * scale {
+        Some(DimsWarning::Cancellation)
+    } else {
+        None
+    }
+}
+
+impl<V, U> ValUnc<V, U>
+where
+    V: ToPrimitive,
+{
+    /// Adds `other` to `self`, and checks for a catastrophic cancellation.
+    pub fn checked_add(self, other: Self) -> (Self, Option<DimsWarning>)
+    where
+        Self: Add<Output = Self>,
+    {
+        let (a, b) = (self.val.to_f64(), other.val.to_f64());
+        let result = self + other;
+        let warning = cancellation(a, b, result.val.to_f64());
+        (result, warning)
+    }
+
+    /// Subtracts `other` from `self`, and checks for a catastrophic cancellation.
+    pub fn checked_sub(self, other: Self) -> (Self, Option<DimsWarning>)
+    where
+        Self: Sub<Output = Self>,
+    {
+        let (a, b) = (self.val.to_f64(), other.val.to_f64());
+        let result = self - other;
+        let warning = cancellation(a, b, result.val.to_f64());
+        (result, warning)
+    }
+
+    /// Multiplies `self` by `other`, and checks that neither value is zero.
+    pub fn checked_mul(self, other: Self) -> (Self, Option<DimsWarning>)
+    where
+        Self: Mul<Output = Self>,
+    {
+        let warning = zero_value(&self.val, &other.val);
+        (self * other, warning)
+    }
+
+    /// Divides `self` by `other`, and checks that neither value is zero.
+    pub fn checked_div(self, other: Self) -> (Self, Option<DimsWarning>)
+    where
+        Self: Div<Output = Self>,
+    {
+        let warning = zero_value(&self.val, &other.val);
+        (self / other, warning)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unc;
+
+    #[test]
+    fn zero() {
+        let (v, w) = ValUnc::new(0.0, Unc(0.1_f64)).checked_mul(ValUnc::new(2.0, Unc(0.1)));
+        assert_eq!(w, Some(DimsWarning::ZeroValue));
+        // The relative uncertainty of the zero value is infinite
+        assert!(v.unc.0.is_nan());
+
+        let (_, w) = ValUnc::new(1.0, Unc(0.1)).checked_div(ValUnc::new(0.0, Unc(0.1)));
+        assert_eq!(w, Some(DimsWarning::ZeroValue));
+    }
+
+    #[test]
+    fn cancellation() {
+        let a = ValUnc::new(1e10, Unc(0.1));
+        let b = ValUnc::new(1e10 - 1e-3, Unc(0.1));
+        assert_eq!(a.checked_sub(b).1, Some(DimsWarning::Cancellation));
+        assert_eq!(a.checked_add(-b).1, Some(DimsWarning::Cancellation));
+
+        // An exactly zero result isn't a cancellation
+        assert_eq!(a.checked_sub(a).1, None);
+    }
+
+    #[test]
+    fn ok() {
+        let (a, b) = (ValUnc::new(1.0, Unc(0.1)), ValUnc::new(0.5, Unc(0.1)));
+        assert_eq!(a.checked_mul(b), (a * b, None));
+        assert_eq!(a.checked_div(b), (a / b, None));
+        assert_eq!(a.checked_sub(b), (a - b, None));
+        let zero = ValUnc::new(0.0, Unc(0.1));
+        assert_eq!(zero.checked_add(zero), (zero + zero, None));
+    }
+}
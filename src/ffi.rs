@@ -0,0 +1,127 @@
+//! Exchanging `ValUnc`s with C
+//!
+//! [`ValUncC`] has the C layout of
+//!
+//! ```c
+//! typedef struct ValUncC {
+//!     double val;
+//!     double unc;
+//! } ValUncC;
+//! ```
+//!
+//! and converts to and from a `ValUnc<f64, Unc<f64>>` with [`ValUnc::to_ffi`] and
+//! [`ValUnc::from_ffi`]. The `val_unc_*` functions do the arithmetic of [`Unc`] on them, so that
+//! C and C++ code can propagate uncertainties without reimplementing it. The types and functions
+//! only use C types, so headers can be generated with [cbindgen].
+//!
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+//!
+//! ```
+//! use val_unc::{ffi::{val_unc_add, ValUncC}, Unc, ValUnc};
+//!
+//! let a = ValUnc::new(1.0, Unc(0.3)).to_ffi();
+//! let b = ValUncC { val: 2.0, unc: 0.4 };
+//!
+//! assert_eq!(ValUnc::from_ffi(val_unc_add(a, b)), ValUnc::new(3.0, Unc(0.5)));
+//! ```
+
+use crate::{unc::Unc, val_unc::ValUnc};
+
+/// A `ValUnc<f64, Unc<f64>>` with the C layout
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct ValUncC {
+    pub val: f64,
+    pub unc: f64,
+}
+
+impl ValUnc<f64, Unc<f64>> {
+    /// Converts `self` into a [`ValUncC`].
+    pub fn to_ffi(&self) -> ValUncC {
+        ValUncC {
+            val: self.val,
+            unc: self.unc.0,
+        }
+    }
+
+    /// Converts a [`ValUncC`] into a `ValUnc`.
+    pub fn from_ffi(v: ValUncC) -> Self {
+        ValUnc::new(v.val, Unc(v.unc))
+    }
+}
+
+impl From<ValUnc<f64, Unc<f64>>> for ValUncC {
+    fn from(v: ValUnc<f64, Unc<f64>>) -> Self {
+        v.to_ffi()
+    }
+}
+
+impl From<ValUncC> for ValUnc<f64, Unc<f64>> {
+    fn from(v: ValUncC) -> Self {
+        ValUnc::from_ffi(v)
+    }
+}
+
+/// Adds `a` and `b`.
+#[no_mangle]
+pub extern "C" fn val_unc_add(a: ValUncC, b: ValUncC) -> ValUncC {
+    (ValUnc::from_ffi(a) + ValUnc::from_ffi(b)).to_ffi()
+}
+
+/// Subtracts `b` from `a`.
+#[no_mangle]
+pub extern "C" fn val_unc_sub(a: ValUncC, b: ValUncC) -> ValUncC {
+    (ValUnc::from_ffi(a) - ValUnc::from_ffi(b)).to_ffi()
+}
+
+/// Multiplies `a` by `b`.
+#[no_mangle]
+pub extern "C" fn val_unc_mul(a: ValUncC, b: ValUncC) -> ValUncC {
+    (ValUnc::from_ffi(a) * ValUnc::from_ffi(b)).to_ffi()
+}
+
+/// Divides `a` by `b`.
+#[no_mangle]
+pub extern "C" fn val_unc_div(a: ValUncC, b: ValUncC) -> ValUncC {
+    (ValUnc::from_ffi(a) / ValUnc::from_ffi(b)).to_ffi()
+}
+
+/// Negates `a`.
+#[no_mangle]
+pub extern "C" fn val_unc_neg(a: ValUncC) -> ValUncC {
+    (-ValUnc::from_ffi(a)).to_ffi()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<ValUncC>(), 2 * size_of::<f64>());
+        assert_eq!(align_of::<ValUncC>(), align_of::<f64>());
+
+        let v = ValUnc::new(1.5, Unc(0.25));
+        assert_eq!(
+            v.to_ffi(),
+            ValUncC {
+                val: 1.5,
+                unc: 0.25
+            }
+        );
+        assert_eq!(ValUnc::from(ValUncC::from(v)), v);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (ValUnc::new(3.0, Unc(0.3)), ValUnc::new(2.0, Unc(0.4)));
+        let (ac, bc) = (a.to_ffi(), b.to_ffi());
+
+        assert_eq!(val_unc_add(ac, bc), (a + b).to_ffi());
+        assert_eq!(val_unc_sub(ac, bc), (a - b).to_ffi());
+        assert_eq!(val_unc_mul(ac, bc), (a * b).to_ffi());
+        assert_eq!(val_unc_div(ac, bc), (a / b).to_ffi());
+        assert_eq!(val_unc_neg(ac), (-a).to_ffi());
+    }
+}
@@ -0,0 +1,595 @@
+//! Fitting models to measured points, weighted by their uncertainties
+//!
+//! [`linear`] fits a straight line to points with exact `x`s. [`york`] and [`york_correlated`]
+//! fit a straight line to points with uncertainties in both coordinates. With the `alloc`
+//! feature, [`polynomial`] fits a polynomial, and [`least_squares`] fits any model with
+//! parameters.
+//!
+//! ```
+//! use val_unc::{fit, Unc, ValUnc};
+//!
+//! let xs = [0.0, 1.0, 2.0, 3.0];
+//! let ys = [1.1, 2.9, 5.1, 6.9].map(|y| ValUnc::new(y, Unc(0.1)));
+//! let line = fit::linear(&xs, &ys).unwrap();
+//!
+//! assert!(f64::abs(line.slope.val - 1.96) <= 1e-12);
+//! assert!(f64::abs(line.intercept.val - 1.06) <= 1e-12);
+//! ```
+
+use crate::{traits::UncWeight, unc::Unc, val_unc::ValUnc};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A straight line `y = slope x + intercept` fit to measured points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearFit {
+    pub slope: ValUnc<f64>,
+    pub intercept: ValUnc<f64>,
+    /// The covariance of the slope and the intercept
+    pub cov: f64,
+    /// The χ² of the points with respect to the line
+    pub chi2: f64,
+    /// The number of degrees of freedom, two less than the number of points
+    pub ndf: usize,
+}
+
+impl LinearFit {
+    /// The line at `x`, with the uncertainty from the slope and the intercept, including their
+    /// covariance.
+    pub fn eval(&self, x: f64) -> ValUnc<f64> {
+        let var =
+            (x * self.slope.unc.0).powi(2) + self.intercept.unc.0.powi(2) + 2.0 * x * self.cov;
+        ValUnc::new(
+            self.slope.val * x + self.intercept.val,
+            Unc(var.max(0.0).sqrt()),
+        )
+    }
+}
+
+/// Fits a straight line to the points `(xs[i], ys[i])` by weighted least squares, with the
+/// weights of the `ys` according to [`UncWeight`], or returns `None` if there are fewer than two
+/// distinct `xs`.
+///
+/// The `xs` are exact. The uncertainties of the slope and the intercept are from the
+/// uncertainties of the `ys` alone, and aren't scaled by the χ².
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn linear<U>(xs: &[f64], ys: &[ValUnc<f64, U>]) -> Option<LinearFit>
+where
+    U: UncWeight<f64>,
+{
+    assert_eq!(xs.len(), ys.len(), "the lengths are different");
+    let points = || {
+        xs.iter()
+            .zip(ys)
+            .map(|(&x, y)| (x, y.val, y.unc.weight(y.val)))
+    };
+
+    // The sums are taken about the weighted mean of the xs, to avoid cancellation
+    let (sum_w, sum_wx) = points().fold((0.0, 0.0), |(s, sx), (x, _, w)| (s + w, sx + w * x));
+    let x_mean = sum_wx / sum_w;
+    let (stt, sty, sy) = points().fold((0.0, 0.0, 0.0), |(stt, sty, sy), (x, y, w)| {
+        let t = x - x_mean;
+        (stt + w * t * t, sty + w * t * y, sy + w * y)
+    });
+    // This is also true for NaN, e.g. with no points
+    if stt.is_nan() || stt <= 0.0 {
+        return None;
+    }
+
+    let slope = sty / stt;
+    let intercept = (sy - sum_wx * slope) / sum_w;
+    let chi2 = points()
+        .map(|(x, y, w)| w * (y - slope * x - intercept).powi(2))
+        .sum();
+    Some(LinearFit {
+        slope: ValUnc::new(slope, Unc(f64::sqrt(1.0 / stt))),
+        intercept: ValUnc::new(
+            intercept,
+            Unc(f64::sqrt(1.0 / sum_w + x_mean.powi(2) / stt)),
+        ),
+        cov: -x_mean / stt,
+        chi2,
+        ndf: xs.len() - 2,
+    })
+}
+
+/// Fits a straight line to the points `(xs[i], ys[i])`, where both coordinates have
+/// uncertainties, with York's method, or returns `None` if there are fewer than two distinct
+/// `xs`.
+///
+/// The slope is found iteratively, starting from the fit with exact `xs`. The coordinates are
+/// weighted according to [`UncWeight`], so all of the uncertainties must be nonzero. If the `xs`
+/// are exact, use [`linear`] instead. The uncertainties of the slope and the intercept are those
+/// of York et al. (2004), and aren't scaled by the χ², which is the sum of the weighted squared
+/// distances of the points from the line.
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn york<Ux, Uy>(xs: &[ValUnc<f64, Ux>], ys: &[ValUnc<f64, Uy>]) -> Option<LinearFit>
+where
+    Ux: UncWeight<f64>,
+    Uy: UncWeight<f64>,
+{
+    assert_eq!(xs.len(), ys.len(), "the lengths are different");
+    york_impl(xs, ys, |_| 0.0)
+}
+
+/// Like [`york`], with `r[i]` the correlation coefficient of the uncertainties of `xs[i]` and
+/// `ys[i]`.
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn york_correlated<Ux, Uy>(
+    xs: &[ValUnc<f64, Ux>],
+    ys: &[ValUnc<f64, Uy>],
+    r: &[f64],
+) -> Option<LinearFit>
+where
+    Ux: UncWeight<f64>,
+    Uy: UncWeight<f64>,
+{
+    assert_eq!(xs.len(), ys.len(), "the lengths are different");
+    assert_eq!(xs.len(), r.len(), "the lengths are different");
+    york_impl(xs, ys, |i| r[i])
+}
+
+/// The iteration of York et al. (2004), "Unified equations for the slope, intercept, and standard
+/// errors of the best straight line", with `r(i)` the correlation of point `i`.
+fn york_impl<Ux, Uy, R>(xs: &[ValUnc<f64, Ux>], ys: &[ValUnc<f64, Uy>], r: R) -> Option<LinearFit>
+where
+    Ux: UncWeight<f64>,
+    Uy: UncWeight<f64>,
+    R: Fn(usize) -> f64,
+{
+    const MAX_ITER: usize = 100;
+
+    let points = || {
+        xs.iter().zip(ys).enumerate().map(|(i, (x, y))| {
+            let (wx, wy) = (x.unc.weight(x.val), y.unc.weight(y.val));
+            (x.val, y.val, wx, wy, r(i) * f64::sqrt(wx * wy))
+        })
+    };
+    // The weight of each point for the slope `b`, with `ra` the correlation over the product of
+    // the uncertainties
+    let weight = |b: f64, wx: f64, wy: f64, ra: f64| wx * wy / (wx + b * b * wy - 2.0 * b * ra);
+    // The weighted means of the coordinates for the slope `b`
+    let means = |b: f64| {
+        let (sw, swx, swy) =
+            points().fold((0.0, 0.0, 0.0), |(sw, swx, swy), (x, y, wx, wy, ra)| {
+                let w = weight(b, wx, wy, ra);
+                (sw + w, swx + w * x, swy + w * y)
+            });
+        (sw, swx / sw, swy / sw)
+    };
+    // The deviation of the adjusted x of each point from `x_mean`, for the slope `b`
+    let beta = |b: f64, (x, y, wx, wy, ra): (f64, f64, f64, f64, f64), x_mean: f64, y_mean: f64| {
+        let (u, v) = (x - x_mean, y - y_mean);
+        weight(b, wx, wy, ra) * (u / wy + b * v / wx - (b * u + v) * ra / (wx * wy))
+    };
+
+    // Start from the fit with exact xs
+    let (sw, swx) = points().fold((0.0, 0.0), |(sw, swx), p| (sw + p.3, swx + p.3 * p.0));
+    let (stt, sty) = points().fold((0.0, 0.0), |(stt, sty), (x, y, _, wy, _)| {
+        let t = x - swx / sw;
+        (stt + wy * t * t, sty + wy * t * y)
+    });
+    // This is also true for NaN, e.g. with no points
+    if stt.is_nan() || stt <= 0.0 {
+        return None;
+    }
+    let mut b = sty / stt;
+    for _ in 0..MAX_ITER {
+        let (_, x_mean, y_mean) = means(b);
+        let (num, denom) = points().fold((0.0, 0.0), |(num, denom), p| {
+            let w = weight(b, p.2, p.3, p.4);
+            let beta = beta(b, p, x_mean, y_mean);
+            (
+                num + w * beta * (p.1 - y_mean),
+                denom + w * beta * (p.0 - x_mean),
+            )
+        });
+        let next = num / denom;
+        if !next.is_finite() {
+            return None;
+        }
+        let done = (next - b).abs() <= 1e-15 * next.abs();
+        b = next;
+        if done {
+            break;
+        }
+    }
+
+    let (sw, x_mean, y_mean) = means(b);
+    let a = y_mean - b * x_mean;
+    // The adjusted xs are `x_mean + beta`, and their weighted mean is `x_mean + beta_mean`
+    let beta_mean = points()
+        .map(|p| weight(b, p.2, p.3, p.4) * beta(b, p, x_mean, y_mean))
+        .sum::<f64>()
+        / sw;
+    let suu = points()
+        .map(|p| weight(b, p.2, p.3, p.4) * (beta(b, p, x_mean, y_mean) - beta_mean).powi(2))
+        .sum::<f64>();
+    let adjusted_mean = x_mean + beta_mean;
+    let var_b = 1.0 / suu;
+    let chi2 = points()
+        .map(|(x, y, wx, wy, ra)| weight(b, wx, wy, ra) * (y - b * x - a).powi(2))
+        .sum();
+    Some(LinearFit {
+        slope: ValUnc::new(b, Unc(var_b.sqrt())),
+        intercept: ValUnc::new(a, Unc(f64::sqrt(1.0 / sw + adjusted_mean.powi(2) * var_b))),
+        cov: -adjusted_mean * var_b,
+        chi2,
+        ndf: xs.len() - 2,
+    })
+}
+
+/// Parameters fit to measured points, returned by [`polynomial`] and [`least_squares`]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit {
+    /// The parameters, with their uncertainties from the uncertainties of the points
+    pub params: Vec<ValUnc<f64>>,
+    /// The covariance matrix of the parameters, row by row
+    pub cov: Vec<Vec<f64>>,
+    /// The χ² of the points with respect to the model
+    pub chi2: f64,
+    /// The number of degrees of freedom, the number of points minus the number of parameters
+    pub ndf: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl Fit {
+    /// Creates a `Fit` from the parameters and their covariance.
+    fn new(params: Vec<f64>, cov: Vec<Vec<f64>>, chi2: f64, n: usize) -> Self {
+        let params = params
+            .iter()
+            .zip(&cov)
+            .enumerate()
+            .map(|(i, (&p, row))| ValUnc::new(p, Unc(row[i].sqrt())))
+            .collect();
+        Self {
+            ndf: n.saturating_sub(cov.len()),
+            params,
+            cov,
+            chi2,
+        }
+    }
+}
+
+/// The normal matrix `JᵀJ` and the vector `Jᵀr` of the rows `(j, r)`.
+#[cfg(feature = "alloc")]
+fn normal_equations<I>(rows: I, p: usize) -> (Vec<Vec<f64>>, Vec<f64>)
+where
+    I: IntoIterator<Item = (Vec<f64>, f64)>,
+{
+    let mut n = vec![vec![0.0; p]; p];
+    let mut v = vec![0.0; p];
+    for (j, r) in rows {
+        for (k, jk) in j.iter().enumerate() {
+            v[k] += jk * r;
+            for (l, jl) in j.iter().enumerate() {
+                n[k][l] += jk * jl;
+            }
+        }
+    }
+    (n, v)
+}
+
+/// Inverts the matrix `m` by Gauss–Jordan elimination with partial pivoting, or returns `None` if
+/// it's singular.
+#[cfg(feature = "alloc")]
+fn invert(mut m: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let p = m.len();
+    let mut inv: Vec<Vec<f64>> = (0..p)
+        .map(|i| (0..p).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    for col in 0..p {
+        let pivot = (col..p).max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))?;
+        if m[pivot][col] == 0.0 || m[pivot][col].is_nan() {
+            return None;
+        }
+        m.swap(col, pivot);
+        inv.swap(col, pivot);
+        let d = m[col][col];
+        for k in 0..p {
+            m[col][k] /= d;
+            inv[col][k] /= d;
+        }
+        for row in 0..p {
+            if row != col {
+                let f = m[row][col];
+                for k in 0..p {
+                    m[row][k] -= f * m[col][k];
+                    inv[row][k] -= f * inv[col][k];
+                }
+            }
+        }
+    }
+    Some(inv)
+}
+
+/// The product of the matrix `m` and the vector `v`.
+#[cfg(feature = "alloc")]
+fn mul(m: &[Vec<f64>], v: &[f64]) -> Vec<f64> {
+    m.iter()
+        .map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+/// Fits the polynomial `Σ c[k] x^k`, `k = 0..=degree`, to the points `(xs[i], ys[i])` by
+/// weighted least squares, with the weights of the `ys` according to [`UncWeight`], or returns
+/// `None` if there are fewer distinct `xs` than coefficients.
+///
+/// The coefficients are in order of increasing power. The `xs` are exact. The normal equations
+/// are solved directly, which is accurate for the low degrees typical of calibration curves.
+///
+/// ```
+/// use val_unc::{fit, Unc, ValUnc};
+///
+/// let xs = [-1.0, 0.0, 1.0, 2.0];
+/// let ys = [2.0, 1.0, 2.0, 5.0].map(|y| ValUnc::new(y, Unc(0.1)));
+/// let fit = fit::polynomial(&xs, &ys, 2).unwrap();
+///
+/// // y = 1 + x²
+/// assert!(f64::abs(fit.params[0].val - 1.0) <= 1e-12);
+/// assert!(f64::abs(fit.params[1].val) <= 1e-12);
+/// assert!(f64::abs(fit.params[2].val - 1.0) <= 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+#[cfg(feature = "alloc")]
+pub fn polynomial<U>(xs: &[f64], ys: &[ValUnc<f64, U>], degree: usize) -> Option<Fit>
+where
+    U: UncWeight<f64>,
+{
+    assert_eq!(xs.len(), ys.len(), "the lengths are different");
+    let p = degree + 1;
+    let mut distinct = xs.to_vec();
+    distinct.sort_by(f64::total_cmp);
+    distinct.dedup();
+    if distinct.len() < p {
+        return None;
+    }
+    let row = |x: f64, w: f64| -> Vec<f64> {
+        let s = w.sqrt();
+        (0..p)
+            .scan(s, |power, _| {
+                let term = *power;
+                *power *= x;
+                Some(term)
+            })
+            .collect()
+    };
+    let rows = xs.iter().zip(ys).map(|(&x, y)| {
+        let w = y.unc.weight(y.val);
+        (row(x, w), w.sqrt() * y.val)
+    });
+    let (n, v) = normal_equations(rows, p);
+    let cov = invert(n)?;
+    let c = mul(&cov, &v);
+    let chi2 = xs
+        .iter()
+        .zip(ys)
+        .map(|(&x, y)| {
+            let f = c.iter().rev().fold(0.0, |f, c| f * x + c);
+            y.unc.weight(y.val) * (y.val - f).powi(2)
+        })
+        .sum();
+    Some(Fit::new(c, cov, chi2, xs.len()))
+}
+
+/// Fits the parameters of `model(x, params)` to the points `(xs[i], ys[i])` by weighted least
+/// squares, with the weights of the `ys` according to [`UncWeight`], starting from `params0`, or
+/// returns `None` if the parameters aren't determined by the points.
+///
+/// The χ² is minimized with the Levenberg–Marquardt algorithm, with the derivatives of the
+/// model found by central differences. The `xs` are exact. Like any nonlinear fit, this finds a
+/// local minimum, so `params0` should be reasonably close to the answer.
+///
+/// ```
+/// use val_unc::{fit, Unc, ValUnc};
+///
+/// let decay = |t: f64, p: &[f64]| p[0] * f64::exp(-t / p[1]);
+/// let ts = [0.0, 1.0, 2.0, 3.0, 4.0];
+/// let ys = ts.map(|t| ValUnc::new(decay(t, &[10.0, 2.0]), Unc(0.1)));
+/// let fit = fit::least_squares(decay, &[5.0, 1.0], &ts, &ys).unwrap();
+///
+/// assert!(f64::abs(fit.params[0].val - 10.0) <= 1e-6);
+/// assert!(f64::abs(fit.params[1].val - 2.0) <= 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the lengths of `xs` and `ys` are different.
+#[cfg(feature = "alloc")]
+pub fn least_squares<F, U>(
+    model: F,
+    params0: &[f64],
+    xs: &[f64],
+    ys: &[ValUnc<f64, U>],
+) -> Option<Fit>
+where
+    F: Fn(f64, &[f64]) -> f64,
+    U: UncWeight<f64>,
+{
+    const MAX_ITER: usize = 1000;
+
+    assert_eq!(xs.len(), ys.len(), "the lengths are different");
+    let ws: Vec<f64> = ys.iter().map(|y| y.unc.weight(y.val).sqrt()).collect();
+    let chi2 = |params: &[f64]| -> f64 {
+        xs.iter()
+            .zip(ys)
+            .zip(&ws)
+            .map(|((&x, y), w)| (w * (y.val - model(x, params))).powi(2))
+            .sum()
+    };
+    // The rows of the weighted Jacobian and the weighted residuals
+    let rows = |params: &[f64]| -> Vec<(Vec<f64>, f64)> {
+        let mut shifted = params.to_vec();
+        xs.iter()
+            .zip(ys)
+            .zip(&ws)
+            .map(|((&x, y), w)| {
+                let j = (0..params.len())
+                    .map(|k| {
+                        let h = 6e-6 * params[k].abs().max(1.0);
+                        shifted[k] = params[k] + h;
+                        let up = model(x, &shifted);
+                        shifted[k] = params[k] - h;
+                        let down = model(x, &shifted);
+                        shifted[k] = params[k];
+                        w * (up - down) / (2.0 * h)
+                    })
+                    .collect();
+                (j, w * (y.val - model(x, params)))
+            })
+            .collect()
+    };
+
+    let p = params0.len();
+    let mut params = params0.to_vec();
+    let mut current = chi2(&params);
+    let mut lambda = 1e-3;
+    for _ in 0..MAX_ITER {
+        let (n, v) = normal_equations(rows(&params), p);
+        // Damp the diagonal until a step reduces the χ²
+        let step = loop {
+            let mut damped = n.clone();
+            for (k, row) in damped.iter_mut().enumerate() {
+                row[k] += lambda * n[k][k];
+            }
+            let delta = mul(&invert(damped)?, &v);
+            let next: Vec<f64> = params.iter().zip(delta).map(|(a, d)| a + d).collect();
+            let next_chi2 = chi2(&next);
+            if next_chi2 <= current {
+                lambda = (lambda / 10.0).max(1e-12);
+                break Some((next, next_chi2));
+            }
+            lambda *= 10.0;
+            if lambda > 1e12 {
+                break None;
+            }
+        };
+        match step {
+            Some((next, next_chi2)) => {
+                let done = current - next_chi2 <= 1e-12 * current.max(f64::MIN_POSITIVE);
+                params = next;
+                current = next_chi2;
+                if done {
+                    break;
+                }
+            }
+            // No step reduces the χ², so this is a minimum
+            None => break,
+        }
+    }
+
+    let (n, _) = normal_equations(rows(&params), p);
+    let cov = invert(n)?;
+    Some(Fit::new(params, cov, current, xs.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unc::RelUnc;
+
+    #[test]
+    fn line() {
+        let xs = [0.0, 1.0, 2.0];
+        let ys = [1.0, 3.0, 5.0].map(|y| ValUnc::new(y, Unc(1.0)));
+        let fit = linear(&xs, &ys).unwrap();
+
+        // With unit weights, the sums are 3 and Σ(x - 1)² = 2
+        assert!(f64::abs(fit.slope.val - 2.0) <= 1e-12);
+        assert!(f64::abs(fit.intercept.val - 1.0) <= 1e-12);
+        assert!(f64::abs(fit.slope.unc.0 - f64::sqrt(0.5)) <= 1e-12);
+        assert!(f64::abs(fit.intercept.unc.0 - f64::sqrt(5.0 / 6.0)) <= 1e-12);
+        assert!(f64::abs(fit.cov + 0.5) <= 1e-12);
+        assert!(fit.chi2 <= 1e-20);
+        assert_eq!(fit.ndf, 1);
+
+        // At the weighted mean of the xs, the variance is 1 / Σw
+        assert!(f64::abs(fit.eval(1.0).unc.0 - f64::sqrt(1.0 / 3.0)) <= 1e-12);
+        assert!(f64::abs(fit.eval(0.0).unc.0 - fit.intercept.unc.0) <= 1e-12);
+    }
+
+    #[test]
+    fn degenerate() {
+        let ys = [ValUnc::new(1.0, RelUnc(0.1)), ValUnc::new(2.0, RelUnc(0.1))];
+
+        assert_eq!(linear(&[1.0, 1.0], &ys), None);
+        assert_eq!(linear(&[1.0], &ys[..1]), None);
+        assert!(linear(&[1.0, 2.0], &ys).is_some());
+    }
+
+    #[test]
+    fn york_pearson() {
+        // Pearson's data with York's weights, from York et al. (2004)
+        let x = [0.0, 0.9, 1.8, 2.6, 3.3, 4.4, 5.2, 6.1, 6.5, 7.4];
+        let wx = [
+            1000.0, 1000.0, 500.0, 800.0, 200.0, 80.0, 60.0, 20.0, 1.8, 1.0,
+        ];
+        let y = [5.9, 5.4, 4.4, 4.6, 3.5, 3.7, 2.8, 2.8, 2.4, 1.5];
+        let wy = [1.0, 1.8, 4.0, 8.0, 20.0, 20.0, 70.0, 70.0, 100.0, 500.0];
+        let points = |v: [f64; 10], w: [f64; 10]| {
+            let mut p = [ValUnc::new(0.0, Unc(0.0)); 10];
+            for i in 0..10 {
+                p[i] = ValUnc::new(v[i], Unc(1.0 / w[i].sqrt()));
+            }
+            p
+        };
+        let (xs, ys) = (points(x, wx), points(y, wy));
+        let fit = york(&xs, &ys).unwrap();
+
+        assert!(f64::abs(fit.slope.val + 0.480_533_4) <= 1e-7);
+        assert!(f64::abs(fit.intercept.val - 5.479_910_2) <= 1e-7);
+        assert!(f64::abs(fit.slope.unc.0 - 0.0580) <= 1e-4);
+        assert!(f64::abs(fit.intercept.unc.0 - 0.2950) <= 1e-4);
+        assert_eq!(fit.ndf, 8);
+        assert_eq!(york_correlated(&xs, &ys, &[0.0; 10]), Some(fit));
+
+        // Nearly exact xs give the ordinary weighted fit
+        let xs = points(x, [1e12; 10]);
+        let exact = linear(&x, &ys).unwrap();
+        let fit = york(&xs, &ys).unwrap();
+        assert!(f64::abs(fit.slope.val - exact.slope.val) <= 1e-9);
+        assert!(f64::abs(fit.slope.unc.0 - exact.slope.unc.0) <= 1e-9);
+        assert!(f64::abs(fit.intercept.unc.0 - exact.intercept.unc.0) <= 1e-9);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn polynomial_and_general() {
+        let xs = [0.0, 1.0, 2.0];
+        let ys = [1.0, 3.0, 5.5].map(|y| ValUnc::new(y, Unc(1.0)));
+
+        // A polynomial of degree 1 is a line
+        let line = linear(&xs, &ys).unwrap();
+        let fit = polynomial(&xs, &ys, 1).unwrap();
+        assert!(f64::abs(fit.params[0].val - line.intercept.val) <= 1e-12);
+        assert!(f64::abs(fit.params[1].val - line.slope.val) <= 1e-12);
+        assert!(f64::abs(fit.params[1].unc.0 - line.slope.unc.0) <= 1e-12);
+        assert!(f64::abs(fit.cov[0][1] - line.cov) <= 1e-12);
+        assert!(f64::abs(fit.chi2 - line.chi2) <= 1e-12);
+        assert_eq!(fit.ndf, 1);
+
+        // So is a general model
+        let fit = least_squares(|x, p| p[0] + p[1] * x, &[0.0, 0.0], &xs, &ys).unwrap();
+        assert!(f64::abs(fit.params[0].val - line.intercept.val) <= 1e-8);
+        assert!(f64::abs(fit.params[1].val - line.slope.val) <= 1e-8);
+        assert!(f64::abs(fit.params[1].unc.0 - line.slope.unc.0) <= 1e-8);
+        assert!(f64::abs(fit.cov[1][0] - line.cov) <= 1e-8);
+
+        assert_eq!(polynomial(&xs, &ys, 3), None);
+    }
+}
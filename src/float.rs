@@ -0,0 +1,431 @@
+//! Implementations of the `num-traits` numeric traits for `ValUnc<f32, Unc<f32>>` and
+//! `ValUnc<f64, Unc<f64>>`, so that they can be used with generic numeric code
+//!
+//! Functions propagate the uncertainty to first order, i.e. `σ_f = |f'(x)| σ_x`, and functions of
+//! several arguments assume that the arguments are independent. Consequently, piecewise constant
+//! functions, e.g. `floor` and `signum`, have no uncertainty, and comparisons and classifications,
+//! e.g. `max` and `is_nan`, only consider the value.
+//!
+//! Like the arithmetic operators, each function treats its arguments as independent, so generic
+//! code that uses a quantity more than once, e.g. `x * x` instead of `x.powi(2)`, doesn't account
+//! for the correlation.
+//!
+//! Other uncertainty types only get [`Zero`] and [`One`], since these traits need a single notion
+//! of propagation through arbitrary functions.
+
+use crate::{
+    traits::{Float, Signed},
+    unc::Unc,
+    val_unc::ValUnc,
+};
+use core::{num::FpCategory, ops::Rem};
+use num_traits::{Num, NumCast, ParseFloatError, ToPrimitive};
+
+macro_rules! float_impl {
+    ($($T:ident),+) => {$(
+        impl ValUnc<$T, Unc<$T>> {
+            /// Returns the uncertainty `u` propagated with derivative `d`.
+            ///
+            /// An exact operand doesn't contribute, even if the derivative isn't finite, e.g. of
+            /// `sqrt` at 0.
+            fn propagated(d: $T, u: $T) -> $T {
+                if u == 0.0 {
+                    0.0
+                } else {
+                    (d * u).abs()
+                }
+            }
+
+            /// Returns `val` with the uncertainty propagated from `self` with derivative `d`.
+            fn unary(self, val: $T, d: $T) -> Self {
+                ValUnc::new(val, Unc(Self::propagated(d, self.unc.0)))
+            }
+
+            /// Returns `val` with the uncertainties propagated from `self` and `other` with
+            /// derivatives `d` and `d_other`.
+            fn binary(self, other: Self, val: $T, d: $T, d_other: $T) -> Self {
+                let u = Self::propagated(d, self.unc.0);
+                ValUnc::new(val, Unc(u.hypot(Self::propagated(d_other, other.unc.0))))
+            }
+
+            fn exact(val: $T) -> Self {
+                ValUnc::new(val, Unc(0.0))
+            }
+        }
+
+        /// The remainder, `x - y * trunc(x / y)`, where `trunc(x / y)` is constant.
+        impl Rem for ValUnc<$T, Unc<$T>> {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self {
+                let q = (self.val / other.val).trunc();
+                self.binary(other, self.val % other.val, 1.0, -q)
+            }
+        }
+
+        impl Num for ValUnc<$T, Unc<$T>> {
+            type FromStrRadixErr = ParseFloatError;
+
+            /// Parses the value, with no uncertainty.
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFloatError> {
+                <$T as Num>::from_str_radix(s, radix).map(Self::exact)
+            }
+        }
+
+        impl Signed for ValUnc<$T, Unc<$T>> {
+            fn abs(&self) -> Self {
+                Float::abs(*self)
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                if self.val <= other.val {
+                    Self::exact(0.0)
+                } else {
+                    *self - *other
+                }
+            }
+
+            fn signum(&self) -> Self {
+                Float::signum(*self)
+            }
+
+            fn is_positive(&self) -> bool {
+                self.val.is_sign_positive()
+            }
+
+            fn is_negative(&self) -> bool {
+                self.val.is_sign_negative()
+            }
+        }
+
+        impl ToPrimitive for ValUnc<$T, Unc<$T>> {
+            fn to_i64(&self) -> Option<i64> {
+                self.val.to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.val.to_u64()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                self.val.to_f64()
+            }
+        }
+
+        impl NumCast for ValUnc<$T, Unc<$T>> {
+            fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+                <$T as NumCast>::from(n).map(Self::exact)
+            }
+        }
+
+        impl Float for ValUnc<$T, Unc<$T>> {
+            fn nan() -> Self {
+                Self::exact($T::NAN)
+            }
+
+            fn infinity() -> Self {
+                Self::exact($T::INFINITY)
+            }
+
+            fn neg_infinity() -> Self {
+                Self::exact($T::NEG_INFINITY)
+            }
+
+            fn neg_zero() -> Self {
+                Self::exact(-0.0)
+            }
+
+            fn min_value() -> Self {
+                Self::exact($T::MIN)
+            }
+
+            fn min_positive_value() -> Self {
+                Self::exact($T::MIN_POSITIVE)
+            }
+
+            fn max_value() -> Self {
+                Self::exact($T::MAX)
+            }
+
+            fn is_nan(self) -> bool {
+                self.val.is_nan()
+            }
+
+            fn is_infinite(self) -> bool {
+                self.val.is_infinite()
+            }
+
+            fn is_finite(self) -> bool {
+                self.val.is_finite()
+            }
+
+            fn is_normal(self) -> bool {
+                self.val.is_normal()
+            }
+
+            fn classify(self) -> FpCategory {
+                self.val.classify()
+            }
+
+            fn floor(self) -> Self {
+                Self::exact(self.val.floor())
+            }
+
+            fn ceil(self) -> Self {
+                Self::exact(self.val.ceil())
+            }
+
+            fn round(self) -> Self {
+                Self::exact(self.val.round())
+            }
+
+            fn trunc(self) -> Self {
+                Self::exact(self.val.trunc())
+            }
+
+            fn fract(self) -> Self {
+                self.unary(self.val.fract(), 1.0)
+            }
+
+            fn abs(self) -> Self {
+                self.unary(self.val.abs(), 1.0)
+            }
+
+            fn signum(self) -> Self {
+                Self::exact(self.val.signum())
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.val.is_sign_positive()
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.val.is_sign_negative()
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                let val = self.val.mul_add(a.val, b.val);
+                let ValUnc { val, unc } = self.binary(a, val, a.val, self.val);
+                ValUnc::new(val, Unc(unc.0.hypot(b.unc.0)))
+            }
+
+            fn recip(self) -> Self {
+                self.unary(self.val.recip(), -self.val.powi(-2))
+            }
+
+            fn powi(self, n: i32) -> Self {
+                // The derivative would be 0·∞ at 0
+                if n == 0 {
+                    return Self::exact(1.0);
+                }
+                self.unary(self.val.powi(n), n as $T * self.val.powi(n - 1))
+            }
+
+            fn powf(self, n: Self) -> Self {
+                let val = self.val.powf(n.val);
+                let d = if n.val == 0.0 {
+                    0.0
+                } else {
+                    n.val * self.val.powf(n.val - 1.0)
+                };
+                self.binary(n, val, d, val * self.val.ln())
+            }
+
+            fn sqrt(self) -> Self {
+                let val = self.val.sqrt();
+                self.unary(val, 0.5 / val)
+            }
+
+            fn exp(self) -> Self {
+                let val = self.val.exp();
+                self.unary(val, val)
+            }
+
+            fn exp2(self) -> Self {
+                let val = self.val.exp2();
+                self.unary(val, val * core::$T::consts::LN_2)
+            }
+
+            fn ln(self) -> Self {
+                self.unary(self.val.ln(), self.val.recip())
+            }
+
+            fn log(self, base: Self) -> Self {
+                let ln_b = base.val.ln();
+                let val = self.val.log(base.val);
+                self.binary(base, val, (self.val * ln_b).recip(), -val / (base.val * ln_b))
+            }
+
+            fn log2(self) -> Self {
+                self.unary(self.val.log2(), (self.val * core::$T::consts::LN_2).recip())
+            }
+
+            fn log10(self) -> Self {
+                self.unary(self.val.log10(), (self.val * core::$T::consts::LN_10).recip())
+            }
+
+            fn max(self, other: Self) -> Self {
+                if other.val > self.val {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            fn min(self, other: Self) -> Self {
+                if other.val < self.val {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            fn abs_sub(self, other: Self) -> Self {
+                Signed::abs_sub(&self, &other)
+            }
+
+            fn cbrt(self) -> Self {
+                let val = self.val.cbrt();
+                self.unary(val, (3.0 * val * val).recip())
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                let val = self.val.hypot(other.val);
+                self.binary(other, val, self.val / val, other.val / val)
+            }
+
+            fn sin(self) -> Self {
+                self.unary(self.val.sin(), self.val.cos())
+            }
+
+            fn cos(self) -> Self {
+                self.unary(self.val.cos(), self.val.sin())
+            }
+
+            fn tan(self) -> Self {
+                self.unary(self.val.tan(), self.val.cos().powi(-2))
+            }
+
+            fn asin(self) -> Self {
+                self.unary(self.val.asin(), (1.0 - self.val * self.val).sqrt().recip())
+            }
+
+            fn acos(self) -> Self {
+                self.unary(self.val.acos(), (1.0 - self.val * self.val).sqrt().recip())
+            }
+
+            fn atan(self) -> Self {
+                self.unary(self.val.atan(), (1.0 + self.val * self.val).recip())
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                let r2 = self.val * self.val + other.val * other.val;
+                self.binary(other, self.val.atan2(other.val), other.val / r2, -self.val / r2)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                (Float::sin(self), Float::cos(self))
+            }
+
+            fn exp_m1(self) -> Self {
+                self.unary(self.val.exp_m1(), self.val.exp())
+            }
+
+            fn ln_1p(self) -> Self {
+                self.unary(self.val.ln_1p(), (1.0 + self.val).recip())
+            }
+
+            fn sinh(self) -> Self {
+                self.unary(self.val.sinh(), self.val.cosh())
+            }
+
+            fn cosh(self) -> Self {
+                self.unary(self.val.cosh(), self.val.sinh())
+            }
+
+            fn tanh(self) -> Self {
+                self.unary(self.val.tanh(), self.val.cosh().powi(-2))
+            }
+
+            fn asinh(self) -> Self {
+                self.unary(self.val.asinh(), (self.val * self.val + 1.0).sqrt().recip())
+            }
+
+            fn acosh(self) -> Self {
+                self.unary(self.val.acosh(), (self.val * self.val - 1.0).sqrt().recip())
+            }
+
+            fn atanh(self) -> Self {
+                self.unary(self.val.atanh(), (1.0 - self.val * self.val).recip())
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                Float::integer_decode(self.val)
+            }
+        }
+    )+}
+}
+
+float_impl!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A generic function, which knows nothing about uncertainties
+    fn double_sqrt<T: Float>(x: T) -> T {
+        x.sqrt() * T::from(2).unwrap()
+    }
+
+    #[test]
+    fn generic() {
+        let x = ValUnc::new(4.0, Unc(0.1));
+        let r = double_sqrt(x);
+
+        assert!(f64::abs(r.val - 4.0) <= f64::EPSILON);
+        assert!(f64::abs(r.unc.0 - 0.05) <= f64::EPSILON);
+
+        let h = Float::hypot(x, ValUnc::new(3.0, Unc(0.0)));
+        assert!(f64::abs(h.val - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(h.unc.0 - 0.08) <= f64::EPSILON);
+    }
+
+    #[test]
+    fn functions() {
+        let x = ValUnc::new(0.5f64, Unc(0.01));
+
+        assert!(f64::abs(x.sin().unc.0 - 0.01 * 0.5f64.cos()) <= f64::EPSILON);
+        assert!(f64::abs(x.ln().unc.0 - 0.02) <= f64::EPSILON);
+        assert!(f64::abs(x.powi(2).unc.0 - 0.01) <= f64::EPSILON);
+        assert_eq!(x.floor(), ValUnc::new(0.0, Unc(0.0)));
+        assert_eq!(Float::max(x, -x), x);
+        assert!(f64::abs((x % ValUnc::new(0.2, Unc(0.0))).val - 0.1) <= 1e-12);
+        assert_eq!(
+            <ValUnc<f64, Unc<f64>> as Num>::from_str_radix("2.5", 10).unwrap(),
+            ValUnc::new(2.5, Unc(0.0))
+        );
+    }
+
+    #[test]
+    fn edge_cases() {
+        let exact = |val: f64| ValUnc::new(val, Unc(0.0));
+        let two = exact(2.0);
+
+        // The derivative with respect to the exact exponent is NaN or 0·∞
+        assert_eq!(
+            ValUnc::new(-2.0, Unc(0.1)).powf(two),
+            ValUnc::new(4.0, Unc(0.4))
+        );
+        assert_eq!(ValUnc::new(0.0, Unc(0.1)).powf(two), exact(0.0));
+        assert_eq!(ValUnc::new(3.0, Unc(0.1)).powf(exact(0.0)), exact(1.0));
+        assert_eq!(ValUnc::new(0.0, Unc(0.1)).powf(exact(0.0)), exact(1.0));
+
+        assert_eq!(ValUnc::new(0.0, Unc(0.1)).powi(0), exact(1.0));
+        assert_eq!(ValUnc::new(0.0, Unc(0.1)).powi(2), exact(0.0));
+        assert_eq!(exact(0.0).sqrt(), exact(0.0));
+        assert_eq!(exact(0.0).ln(), exact(f64::NEG_INFINITY));
+
+        // An uncertain operand still gives an infinite uncertainty
+        assert_eq!(ValUnc::new(0.0, Unc(0.1)).sqrt().unc.0, f64::INFINITY);
+    }
+}
@@ -0,0 +1,960 @@
+//! Formatting of `ValUnc`s rounded to the precision of their uncertainty
+//!
+//! The `Display` implementation of [`ValUnc`] writes the value and the uncertainties as they are.
+//! An [`UncFormatter`] instead rounds the total uncertainty (see [`UncTotal`]) according to a
+//! [`Rounding`] policy, rounds the value to the same decimal place (both with the same
+//! [`RoundingMode`]), and writes them in the chosen [`Notation`], optionally with a shared power of
+//! ten (see [`Exponent`]).
+//!
+//! ```
+//! use val_unc::{
+//!     fmt::{Notation, UncFormatter},
+//!     Unc, ValUnc,
+//! };
+//!
+//! let v = ValUnc::new(1.234_5678, Unc(0.000_0671));
+//!
+//! let plus_minus = UncFormatter::new();
+//! assert_eq!(plus_minus.display(&v).to_string(), "1.234568 ± 0.000067");
+//!
+//! let parenthesis = UncFormatter::new().notation(Notation::Parenthesis);
+//! assert_eq!(parenthesis.display(&v).to_string(), "1.234568(67)");
+//! ```
+
+use crate::{
+    traits::{round::round_float, Float, UncRound, UncTotal},
+    val_unc::ValUnc,
+};
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+
+/// How the value and the uncertainty are written
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum Notation {
+    /// `1.2346 ± 0.0067`
+    #[default]
+    PlusMinus,
+    /// `1.2346(67)`, where the digits in parentheses are the uncertainty in the last digits of the
+    /// value, as used by CODATA
+    Parenthesis,
+}
+
+/// Whether the value and the uncertainty share a power of ten
+///
+/// ```
+/// use val_unc::{
+///     fmt::{Exponent, UncFormatter},
+///     Unc, ValUnc,
+/// };
+///
+/// let v = ValUnc::new(1.2341e-3, Unc(0.0052e-3));
+/// let fmt = UncFormatter::new().exponent(Exponent::Scientific);
+/// assert_eq!(fmt.display(&v).to_string(), "(1.2341 ± 0.0052) × 10⁻³");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum Exponent {
+    /// Numbers are written out, e.g. `0.0012341 ± 0.0000052`
+    #[default]
+    None,
+    /// One digit before the decimal point, e.g. `(1.2341 ± 0.0052) × 10⁻³`
+    Scientific,
+    /// One to three digits before the decimal point, with a power of ten that is a multiple of
+    /// three, e.g. `(123.41 ± 0.52) × 10⁻⁶`
+    Engineering,
+}
+
+impl Exponent {
+    /// The factor of ten between exponents
+    fn step(self) -> i32 {
+        match self {
+            Self::None => 0,
+            Self::Scientific => 1,
+            Self::Engineering => 3,
+        }
+    }
+
+    /// Returns the power of ten of `val`, or of `unc` if `val` is zero.
+    fn of(self, val: f64, unc: f64) -> i32 {
+        let x = if val.is_finite() && val != 0.0 {
+            val.abs()
+        } else if unc.is_finite() && unc > 0.0 {
+            unc
+        } else {
+            return 0;
+        };
+
+        let exp = x.log10().floor() as i32;
+        match self {
+            Self::None => 0,
+            Self::Scientific => exp,
+            Self::Engineering => exp.div_euclid(3) * 3,
+        }
+    }
+}
+
+/// How many significant digits of the uncertainty are kept
+///
+/// ```
+/// use val_unc::{fmt::Rounding, Unc, ValUnc};
+///
+/// let v = ValUnc::new(1.234_5678, Unc(0.0671));
+/// assert_eq!(v.rounded(Rounding::Pdg), ValUnc::new(1.23, Unc(0.07)));
+/// assert_eq!(v.rounded(Rounding::Gum), ValUnc::new(1.235, Unc(0.067)));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum Rounding {
+    /// The rule of the Particle Data Group: if the three highest digits of the uncertainty are
+    /// between 100 and 354, two digits are kept, if they are between 355 and 949, one digit is
+    /// kept, and otherwise the uncertainty is rounded up to 1000 and two digits are kept.
+    Pdg,
+    /// Two significant digits, as recommended by the GUM
+    #[default]
+    Gum,
+    /// A fixed number of significant digits
+    SigFigs(usize),
+}
+
+impl Rounding {
+    /// Returns the number of decimal places (negative to the left of the decimal point) to round
+    /// to, or `None` if `unc` isn't positive and finite.
+    pub fn places(self, unc: f64) -> Option<i32> {
+        self.places_in(unc, RoundingMode::default())
+    }
+
+    /// Like [`places`](Self::places), but for digits rounded with `mode`, which determines
+    /// whether rounding adds a digit, e.g. 0.0996 to 0.100.
+    pub fn places_in(self, unc: f64, mode: RoundingMode) -> Option<i32> {
+        match self {
+            Self::Pdg => {
+                let places = unc_places(unc, 3, mode)?;
+                let leading = units(unc, places, mode);
+                // The exponent of the highest digit
+                let exp = 2 - places;
+                if leading <= 354.0 {
+                    Some(1 - exp)
+                } else {
+                    // Between 950 and 999, this rounds up to two digits, e.g. 0.0950 to 0.10
+                    Some(-exp)
+                }
+            }
+            Self::Gum => unc_places(unc, 2, mode),
+            Self::SigFigs(n) => unc_places(unc, n.max(1), mode),
+        }
+    }
+}
+
+/// How the last kept digit is rounded
+///
+/// The same mode is applied to the value and the uncertainty.
+///
+/// ```
+/// use val_unc::{
+///     fmt::{RoundingMode, UncFormatter},
+///     Unc, ValUnc,
+/// };
+///
+/// let v = ValUnc::new(2.5, Unc(1.5));
+/// let display = |mode| {
+///     let fmt = UncFormatter::new().unc_digits(1).rounding_mode(mode);
+///     fmt.display(&v).to_string()
+/// };
+/// assert_eq!(display(RoundingMode::HalfUp), "3 ± 2");
+/// assert_eq!(display(RoundingMode::HalfEven), "2 ± 2");
+/// assert_eq!(display(RoundingMode::TowardZero), "2 ± 1");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum RoundingMode {
+    /// Halves are rounded away from zero, e.g. 2.5 to 3 and -2.5 to -3
+    #[default]
+    HalfUp,
+    /// Halves are rounded to the even digit, e.g. 2.5 to 2 and 3.5 to 4
+    HalfEven,
+    /// The digits are truncated, e.g. 2.7 to 2 and -2.7 to -2
+    TowardZero,
+}
+
+impl RoundingMode {
+    /// Rounds `x` to an integer.
+    fn round(self, x: f64) -> f64 {
+        match self {
+            Self::HalfUp => x.round(),
+            Self::HalfEven => {
+                if (x - x.trunc()).abs() == 0.5 {
+                    2.0 * (x / 2.0).round()
+                } else {
+                    x.round()
+                }
+            }
+            Self::TowardZero => x.trunc(),
+        }
+    }
+}
+
+/// The brackets around a value and an uncertainty that share a power of ten
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum Brackets {
+    /// `(1.2341 ± 0.0052) × 10⁻³`
+    #[default]
+    Round,
+    /// `[1.2341 ± 0.0052] × 10⁻³`
+    Square,
+}
+
+impl Brackets {
+    fn open(self) -> char {
+        match self {
+            Self::Round => '(',
+            Self::Square => '[',
+        }
+    }
+
+    fn close(self) -> char {
+        match self {
+            Self::Round => ')',
+            Self::Square => ']',
+        }
+    }
+}
+
+/// The decimal separator and digit grouping of a locale
+///
+/// See [`UncFormatter::locale`].
+#[cfg(feature = "locale")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Locale {
+    /// The decimal separator, e.g. `','`
+    pub decimal_separator: char,
+    /// The separator between groups of three digits before the decimal point, if any
+    pub group_separator: Option<char>,
+    /// The number of digits that the highest group needs for the digits to be grouped, e.g. 2 if
+    /// `1234` is written without a separator, but `12 345` with one
+    pub min_grouping_digits: usize,
+}
+
+#[cfg(feature = "locale")]
+impl Locale {
+    /// Returns the conventions of a locale given by a language tag, e.g. `"de"`, `"de-CH"` or
+    /// `"pt_PT"`, or `None` if the language isn't known.
+    ///
+    /// The data follows the CLDR for the Latin digits of a number of common locales. A region
+    /// without its own conventions uses those of the language.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next()?;
+        let region = parts.find(|p| p.len() == 2 || p.chars().all(|c| c.is_ascii_digit()));
+
+        let find = |region: Option<&str>| {
+            LOCALES
+                .iter()
+                .find(|(l, r, _)| {
+                    l.eq_ignore_ascii_case(language)
+                        && match (r, region) {
+                            (Some(r), Some(region)) => r.eq_ignore_ascii_case(region),
+                            (None, None) => true,
+                            _ => false,
+                        }
+                })
+                .map(|&(_, _, locale)| locale)
+        };
+        region.and_then(|r| find(Some(r))).or_else(|| find(None))
+    }
+
+    const fn new(
+        decimal_separator: char,
+        group_separator: char,
+        min_grouping_digits: usize,
+    ) -> Self {
+        Self {
+            decimal_separator,
+            group_separator: Some(group_separator),
+            min_grouping_digits,
+        }
+    }
+}
+
+/// The languages and regions known to [`Locale::from_tag`]
+#[cfg(feature = "locale")]
+const LOCALES: &[(&str, Option<&str>, Locale)] = &[
+    ("de", None, Locale::new(',', '.', 1)),
+    ("de", Some("AT"), Locale::new(',', '\u{a0}', 1)),
+    ("de", Some("CH"), Locale::new('.', '’', 1)),
+    ("en", None, Locale::new('.', ',', 1)),
+    ("es", None, Locale::new(',', '.', 2)),
+    ("es", Some("MX"), Locale::new('.', ',', 1)),
+    ("fr", None, Locale::new(',', '\u{202f}', 1)),
+    ("it", None, Locale::new(',', '.', 1)),
+    ("ja", None, Locale::new('.', ',', 1)),
+    ("nl", None, Locale::new(',', '.', 1)),
+    ("pl", None, Locale::new(',', '\u{a0}', 2)),
+    ("pt", None, Locale::new(',', '.', 1)),
+    ("pt", Some("PT"), Locale::new(',', '\u{a0}', 2)),
+    ("ru", None, Locale::new(',', '\u{a0}', 1)),
+    ("sv", None, Locale::new(',', '\u{a0}', 1)),
+    ("zh", None, Locale::new('.', ',', 1)),
+];
+
+/// A builder for the formatting of `ValUnc`s
+///
+/// The options can be set once for a house style, and used for any number of values:
+///
+/// ```
+/// use val_unc::{
+///     fmt::{Brackets, Exponent, UncFormatter},
+///     Unc, ValUnc,
+/// };
+///
+/// let style = UncFormatter::new()
+///     .ascii(true)
+///     .decimal_separator(',')
+///     .brackets(Brackets::Square)
+///     .exponent(Exponent::Engineering);
+///
+/// let v = ValUnc::new(1.2341e-4, Unc(5.2e-7));
+/// assert_eq!(style.display(&v).to_string(), "[123,41 +/- 0,52]e-6");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct UncFormatter {
+    notation: Notation,
+    rounding: Rounding,
+    rounding_mode: RoundingMode,
+    exponent: Exponent,
+    percent: bool,
+    ascii: bool,
+    separator: Option<String>,
+    decimal_separator: char,
+    group_separator: Option<char>,
+    min_grouping_digits: usize,
+    brackets: Brackets,
+}
+
+impl Default for UncFormatter {
+    fn default() -> Self {
+        Self {
+            notation: Notation::default(),
+            rounding: Rounding::default(),
+            rounding_mode: RoundingMode::default(),
+            exponent: Exponent::default(),
+            percent: false,
+            ascii: false,
+            separator: None,
+            decimal_separator: '.',
+            group_separator: None,
+            min_grouping_digits: 1,
+            brackets: Brackets::default(),
+        }
+    }
+}
+
+impl UncFormatter {
+    /// Creates a formatter with `±` notation and [`Rounding::Gum`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+
+    pub fn exponent(mut self, exponent: Exponent) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Sets whether the uncertainty is written as a percentage of the value, e.g. `12.3 ± 1.5 %`.
+    ///
+    /// The percentage is rounded according to the [`Rounding`] policy, and the value to the
+    /// precision of the absolute uncertainty. A value that is within one uncertainty of zero (or
+    /// not finite) has no meaningful relative uncertainty, so the absolute uncertainty is written
+    /// instead.
+    ///
+    /// ```
+    /// use val_unc::{fmt::UncFormatter, Unc, ValUnc};
+    ///
+    /// let fmt = UncFormatter::new().percent(true);
+    /// assert_eq!(fmt.display(&ValUnc::new(12.31, Unc(0.1845))).to_string(), "12.31 ± 1.5 %");
+    /// assert_eq!(fmt.display(&ValUnc::new(0.0, Unc(0.1845))).to_string(), "0.00 ± 0.18");
+    /// ```
+    pub fn percent(mut self, percent: bool) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// Sets whether only ASCII is written, i.e. ` +/- ` instead of ` ± `, and `e-3` instead of
+    /// ` × 10⁻³`.
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Sets the separator between the value and the uncertainty, e.g. `" +- "`, instead of
+    /// ` ± ` (or ` +/- ` if [`ascii`](Self::ascii) is set).
+    pub fn separator<S>(mut self, separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Sets the character used as the decimal point, e.g. `','`.
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Sets the character that separates groups of three digits before the decimal point, e.g.
+    /// `'\u{202f}'` (a narrow no-break space) for `12 345.6`.
+    pub fn group_separator(mut self, group_separator: char) -> Self {
+        self.group_separator = Some(group_separator);
+        self
+    }
+
+    /// Sets the decimal separator and digit grouping of `locale`.
+    ///
+    /// ```
+    /// use val_unc::{
+    ///     fmt::{Locale, UncFormatter},
+    ///     Unc, ValUnc,
+    /// };
+    ///
+    /// let v = ValUnc::new(12345.678, Unc(0.12));
+    /// let de = UncFormatter::new().locale(Locale::from_tag("de-DE").unwrap());
+    /// assert_eq!(de.display(&v).to_string(), "12.345,68 ± 0,12");
+    /// ```
+    #[cfg(feature = "locale")]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.decimal_separator = locale.decimal_separator;
+        self.group_separator = locale.group_separator;
+        self.min_grouping_digits = locale.min_grouping_digits;
+        self
+    }
+
+    pub fn brackets(mut self, brackets: Brackets) -> Self {
+        self.brackets = brackets;
+        self
+    }
+
+    /// Sets the number of significant digits of the uncertainty (see [`Rounding::SigFigs`]).
+    pub fn unc_digits(self, unc_digits: usize) -> Self {
+        self.rounding(Rounding::SigFigs(unc_digits))
+    }
+
+    /// Returns an adapter that displays `val_unc` with this formatting.
+    pub fn display<'a, V, U>(&'a self, val_unc: &'a ValUnc<V, U>) -> Formatted<'a, V, U> {
+        Formatted {
+            formatter: self,
+            val_unc,
+        }
+    }
+
+    /// Returns the number of decimal places to round `unc` and its value to.
+    fn places(&self, unc: f64) -> Option<i32> {
+        self.rounding.places_in(unc, self.rounding_mode)
+    }
+
+    /// Returns the shared power of ten, and the value and uncertainty scaled by it.
+    fn scale(&self, val: f64, unc: f64) -> (i32, f64, f64) {
+        let mut exp = self.exponent.of(val, unc);
+        let scale = |exp: i32| (val / 10f64.powi(exp), unc / 10f64.powi(exp));
+        let (mut val_m, mut unc_m) = scale(exp);
+        // Rounding can add a digit to the value, e.g. 9.9996 to 10.000
+        if let (Some(places), 1..) = (self.places(unc_m), self.exponent.step()) {
+            if round_at(val_m.abs(), places, self.rounding_mode) >= 10f64.powi(self.exponent.step())
+            {
+                exp += self.exponent.step();
+                (val_m, unc_m) = scale(exp);
+            }
+        }
+        (exp, val_m, unc_m)
+    }
+
+    fn separator_str(&self) -> &str {
+        match &self.separator {
+            Some(separator) => separator,
+            None if self.ascii => " +/- ",
+            None => " ± ",
+        }
+    }
+
+    /// Writes `x`, rounded to `places` decimal places if given, with the decimal and group
+    /// separators.
+    fn write_num(&self, x: f64, places: Option<i32>, f: &mut String) -> fmt::Result {
+        let start = f.len();
+        match places {
+            Some(places) => write_rounded(x, places, self.rounding_mode, f)?,
+            None => write!(f, "{}", x)?,
+        }
+        if self.decimal_separator != '.' {
+            if let Some(i) = f[start..].find('.') {
+                let mut buf = [0; 4];
+                let sep = self.decimal_separator.encode_utf8(&mut buf);
+                f.replace_range(start + i..start + i + 1, sep);
+            }
+        }
+        if let Some(group_separator) = self.group_separator {
+            let digits = start + f[start..].find(|c: char| c.is_ascii_digit()).unwrap_or(0);
+            let len = f[digits..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(f.len() - digits);
+            if len >= 3 + self.min_grouping_digits {
+                // Inserting from the right keeps the positions of the groups to the left
+                for i in 1..=(len - 1) / 3 {
+                    f.insert(digits + len - 3 * i, group_separator);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_exp(&self, exp: i32, f: &mut String) -> fmt::Result {
+        if self.ascii {
+            write!(f, "e{}", exp)
+        } else {
+            write!(f, " × 10{}", superscript(exp))
+        }
+    }
+
+    fn fmt(&self, val: f64, unc: f64, f: &mut String) -> fmt::Result {
+        let rel = 100.0 * (unc / val).abs();
+        if self.percent && rel < 100.0 {
+            return self.fmt_percent(val, unc, rel, f);
+        }
+
+        let (exp, val_m, unc_m) = self.scale(val, unc);
+        if exp == 0 {
+            return self.fmt_mantissas(val_m, unc_m, f);
+        }
+        match self.notation {
+            Notation::PlusMinus => {
+                f.push(self.brackets.open());
+                self.fmt_mantissas(val_m, unc_m, f)?;
+                f.push(self.brackets.close());
+            }
+            Notation::Parenthesis => self.fmt_mantissas(val_m, unc_m, f)?,
+        }
+        self.write_exp(exp, f)
+    }
+
+    fn fmt_percent(&self, val: f64, unc: f64, rel: f64, f: &mut String) -> fmt::Result {
+        let (exp, val_m, unc_m) = self.scale(val, unc);
+        self.write_num(val_m, self.places(unc_m), f)?;
+        if exp != 0 {
+            self.write_exp(exp, f)?;
+        }
+
+        f.push_str(self.separator_str());
+        self.write_num(rel, self.places(rel), f)?;
+        f.write_str(" %")
+    }
+
+    fn fmt_mantissas(&self, val: f64, unc: f64, f: &mut String) -> fmt::Result {
+        let places = match self.places(unc) {
+            Some(places) => places,
+            // There is no precision to round to
+            None => {
+                self.write_num(val, None, f)?;
+                return match self.notation {
+                    Notation::PlusMinus => {
+                        f.push_str(self.separator_str());
+                        self.write_num(unc, None, f)
+                    }
+                    Notation::Parenthesis => {
+                        f.push('(');
+                        self.write_num(unc, None, f)?;
+                        f.write_str(")")
+                    }
+                };
+            }
+        };
+
+        match self.notation {
+            Notation::PlusMinus => {
+                self.write_num(val, Some(places), f)?;
+                f.push_str(self.separator_str());
+                self.write_num(unc, Some(places), f)
+            }
+            Notation::Parenthesis => {
+                self.write_num(val, Some(places), f)?;
+                if places > 0 {
+                    write!(f, "({:.0})", units(unc, places, self.rounding_mode))
+                } else {
+                    f.write_str("(")?;
+                    self.write_num(unc, Some(places), f)?;
+                    f.write_str(")")
+                }
+            }
+        }
+    }
+}
+
+/// A `ValUnc` displayed with an [`UncFormatter`]
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a, V, U> {
+    formatter: &'a UncFormatter,
+    val_unc: &'a ValUnc<V, U>,
+}
+
+impl<'a, V, U> fmt::Display for Formatted<'a, V, U>
+where
+    V: Float,
+    U: UncTotal<V>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let val = self.val_unc.val;
+        let unc = self.val_unc.unc.total(val);
+        let mut s = String::new();
+        self.formatter.fmt(
+            val.to_f64().unwrap_or(f64::NAN),
+            unc.to_f64().unwrap_or(f64::NAN),
+            &mut s,
+        )?;
+        f.pad(&s)
+    }
+}
+
+impl<V, U> ValUnc<V, U>
+where
+    V: crate::traits::Real,
+    U: UncTotal<V> + UncRound,
+{
+    /// Rounds the total uncertainty according to `rounding`, and the value and each uncertainty
+    /// component to the same decimal place.
+    ///
+    /// The `ValUnc` is left as is if the total uncertainty isn't positive and finite.
+    pub fn rounded(self, rounding: Rounding) -> Self {
+        let unc = self
+            .unc
+            .total(self.val.clone())
+            .to_f64()
+            .unwrap_or(f64::NAN);
+        match rounding.places(unc) {
+            Some(places) => ValUnc::new(round_float(self.val, places), self.unc.round_at(places)),
+            None => self,
+        }
+    }
+
+    /// Rounds the total uncertainty to `sig_figs` significant digits, and the value and each
+    /// uncertainty component to the same decimal place (see [`Rounding::SigFigs`]).
+    ///
+    /// ```
+    /// use val_unc::{Unc, ValUnc};
+    ///
+    /// let v = ValUnc::new(18.7234, (Unc(3.0123), Unc(4.0234)));
+    /// assert_eq!(v.round_to_unc(2), ValUnc::new(18.7, (Unc(3.0), Unc(4.0))));
+    /// ```
+    pub fn round_to_unc(self, sig_figs: usize) -> Self {
+        self.rounded(Rounding::SigFigs(sig_figs))
+    }
+}
+
+/// Returns the number of decimal places that gives `unc` `digits` significant digits after
+/// rounding, or `None` if `unc` isn't positive and finite.
+fn unc_places(unc: f64, digits: usize, mode: RoundingMode) -> Option<i32> {
+    if !(unc.is_finite() && unc > 0.0) {
+        return None;
+    }
+
+    let digits = digits as i32;
+    let places = digits - 1 - unc.log10().floor() as i32;
+    // Rounding can add a digit, e.g. 0.0996 to 0.100
+    if units(unc, places, mode) >= 10f64.powi(digits) {
+        Some(places - 1)
+    } else {
+        Some(places)
+    }
+}
+
+/// Rounds `x` to `places` decimal places with `mode`, in units of the last place.
+fn units(x: f64, places: i32, mode: RoundingMode) -> f64 {
+    if places >= 0 {
+        mode.round(x * 10f64.powi(places))
+    } else {
+        mode.round(x / 10f64.powi(-places))
+    }
+}
+
+/// Rounds `x` to `places` decimal places with `mode`.
+fn round_at(x: f64, places: i32, mode: RoundingMode) -> f64 {
+    if places >= 0 {
+        units(x, places, mode) / 10f64.powi(places)
+    } else {
+        units(x, places, mode) * 10f64.powi(-places)
+    }
+}
+
+fn superscript(exp: i32) -> String {
+    exp.to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
+fn write_rounded(x: f64, places: i32, mode: RoundingMode, f: &mut String) -> fmt::Result {
+    write!(
+        f,
+        "{:.*}",
+        places.max(0) as usize,
+        round_at(x, places, mode)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DofUnc, Unc};
+
+    #[test]
+    fn plus_minus() {
+        let fmt = UncFormatter::new();
+        let display = |val: f64, unc: f64| fmt.display(&ValUnc::new(val, Unc(unc))).to_string();
+
+        assert_eq!(display(12.3449, 0.0512), "12.345 ± 0.051");
+        assert_eq!(display(12.3449, 0.0996), "12.34 ± 0.10");
+        assert_eq!(display(12345.6, 567.0), "12350 ± 570");
+        assert_eq!(display(-1.0, 0.0), "-1 ± 0");
+        assert_eq!(
+            UncFormatter::new()
+                .unc_digits(1)
+                .display(&ValUnc::new(12.3449, (Unc(0.03), Unc(0.04))))
+                .to_string(),
+            "12.34 ± 0.05"
+        );
+    }
+
+    #[test]
+    fn pdg() {
+        let fmt = UncFormatter::new().rounding(Rounding::Pdg);
+        let display = |val: f64, unc: f64| fmt.display(&ValUnc::new(val, Unc(unc))).to_string();
+
+        assert_eq!(display(1.23456, 0.0354), "1.235 ± 0.035");
+        assert_eq!(display(1.23456, 0.0355), "1.23 ± 0.04");
+        assert_eq!(display(1.23456, 0.0949), "1.23 ± 0.09");
+        assert_eq!(display(1.23456, 0.0950), "1.23 ± 0.10");
+        assert_eq!(display(1.23456, 0.9996), "1.2 ± 1.0");
+        assert_eq!(display(123.456, 12.0), "123 ± 12");
+    }
+
+    #[test]
+    fn exponent() {
+        let sci = UncFormatter::new().exponent(Exponent::Scientific);
+        let eng = UncFormatter::new().exponent(Exponent::Engineering);
+        let display = |fmt: &UncFormatter, val: f64, unc: f64| {
+            fmt.display(&ValUnc::new(val, Unc(unc))).to_string()
+        };
+
+        assert_eq!(display(&sci, 12345.6, 56.7), "(1.2346 ± 0.0057) × 10⁴");
+        assert_eq!(
+            display(&sci, 9.99996e-5, 1.2e-8),
+            "(1.00000 ± 0.00012) × 10⁻⁴"
+        );
+        assert_eq!(display(&sci, 0.0, 1.2e-9), "(0.0 ± 1.2) × 10⁻⁹");
+        assert_eq!(display(&sci, 1.234, 0.056), "1.234 ± 0.056");
+        assert_eq!(display(&eng, 12345.6, 56.7), "(12.346 ± 0.057) × 10³");
+        assert_eq!(
+            display(&eng, 1.2341e-4, 5.2e-9),
+            "(123.4100 ± 0.0052) × 10⁻⁶"
+        );
+        assert_eq!(
+            display(&eng.notation(Notation::Parenthesis), 1.2341e-4, 5.2e-7),
+            "123.41(52) × 10⁻⁶"
+        );
+    }
+
+    #[test]
+    fn percent() {
+        let fmt = UncFormatter::new().percent(true);
+        let display = |fmt: &UncFormatter, val: f64, unc: f64| {
+            fmt.display(&ValUnc::new(val, Unc(unc))).to_string()
+        };
+
+        assert_eq!(display(&fmt, -12.34, 0.1845), "-12.34 ± 1.5 %");
+        assert_eq!(display(&fmt, 12.34, 0.0), "12.34 ± 0 %");
+        assert_eq!(display(&fmt, 1e-3, 1.0), "0.0 ± 1.0");
+        assert_eq!(display(&fmt, 1.0, 0.99), "1.00 ± 99 %");
+        assert_eq!(display(&fmt, 0.0, 1.0), "0.0 ± 1.0");
+        assert_eq!(
+            display(
+                &fmt.clone()
+                    .rounding(Rounding::Pdg)
+                    .exponent(Exponent::Scientific),
+                12345.6,
+                567.0
+            ),
+            "1.23 × 10⁴ ± 5 %"
+        );
+    }
+
+    #[test]
+    fn house_style() {
+        let v = ValUnc::new(12345.6, Unc(56.7));
+
+        let ascii = UncFormatter::new().ascii(true);
+        assert_eq!(ascii.display(&v).to_string(), "12346 +/- 57");
+        assert_eq!(
+            ascii
+                .clone()
+                .exponent(Exponent::Scientific)
+                .display(&v)
+                .to_string(),
+            "(1.2346 +/- 0.0057)e4"
+        );
+        assert_eq!(
+            ascii.clone().percent(true).display(&v).to_string(),
+            "12346 +/- 0.46 %"
+        );
+
+        let custom = UncFormatter::new()
+            .separator(" +- ")
+            .decimal_separator(',')
+            .exponent(Exponent::Scientific);
+        assert_eq!(custom.display(&v).to_string(), "(1,2346 +- 0,0057) × 10⁴");
+        assert_eq!(
+            custom
+                .notation(Notation::Parenthesis)
+                .display(&v)
+                .to_string(),
+            "1,2346(57) × 10⁴"
+        );
+
+        // Only the decimal points of the numbers are replaced
+        let dots = UncFormatter::new()
+            .separator(" ... ")
+            .decimal_separator(',');
+        assert_eq!(dots.display(&v).to_string(), "12346 ... 57");
+        assert_eq!(
+            dots.display(&ValUnc::new(1.25, Unc(0.5))).to_string(),
+            "1,25 ... 0,50"
+        );
+
+        // The width and alignment are applied to the whole
+        assert_eq!(format!("{:>15}|", ascii.display(&v)), "   12346 +/- 57|");
+        assert_eq!(format!("{:-<15}|", ascii.display(&v)), "12346 +/- 57---|");
+    }
+
+    #[test]
+    fn rounded() {
+        let v = ValUnc::new(12345.6f64, Unc(567.0f64));
+        assert_eq!(v.rounded(Rounding::Gum), ValUnc::new(12350.0, Unc(570.0)));
+        assert_eq!(
+            v.rounded(Rounding::SigFigs(1)),
+            ValUnc::new(12300.0, Unc(600.0))
+        );
+
+        let exact = ValUnc::new(1.5f64, Unc(0.0f64));
+        assert_eq!(exact.rounded(Rounding::Pdg), exact);
+
+        let components = ValUnc::new(2.3456, (Unc(0.0123), DofUnc::new(0.0456, 4.0)));
+        assert_eq!(
+            components.round_to_unc(1),
+            ValUnc::new(2.35, (Unc(0.01), DofUnc::new(0.05, 4.0)))
+        );
+    }
+
+    #[test]
+    fn parenthesis() {
+        let fmt = UncFormatter::new().notation(Notation::Parenthesis);
+        let display = |val: f64, unc: f64| fmt.display(&ValUnc::new(val, Unc(unc))).to_string();
+
+        assert_eq!(
+            display(6.674_30e-11, 0.000_15e-11),
+            "0.0000000000667430(15)"
+        );
+        assert_eq!(display(1.602_176_634, 0.0996), "1.60(10)");
+        assert_eq!(display(12345.6, 567.0), "12350(570)");
+        assert_eq!(display(12345.6, 5.67), "12345.6(57)");
+    }
+
+    #[test]
+    fn rounding_mode() {
+        let display = |mode: RoundingMode, val: f64, unc: f64| {
+            UncFormatter::new()
+                .rounding_mode(mode)
+                .display(&ValUnc::new(val, Unc(unc)))
+                .to_string()
+        };
+
+        assert_eq!(display(RoundingMode::HalfUp, -0.125, 0.25), "-0.13 ± 0.25");
+        assert_eq!(
+            display(RoundingMode::HalfEven, -0.125, 0.25),
+            "-0.12 ± 0.25"
+        );
+        assert_eq!(display(RoundingMode::HalfEven, 0.375, 0.25), "0.38 ± 0.25");
+        assert_eq!(
+            display(RoundingMode::TowardZero, -0.129, 0.25),
+            "-0.12 ± 0.25"
+        );
+
+        // Whether rounding adds a digit depends on the mode
+        assert_eq!(
+            display(RoundingMode::HalfEven, 12.3449, 0.0996),
+            "12.34 ± 0.10"
+        );
+        assert_eq!(
+            display(RoundingMode::TowardZero, 12.3449, 0.0996),
+            "12.344 ± 0.099"
+        );
+        assert_eq!(
+            UncFormatter::new()
+                .notation(Notation::Parenthesis)
+                .rounding_mode(RoundingMode::TowardZero)
+                .display(&ValUnc::new(1.23456, Unc(0.00239)))
+                .to_string(),
+            "1.2345(23)"
+        );
+    }
+
+    #[test]
+    fn grouping() {
+        let fmt = UncFormatter::new().group_separator(',');
+        let display = |fmt: &UncFormatter, val: f64, unc: f64| {
+            fmt.display(&ValUnc::new(val, Unc(unc))).to_string()
+        };
+
+        assert_eq!(display(&fmt, -1234567.891, 0.012), "-1,234,567.891 ± 0.012");
+        assert_eq!(display(&fmt, 1234.5, 1234.5), "1,200 ± 1,200");
+        assert_eq!(display(&fmt, 1.0, f64::INFINITY), "1 ± inf");
+    }
+
+    #[test]
+    #[cfg(feature = "locale")]
+    fn locale() {
+        let display = |tag: &str, val: f64, unc: f64| {
+            UncFormatter::new()
+                .locale(Locale::from_tag(tag).unwrap())
+                .display(&ValUnc::new(val, Unc(unc)))
+                .to_string()
+        };
+
+        assert_eq!(display("en-US", 12345.678, 0.12), "12,345.68 ± 0.12");
+        assert_eq!(display("de_CH", 12345.678, 0.12), "12’345.68 ± 0.12");
+        assert_eq!(display("fr", 12345.678, 0.12), "12\u{202f}345,68 ± 0,12");
+        // Spanish only groups numbers with five or more digits
+        assert_eq!(display("es-ES", 1234.5678, 0.12), "1234,57 ± 0,12");
+        assert_eq!(display("es-ES", 12345.678, 0.12), "12.345,68 ± 0,12");
+        assert_eq!(Locale::from_tag("zh-Hant-TW"), Locale::from_tag("zh"));
+        assert_eq!(Locale::from_tag("xx"), None);
+    }
+}
@@ -0,0 +1,153 @@
+//! Integrating sampled data
+//!
+//! The samples are points `(xs[i], ys[i])`, where the `xs` are exact and sorted, and the `ys` are
+//! independent `ValUnc`s. Each rule is a weighted sum of the `ys`, so their uncertainties are
+//! propagated exactly. The error of the rule itself isn't included.
+//!
+//! ```
+//! use val_unc::{integrate, Unc, ValUnc};
+//!
+//! let xs = [0.0, 1.0, 2.0];
+//! let ys = [ValUnc::new(0.0, Unc(0.2)), ValUnc::new(1.0, Unc(0.2)), ValUnc::new(4.0, Unc(0.2))];
+//!
+//! assert_eq!(integrate::trapezoid(&xs, &ys).val, 3.0);
+//! assert!(f64::abs(integrate::simpson(&xs, &ys).val - 8.0 / 3.0) <= 1e-12);
+//! ```
+
+use crate::{traits::UncTotal, unc::Unc, val_unc::ValUnc};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The sum `Σ c(i) ys[i]`, with the uncertainties of the `ys` added in quadrature.
+fn weighted_sum<U, C>(ys: &[ValUnc<f64, U>], c: C) -> ValUnc<f64>
+where
+    U: UncTotal<f64>,
+    C: Fn(usize) -> f64,
+{
+    let (val, var) = ys
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(val, var), (i, y)| {
+            let c = c(i);
+            (val + c * y.val, var + (c * y.unc.total(y.val)).powi(2))
+        });
+    ValUnc::new(val, Unc(var.sqrt()))
+}
+
+/// Integrates the samples with the trapezoidal rule.
+///
+/// With fewer than two samples, the integral is zero.
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn trapezoid<U>(xs: &[f64], ys: &[ValUnc<f64, U>]) -> ValUnc<f64>
+where
+    U: UncTotal<f64>,
+{
+    assert_eq!(xs.len(), ys.len(), "the lengths are different");
+    let n = xs.len();
+    weighted_sum(ys, |i| {
+        let lower = if i > 0 { xs[i] - xs[i - 1] } else { 0.0 };
+        let upper = if i + 1 < n { xs[i + 1] - xs[i] } else { 0.0 };
+        0.5 * (lower + upper)
+    })
+}
+
+/// The weights of Simpson's rule for the points `0`, `1`, and `2` of a pair of intervals of
+/// widths `h0` and `h1`.
+fn simpson_pair(h0: f64, h1: f64) -> [f64; 3] {
+    let s = (h0 + h1) / 6.0;
+    [
+        s * (2.0 - h1 / h0),
+        s * (h0 + h1).powi(2) / (h0 * h1),
+        s * (2.0 - h0 / h1),
+    ]
+}
+
+/// Integrates the samples with Simpson's rule, for unequally spaced `xs`.
+///
+/// Each pair of intervals is integrated with the quadratic through its three points. If the
+/// number of intervals is odd, the last one is integrated with the quadratic through the last
+/// three points. With two samples, this is the trapezoidal rule, and with fewer, the integral is
+/// zero.
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn simpson<U>(xs: &[f64], ys: &[ValUnc<f64, U>]) -> ValUnc<f64>
+where
+    U: UncTotal<f64>,
+{
+    assert_eq!(xs.len(), ys.len(), "the lengths are different");
+    let n = xs.len();
+    if n < 3 {
+        return trapezoid(xs, ys);
+    }
+    let h = |i: usize| xs[i + 1] - xs[i];
+    let pairs = (n - 1) / 2;
+
+    weighted_sum(ys, |i| {
+        // The pairs of intervals `p`, covering the points `2p..=2p + 2`, that contain `i`
+        let first = if i % 2 == 0 && i >= 2 {
+            i / 2 - 1
+        } else {
+            i / 2
+        };
+        let mut c = (first..=i / 2)
+            .filter(|&p| p < pairs)
+            .map(|p| simpson_pair(h(2 * p), h(2 * p + 1))[i - 2 * p])
+            .sum::<f64>();
+
+        // The last interval, if it's left over
+        if (n - 1) % 2 == 1 && i + 3 >= n {
+            let (h0, h1) = (h(n - 3), h(n - 2));
+            c += match n - 1 - i {
+                0 => (2.0 * h1.powi(2) + 3.0 * h0 * h1) / (6.0 * (h0 + h1)),
+                1 => (h1.powi(2) + 3.0 * h0 * h1) / (6.0 * h0),
+                _ => -h1.powi(3) / (6.0 * h0 * (h0 + h1)),
+            };
+        }
+        c
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples<const N: usize>(xs: [f64; N], f: fn(f64) -> f64) -> [ValUnc<f64>; N] {
+        xs.map(|x| ValUnc::new(f(x), Unc(0.1)))
+    }
+
+    #[test]
+    fn trapezoid_rule() {
+        let xs = [0.0, 1.0, 3.0, 4.0];
+        let ys = samples(xs, |x| 2.0 * x + 1.0);
+        let i = trapezoid(&xs, &ys);
+
+        // The weights are 0.5, 1.5, 1.5, and 0.5
+        assert_eq!(i.val, 20.0);
+        assert!(f64::abs(i.unc.0 - 0.1 * f64::sqrt(0.25 + 2.25 + 2.25 + 0.25)) <= 1e-12);
+        assert_eq!(trapezoid(&xs[..1], &ys[..1]), ValUnc::new(0.0, Unc(0.0)));
+    }
+
+    #[test]
+    fn simpson_rule() {
+        // Exact for cubics with an even number of intervals, if each pair is equally spaced
+        let xs = [0.0, 0.5, 1.0, 2.0, 3.0];
+        let i = simpson(&xs, &samples(xs, |x| x.powi(3) - x));
+        assert!(f64::abs(i.val - (81.0 / 4.0 - 9.0 / 2.0)) <= 1e-12);
+
+        // And for quadratics with an odd number
+        let xs = [0.0, 0.5, 1.5, 2.0, 3.0, 3.5];
+        let ys = samples(xs, |x| x * x);
+        let i = simpson(&xs, &ys);
+        assert!(f64::abs(i.val - 3.5f64.powi(3) / 3.0) <= 1e-12);
+
+        // The weights add up to the length
+        let ones = samples(xs, |_| 1.0);
+        assert!(f64::abs(simpson(&xs, &ones).val - 3.5) <= 1e-12);
+        assert_eq!(simpson(&xs[..2], &ys[..2]), trapezoid(&xs[..2], &ys[..2]));
+    }
+}
@@ -0,0 +1,263 @@
+//! Interpolating tables of measured values
+//!
+//! The tables are slices of `(x, y)` points, sorted by `x`, where the `x`s are exact and the `y`s
+//! are independent `ValUnc`s. Each interpolated value is a linear combination of the tabulated
+//! `y`s, so their uncertainties are propagated exactly.
+//!
+//! The `_with_error` variants also estimate the error of the interpolation itself, from the
+//! difference with a local polynomial of higher order, and add it to the uncertainty in
+//! quadrature.
+//!
+//! ```
+//! use val_unc::{interp, Unc, ValUnc};
+//!
+//! let table = [
+//!     (0.0, ValUnc::new(1.0, Unc(0.3))),
+//!     (1.0, ValUnc::new(3.0, Unc(0.4))),
+//! ];
+//! let y = interp::linear(0.5, &table).unwrap();
+//!
+//! assert_eq!(y, ValUnc::new(2.0, Unc(0.25)));
+//! assert_eq!(interp::linear(1.5, &table), None);
+//! ```
+
+use crate::{traits::UncTotal, unc::Unc, val_unc::ValUnc};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The index `k` of the interval `table[k].0 <= x <= table[k + 1].0` containing `x`, or `None` if
+/// `x` is outside of the table.
+fn interval<T>(x: f64, table: &[(f64, T)]) -> Option<usize> {
+    let (first, last) = (table.first()?.0, table.last()?.0);
+    if table.len() < 2 || !(first..=last).contains(&x) {
+        return None;
+    }
+    let k = table.partition_point(|p| p.0 <= x);
+    Some(k.saturating_sub(1).min(table.len() - 2))
+}
+
+/// The value at `x` of the polynomial through the values of `points`.
+fn lagrange<U>(x: f64, points: &[(f64, ValUnc<f64, U>)]) -> f64 {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, (xi, yi))| {
+            let l = points
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(1.0, |l, (_, (xj, _))| l * (x - xj) / (xi - xj));
+            l * yi.val
+        })
+        .sum()
+}
+
+/// Adds the estimated interpolation error `err` to `v` in quadrature.
+fn with_error(v: ValUnc<f64>, err: f64) -> ValUnc<f64> {
+    ValUnc::new(v.val, Unc(f64::sqrt(v.unc.0.powi(2) + err.powi(2))))
+}
+
+/// Interpolates linearly between the two points of `table` around `x`, or returns `None` if `x`
+/// is outside of the table.
+///
+/// The table must be sorted by `x`.
+pub fn linear<U>(x: f64, table: &[(f64, ValUnc<f64, U>)]) -> Option<ValUnc<f64>>
+where
+    U: UncTotal<f64>,
+{
+    let k = interval(x, table)?;
+    let ((x0, y0), (x1, y1)) = (&table[k], &table[k + 1]);
+    let t = (x - x0) / (x1 - x0);
+    let (u0, u1) = (y0.unc.total(y0.val), y1.unc.total(y1.val));
+    Some(ValUnc::new(
+        (1.0 - t) * y0.val + t * y1.val,
+        Unc(f64::sqrt(((1.0 - t) * u0).powi(2) + (t * u1).powi(2))),
+    ))
+}
+
+/// Like [`linear`], with the interpolation error estimated from the quadratic through the two
+/// points around `x` and the next nearest point.
+///
+/// With only two points, the error can't be estimated and is taken to be zero.
+pub fn linear_with_error<U>(x: f64, table: &[(f64, ValUnc<f64, U>)]) -> Option<ValUnc<f64>>
+where
+    U: UncTotal<f64>,
+{
+    let v = linear(x, table)?;
+    if table.len() < 3 {
+        return Some(v);
+    }
+    let k = interval(x, table)?.min(table.len() - 3);
+    Some(with_error(v, (lagrange(x, &table[k..k + 3]) - v.val).abs()))
+}
+
+/// The coefficients `c` of a natural cubic spline through `table` at `x`, such that the spline
+/// is `Σ c[j] y[j]`, for `x` in the interval `k`.
+#[cfg(feature = "alloc")]
+fn spline_coefficients<T>(x: f64, table: &[(f64, T)], k: usize) -> Vec<f64> {
+    let n = table.len();
+    let h: Vec<f64> = table.windows(2).map(|p| p[1].0 - p[0].0).collect();
+    let t = (x - table[k].0) / h[k];
+    let (a, b) = (1.0 - t, t);
+
+    let mut c = vec![0.0; n];
+    c[k] = a;
+    c[k + 1] = b;
+
+    // The second derivatives `m` at the interior points solve `A m = B y`, with `A` symmetric
+    // and tridiagonal, and are zero at the ends. The coefficients of `y` in `m[i]` are row `i`
+    // of `A⁻¹ B`, i.e. `(A⁻¹ e_i)ᵀ B`.
+    let interior = n - 2;
+    let mut add_m = |i: usize, factor: f64| {
+        if i == 0 || i == n - 1 || factor == 0.0 {
+            return;
+        }
+        let w = solve_tridiagonal(&h, i - 1, interior);
+        for (r, w) in w.iter().enumerate() {
+            // Row `r` of `B` is at the interior point `r + 1`
+            let j = r + 1;
+            c[j - 1] += factor * w / h[j - 1];
+            c[j] -= factor * w * (1.0 / h[j - 1] + 1.0 / h[j]);
+            c[j + 1] += factor * w / h[j];
+        }
+    };
+    add_m(k, (a.powi(3) - a) * h[k].powi(2) / 6.0);
+    add_m(k + 1, (b.powi(3) - b) * h[k].powi(2) / 6.0);
+    c
+}
+
+/// Solves `A w = e_i` for the tridiagonal matrix `A` of a natural cubic spline with the interval
+/// widths `h`, with the Thomas algorithm.
+#[cfg(feature = "alloc")]
+fn solve_tridiagonal(h: &[f64], i: usize, m: usize) -> Vec<f64> {
+    let diag = |r: usize| (h[r] + h[r + 1]) / 3.0;
+    // The off-diagonal element between rows `r` and `r + 1`
+    let off = |r: usize| h[r + 1] / 6.0;
+
+    let mut c_prime = vec![0.0; m];
+    let mut w = vec![0.0; m];
+    for r in 0..m {
+        let lower = if r == 0 { 0.0 } else { off(r - 1) };
+        let denom = diag(r) - lower * if r == 0 { 0.0 } else { c_prime[r - 1] };
+        if r + 1 < m {
+            c_prime[r] = off(r) / denom;
+        }
+        let rhs = if r == i { 1.0 } else { 0.0 };
+        let prev = if r == 0 { 0.0 } else { w[r - 1] };
+        w[r] = (rhs - lower * prev) / denom;
+    }
+    for r in (0..m.saturating_sub(1)).rev() {
+        w[r] -= c_prime[r] * w[r + 1];
+    }
+    w
+}
+
+/// Interpolates `table` with a natural cubic spline, or returns `None` if `x` is outside of the
+/// table.
+///
+/// The table must be sorted by `x`, with distinct `x`s. With two points, this is the same as
+/// [`linear`].
+///
+/// ```
+/// use val_unc::{interp, Unc, ValUnc};
+///
+/// let table: Vec<_> = (0..=4)
+///     .map(|i| (i as f64, ValUnc::new(f64::sin(i as f64), Unc(0.01))))
+///     .collect();
+/// let y = interp::spline(1.5, &table).unwrap();
+///
+/// assert!(f64::abs(y.val - f64::sin(1.5)) <= 0.01);
+/// assert!(y.unc.0 < 0.01);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn spline<U>(x: f64, table: &[(f64, ValUnc<f64, U>)]) -> Option<ValUnc<f64>>
+where
+    U: UncTotal<f64>,
+{
+    let k = interval(x, table)?;
+    let c = spline_coefficients(x, table, k);
+    let (val, var) = c
+        .iter()
+        .zip(table)
+        .fold((0.0, 0.0), |(val, var), (c, (_, y))| {
+            (val + c * y.val, var + (c * y.unc.total(y.val)).powi(2))
+        });
+    Some(ValUnc::new(val, Unc(var.sqrt())))
+}
+
+/// Like [`spline`], with the interpolation error estimated from the cubic through the four
+/// points nearest to the interval around `x`.
+///
+/// With fewer than four points, the error can't be estimated and is taken to be zero.
+#[cfg(feature = "alloc")]
+pub fn spline_with_error<U>(x: f64, table: &[(f64, ValUnc<f64, U>)]) -> Option<ValUnc<f64>>
+where
+    U: UncTotal<f64>,
+{
+    let v = spline(x, table)?;
+    if table.len() < 4 {
+        return Some(v);
+    }
+    let k = interval(x, table)?.saturating_sub(1).min(table.len() - 4);
+    Some(with_error(v, (lagrange(x, &table[k..k + 4]) - v.val).abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(f: fn(f64) -> f64, xs: &[f64]) -> Vec<(f64, ValUnc<f64>)> {
+        xs.iter()
+            .map(|&x| (x, ValUnc::new(f(x), Unc(0.1))))
+            .collect()
+    }
+
+    #[test]
+    fn linear_interp() {
+        let t = table(|x| x * x, &[0.0, 1.0, 2.0, 4.0]);
+
+        assert_eq!(linear(1.0, &t), Some(t[1].1));
+        assert_eq!(linear(4.0, &t), Some(t[3].1));
+        let y = linear(3.0, &t).unwrap();
+        assert_eq!(y.val, 10.0);
+        assert!(f64::abs(y.unc.0 - 0.1 * f64::sqrt(0.5)) <= 1e-12);
+        assert_eq!(linear(-0.5, &t), None);
+        assert_eq!(linear(f64::NAN, &t), None);
+
+        // The quadratic is exact, so the estimate is the whole error
+        let y = linear_with_error(1.5, &t).unwrap();
+        assert!(f64::abs(y.unc.0.powi(2) - (0.005 + 0.25f64.powi(2))) <= 1e-12);
+        assert_eq!(linear_with_error(0.5, &t[..2]), linear(0.5, &t[..2]));
+    }
+
+    #[test]
+    fn spline_interp() {
+        // A natural spline through a line is the line
+        let t = table(|x| 2.0 * x + 1.0, &[0.0, 0.5, 2.0, 3.0, 5.0]);
+        for &x in &[0.0, 0.25, 1.0, 2.0, 4.9] {
+            let s = spline(x, &t).unwrap();
+            assert!(f64::abs(s.val - (2.0 * x + 1.0)) <= 1e-12);
+        }
+        assert_eq!(spline(2.0, &t).unwrap().unc, Unc(0.1));
+        assert_eq!(spline(1.0, &t[1..3]), linear(1.0, &t[1..3]));
+
+        // The coefficients are those of the spline through each unit vector
+        let s = spline(1.0, &t).unwrap();
+        let y_unit = |j: usize| {
+            let unit: Vec<_> = t
+                .iter()
+                .enumerate()
+                .map(|(i, (x, _))| (*x, ValUnc::new(if i == j { 1.0 } else { 0.0 }, Unc(0.0))))
+                .collect();
+            spline(1.0, &unit).unwrap().val
+        };
+        assert!(f64::abs(y_unit(0) + 0.354_471_544_715_447) <= 1e-12);
+        let var: f64 = (0..t.len()).map(|j| (0.1 * y_unit(j)).powi(2)).sum();
+        assert!(f64::abs(s.unc.0 - var.sqrt()) <= 1e-12);
+
+        let e = spline_with_error(1.0, &t).unwrap();
+        assert!(f64::abs(e.unc.0 - s.unc.0) <= 1e-12);
+    }
+}
@@ -0,0 +1,103 @@
+//! Adapters for iterators of [`ValUnc`]s
+
+use crate::{
+    stats,
+    traits::{Real, UncTotal, UncWeight},
+    unc::Unc,
+    val_unc::ValUnc,
+};
+use core::{iter::Map, ops::Add};
+
+/// An iterator over values, returned by [`IterValUncExt::vals`] and
+/// [`IterValUncExt::total_uncs`]
+pub type Vals<I, V, U> = Map<I, fn(ValUnc<V, U>) -> V>;
+
+/// An iterator over uncertainties, returned by [`IterValUncExt::uncs`]
+pub type Uncs<I, V, U> = Map<I, fn(ValUnc<V, U>) -> U>;
+
+/// Extension methods for iterators of `ValUnc`s
+///
+/// This is implemented for every iterator of `ValUnc`s. For an iterator of references, e.g.
+/// `slice.iter()`, use `cloned()` first.
+///
+/// ```
+/// use val_unc::{IterValUncExt, Unc, ValUnc};
+///
+/// let v = [ValUnc::new(1.0, Unc(3.0)), ValUnc::new(2.0, Unc(4.0))];
+///
+/// assert_eq!(v.iter().cloned().vals().collect::<Vec<_>>(), vec![1.0, 2.0]);
+/// assert_eq!(v.iter().cloned().sum_val_unc(), Some(ValUnc::new(3.0, Unc(5.0))));
+/// ```
+pub trait IterValUncExt<V, U>: Iterator<Item = ValUnc<V, U>> + Sized {
+    /// Iterates over the values.
+    fn vals(self) -> Vals<Self, V, U> {
+        self.map(|v| v.val)
+    }
+
+    /// Iterates over the uncertainties.
+    fn uncs(self) -> Uncs<Self, V, U> {
+        self.map(|v| v.unc)
+    }
+
+    /// Iterates over the total uncertainties, according to [`UncTotal`].
+    fn total_uncs(self) -> Vals<Self, V, U>
+    where
+        U: UncTotal<V>,
+    {
+        self.map(|v| v.unc.total(v.val))
+    }
+
+    /// The mean of the values weighted according to [`UncWeight`], and its uncertainty, or `None`
+    /// if the iterator is empty. See [`stats::weighted_mean`].
+    fn weighted_mean(self) -> Option<ValUnc<V, Unc<V>>>
+    where
+        V: Real,
+        U: UncWeight<V>,
+    {
+        stats::weighted_mean(self)
+    }
+
+    /// Sums the elements, propagating their uncertainties, or returns `None` if the iterator is
+    /// empty.
+    fn sum_val_unc(self) -> Option<ValUnc<V, U>>
+    where
+        ValUnc<V, U>: Add<Output = ValUnc<V, U>>,
+    {
+        self.reduce(|a, b| a + b)
+    }
+}
+
+impl<I, V, U> IterValUncExt<V, U> for I where I: Iterator<Item = ValUnc<V, U>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unc::RelUnc;
+
+    #[test]
+    fn adapters() {
+        let v = [
+            ValUnc::new(2.0, RelUnc(0.5)),
+            ValUnc::new(4.0, RelUnc(0.25)),
+        ];
+
+        assert!(v.iter().cloned().vals().eq([2.0, 4.0]));
+        assert!(v.iter().cloned().uncs().eq([RelUnc(0.5), RelUnc(0.25)]));
+        assert!(v.iter().cloned().total_uncs().eq([1.0, 1.0]));
+    }
+
+    #[test]
+    fn weighted_mean() {
+        let v = [
+            ValUnc::new(1.0, Unc(1.0)),
+            ValUnc::new(2.0, Unc(0.5)),
+            ValUnc::new(4.0, Unc(0.5)),
+        ];
+        let mean = v.iter().cloned().weighted_mean().unwrap();
+
+        // The weights are 1, 4, and 4
+        assert!(f64::abs(mean.val - 25.0 / 9.0) <= f64::EPSILON);
+        assert!(f64::abs(mean.unc.0 - 1.0 / 3.0) <= f64::EPSILON);
+        assert_eq!(v[..0].iter().cloned().weighted_mean(), None);
+    }
+}
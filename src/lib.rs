@@ -19,14 +19,127 @@
 //!
 //! [uncertainty]: https://en.wikipedia.org/wiki/Propagation_of_uncertainty
 //!
+//! The commonly used items, including the traits, the uncertainty types, and the macros, can be
+//! imported with `use val_unc::prelude::*`; see [`prelude`].
+//!
 //! # Features
 //!
-//! The `serde` feature can be enabled for use with [`serde`]. A `ValUnc<V, U>`
-//! is (de)serialized as a `(V, U)` or if `unc` is zero, according to
-//! [`num-traits::Zero`], just a `V`.
+//! Each integration is behind its own feature. Only the `std` feature is enabled by default, and
+//! the `full` feature enables all the integrations. It deliberately leaves out `diagnostics` and
+//! `check-dims`, since they are opt-in debugging APIs rather than integrations.
+//!
+//! Without the `std` feature, the crate is `no_std`, and the `libm` feature must be enabled for
+//! the floating point functions. The `alloc` feature, which `std` enables, adds the items that
+//! allocate, e.g. [`UncMap`], [`Measurement`], [`ValUncVec`], [`fmt`], and [`parse`].
+//! The `arrow`, `csv`, `dashu`, `diagnostics`, `nalgebra`, `plotters`, `pyo3`, `schemars`,
+//! `statrs`, and `wasm` features require `std`, and `locale`, `ndarray`, `serde`, and `simd`
+//! require `alloc`.
+//!
+//! The `serde` feature can be enabled for use with [`serde`]. A `ValUnc<V, U>` is (de)serialized
+//! as a map with `val` and `unc` fields. Other representations are in [`serde_repr`], and
+//! [`serde_str`] (de)serializes it as a string like `"1.23+/-0.04"`.
+//!
+//! The `csv` feature adds [`csv`], for reading and writing `ValUnc`s as flat CSV columns, e.g.
+//! `energy,energy_unc_stat,energy_unc_sys`.
 //!
 //! [`serde`]: https://serde.rs
-//! [`num-traits::Zero`]: https://docs.rs/num-traits/*/num_traits/identities/trait.Zero.html
+//!
+//! The `schemars` feature implements [`schemars::JsonSchema`] for `ValUnc`, the wrappers in
+//! [`serde_repr`], and the uncertainty types in [`unc`], matching their serde representations.
+//!
+//! [`schemars::JsonSchema`]: https://docs.rs/schemars/*/schemars/trait.JsonSchema.html
+//!
+//! The `arrow` feature adds [`arrow`], for converting `ValUnc`s to and from Arrow `StructArray`s
+//! with a value column and one column per uncertainty component.
+//!
+//! The `nalgebra` feature adds [`RotationUnc`], a rotation with an uncertainty, and [`linalg`], for
+//! using `ValUnc`s in vectors and matrices, built on [`nalgebra`].
+//!
+//! [`nalgebra`]: https://nalgebra.org
+//!
+//! The `complex` feature adds [`ComplexUnc`], an uncertainty on a [`num_complex::Complex`] value
+//! with components for the real and imaginary parts, e.g. for impedances.
+//!
+//! [`num_complex::Complex`]: https://docs.rs/num-complex/*/num_complex/struct.Complex.html
+//!
+//! The `dashu` feature adds [`BigFloat`], a wrapper around `FBig`, an arbitrary precision float
+//! from [`dashu_float`], for when the precision of an `f64` isn't enough. It can be used with the
+//! bundled uncertainty types, e.g. `Unc<BigFloat>`, and `ValUnc<BigFloat, Unc<BigFloat>>`
+//! implements `Sqrt` and `Pow`.
+//!
+//! [`dashu_float`]: https://docs.rs/dashu-float
+//!
+//! The `ndarray` feature adds [`ndarray`], for using `ValUnc`s as the elements of `ndarray`
+//! arrays, with element-wise arithmetic and conversions to and from parallel arrays of values
+//! and uncertainties.
+//!
+//! The `simd` feature adds [`simd`], for adding, multiplying, and scaling slices of `ValUnc<f64>`
+//! four elements at a time with SIMD instructions, through [`wide`].
+//!
+//! [`wide`]: https://docs.rs/wide
+//!
+//! The `plotters` feature adds [`plotters`], for drawing `ValUnc`s as error bars with
+//! [`plotters`](https://docs.rs/plotters).
+//!
+//! The `pyo3` feature adds [`pyo3`], for converting `ValUnc<f64, Unc<f64>>` to and from Python
+//! `(nominal, std_dev)` tuples and `ufloat`s of the `uncertainties` package with
+//! [`pyo3`](https://pyo3.rs). Building it requires a Python interpreter.
+//!
+//! The `statrs` feature adds [`statrs`], for converting `ValUnc`s to and from the distributions of
+//! [`statrs`](https://docs.rs/statrs), with p-values and quantiles that follow the distribution
+//! of the uncertainty type, e.g. Student's t for [`DofUnc`].
+//!
+//! The `wasm` feature adds [`wasm`], a JavaScript class for `ValUnc<f64, Unc<f64>>` with
+//! arithmetic, parsing, and formatting, for use in the browser through
+//! [`wasm-bindgen`](https://docs.rs/wasm-bindgen).
+//!
+//! The `simple` feature implements the uncertainty traits for `f32` and `f64` themselves, with
+//! the same propagation as [`Unc`], so that `ValUnc<f64, f64>` can be used directly.
+//!
+//! The `diagnostics` feature adds [`diagnostics`], with [`Traced`](diagnostics::Traced), a wrapper
+//! around `ValUnc` whose operations check their result for NaN values and invalid uncertainties,
+//! and record the first offending operation.
+//!
+//! The `check-dims` feature adds [`dims`], with `checked_*` methods of `ValUnc` that also return a
+//! warning if a relative uncertainty formula is applied to a zero value, or if an addition or
+//! subtraction cancels catastrophically.
+//!
+//! The `ffi` feature adds [`ffi`], a `#[repr(C)]` mirror of `ValUnc<f64, Unc<f64>>` and C functions
+//! for its arithmetic, for exchanging values with C and C++ code.
+//!
+//! The `float` feature implements the `num-traits` traits `Num`, `Signed` and `Float` for
+//! `ValUnc<f32, Unc<f32>>` and `ValUnc<f64, Unc<f64>>`, propagating the uncertainty to first
+//! order, so that they can be used with generic numeric code.
+//!
+//! The `locale` feature adds [`fmt::Locale`], the decimal separator and digit grouping of common
+//! locales, looked up by a language tag like `"de-CH"`, for displaying `ValUnc`s with an
+//! [`UncFormatter`](fmt::UncFormatter) in non-English locales.
+//!
+//! The `derive` feature adds `#[derive(UncOps)]`, which implements the uncertainty traits for a
+//! struct field-wise, so that named structs can be used instead of tuples, and displays the fields
+//! labeled by their names. `UncZero` is only implemented with `#[unc(zero)]`, since not every
+//! uncertainty type implements it:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use val_unc::{Unc, UncOps, UncZero, ValUnc};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, UncOps)]
+//! #[unc(zero)]
+//! struct MyUnc {
+//!     stat: Unc<f64>,
+//!     sys: Unc<f64>,
+//! }
+//!
+//! let v1 = ValUnc::new(10.2, MyUnc { stat: Unc(4.0), sys: Unc(1.0) });
+//! let v2 = ValUnc::new(8.5, MyUnc { stat: Unc(3.0), sys: Unc(0.0) });
+//! let ValUnc { val, unc: MyUnc { stat, sys } } = v1 + v2;
+//!
+//! assert_eq!(format!("{:.1}", v1 + v2), "18.7 ± 5.0 (stat) ± 1.0 (sys)");
+//! assert!(MyUnc::zero().is_zero());
+//! # }
+//! ```
 //!
 //! # Examples
 //!
@@ -67,16 +180,106 @@
 //! // You can use destructuring to unpack the results
 //! let ValUnc { val, unc: (stat, sys) } = v1 + v2;
 //!
-//! assert!(f64::abs(val - 18.7) <= std::f64::EPSILON);
-//! assert!(f64::abs(stat.0 - 5.0) <= std::f64::EPSILON);
-//! assert!(f64::abs(sys.0 - 2.5) <= std::f64::EPSILON);
+//! assert!(f64::abs(val - 18.7) <= f64::EPSILON);
+//! assert!(f64::abs(stat.0 - 5.0) <= f64::EPSILON);
+//! assert!(f64::abs(sys.0 - 2.5) <= f64::EPSILON);
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("val_unc requires either the `std` or the `libm` feature");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+mod dist;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "std")]
+pub mod audit;
+pub mod compare;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "dashu")]
+mod dashu;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(feature = "check-dims")]
+pub mod dims;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fit;
+#[cfg(feature = "float")]
+mod float;
+#[cfg(feature = "alloc")]
+pub mod fmt;
+pub mod integrate;
+pub mod interp;
+pub mod iter;
+#[cfg(feature = "nalgebra")]
+pub mod linalg;
+pub mod measurement;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+pub mod order;
+#[cfg(feature = "alloc")]
+pub mod parse;
+#[cfg(feature = "plotters")]
+pub mod plotters;
+pub mod point;
+pub mod policy;
+pub mod prelude;
+pub mod propagate;
+#[cfg(feature = "pyo3")]
+pub mod pyo3;
+#[cfg(feature = "nalgebra")]
+pub mod rotation;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+pub mod serde_repr;
+#[cfg(feature = "serde")]
+pub mod serde_str;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(feature = "simple")]
+mod simple;
+#[cfg(feature = "alloc")]
+pub mod soa;
+#[cfg(feature = "statrs")]
+pub mod statrs;
+pub mod stats;
+pub mod storage;
 pub mod traits;
 pub mod unc;
 pub mod val_unc;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use self::{traits::*, unc::*, val_unc::*};
+#[cfg(feature = "dashu")]
+pub use self::dashu::BigFloat;
+#[cfg(feature = "alloc")]
+pub use self::parse::*;
+#[cfg(feature = "nalgebra")]
+pub use self::rotation::*;
+#[cfg(feature = "alloc")]
+pub use self::soa::*;
+pub use self::{
+    compare::*, iter::*, measurement::*, order::*, point::*, policy::*, propagate::*, storage::*,
+    traits::*, unc::*, val_unc::*,
+};
+#[cfg(feature = "derive")]
+pub use val_unc_derive::UncOps;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
 
 #[cfg(test)]
 mod tests {
@@ -111,8 +314,8 @@ mod tests {
             unc: (stat, sys),
         } = v1 + v2;
 
-        assert!(f64::abs(val - 18.7) <= std::f64::EPSILON);
-        assert!(f64::abs(stat.0 - 5.0) <= std::f64::EPSILON);
-        assert!(f64::abs(sys.0 - 2.5) <= std::f64::EPSILON);
+        assert!(f64::abs(val - 18.7) <= f64::EPSILON);
+        assert!(f64::abs(stat.0 - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(sys.0 - 2.5) <= f64::EPSILON);
     }
 }
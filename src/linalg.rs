@@ -0,0 +1,134 @@
+//! Using `ValUnc`s with `nalgebra` vectors and matrices
+//!
+//! A `ValUnc` is a [`nalgebra::Scalar`], so vectors and matrices of `ValUnc`s can be built and
+//! multiplied with the usual `nalgebra` operators, propagating the uncertainty of each element.
+//! Each element of a product is a sum of products of independent elements, so its uncertainty is
+//! correct, but the correlations between the elements of the result are lost.
+//!
+//! Often, e.g. for a coordinate transform, the matrix is exact and only the vector is measured.
+//! [`mul_vector`] and [`mul_vector_cov`] handle this case directly, and the latter also returns the
+//! covariance of the result.
+//!
+//! ```
+//! use nalgebra::{Matrix2, Vector2};
+//! use val_unc::{linalg, Unc, ValUnc};
+//!
+//! // A rotation by 90°
+//! let m = Matrix2::new(0.0, -1.0, 1.0, 0.0);
+//! let v = Vector2::new(ValUnc::new(1.0, Unc(0.1)), ValUnc::new(2.0, Unc(0.2)));
+//!
+//! assert_eq!(
+//!     linalg::mul_vector(&m, &v),
+//!     Vector2::new(ValUnc::new(-2.0, Unc(0.2)), ValUnc::new(1.0, Unc(0.1))),
+//! );
+//! ```
+
+use crate::{unc::Unc, val_unc::ValUnc};
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, Dim, Matrix, OMatrix, OVector, Storage, Vector, U1,
+};
+
+/// Multiplies the vector `v` by the exact matrix `m`, adding the contributions to the uncertainty
+/// of each element in quadrature.
+///
+/// # Panics
+///
+/// Panics if the number of columns of `m` isn't the length of `v`.
+pub fn mul_vector<R, C, S1, S2>(
+    m: &Matrix<f64, R, C, S1>,
+    v: &Vector<ValUnc<f64>, C, S2>,
+) -> OVector<ValUnc<f64>, R>
+where
+    R: Dim,
+    C: Dim,
+    S1: Storage<f64, R, C>,
+    S2: Storage<ValUnc<f64>, C>,
+    DefaultAllocator: Allocator<R>,
+{
+    assert_eq!(
+        m.ncols(),
+        v.nrows(),
+        "the matrix and vector are incompatible"
+    );
+    let (nrows, _) = m.shape_generic();
+    OVector::from_fn_generic(nrows, U1, |i, _| {
+        let (val, var) = m
+            .row(i)
+            .iter()
+            .zip(v.iter())
+            .fold((0.0, 0.0), |(val, var), (a, x)| {
+                (val + a * x.val, var + (a * x.unc.0).powi(2))
+            });
+        ValUnc::new(val, Unc(var.sqrt()))
+    })
+}
+
+/// Multiplies the vector `v`, with covariance `cov`, by the exact matrix `m`, returning the result
+/// and its covariance `m cov mᵀ`.
+///
+/// # Panics
+///
+/// Panics if the number of columns of `m` isn't the length of `v`.
+pub fn mul_vector_cov<R, C, S1, S2, S3>(
+    m: &Matrix<f64, R, C, S1>,
+    v: &Vector<f64, C, S2>,
+    cov: &Matrix<f64, C, C, S3>,
+) -> (OVector<f64, R>, OMatrix<f64, R, R>)
+where
+    R: Dim,
+    C: Dim,
+    S1: Storage<f64, R, C>,
+    S2: Storage<f64, C>,
+    S3: Storage<f64, C, C>,
+    DefaultAllocator: Allocator<R> + Allocator<R, R> + Allocator<R, C> + Allocator<C, R>,
+{
+    assert_eq!(
+        m.ncols(),
+        v.nrows(),
+        "the matrix and vector are incompatible"
+    );
+    (m * v, m * cov * m.transpose())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2, Matrix2x3, Matrix3, Vector2, Vector3};
+
+    #[test]
+    fn matrix_of_val_uncs() {
+        let m = Matrix2::new(
+            ValUnc::new(1.0, Unc(0.1)),
+            ValUnc::new(2.0, Unc(0.2)),
+            ValUnc::new(3.0, Unc(0.3)),
+            ValUnc::new(4.0, Unc(0.4)),
+        );
+        let v = Vector2::new(ValUnc::new(5.0, Unc(0.5)), ValUnc::new(6.0, Unc(0.6)));
+        let mv = m * v;
+
+        assert_eq!(mv[0], m[(0, 0)] * v[0] + m[(0, 1)] * v[1]);
+        assert_eq!(mv[1], m[(1, 0)] * v[0] + m[(1, 1)] * v[1]);
+    }
+
+    #[test]
+    fn exact_matrix() {
+        let m = Matrix2x3::new(1.0, 2.0, 3.0, -1.0, 0.5, 2.0);
+        let v = Vector3::new(
+            ValUnc::new(1.0, Unc(0.1)),
+            ValUnc::new(2.0, Unc(0.2)),
+            ValUnc::new(3.0, Unc(0.3)),
+        );
+        let mv = mul_vector(&m, &v);
+
+        assert!(f64::abs(mv[0].val - 14.0) <= 1e-12);
+        assert!(f64::abs(mv[0].unc.0 - f64::sqrt(0.01 + 0.16 + 0.81)) <= 1e-12);
+        assert!(f64::abs(mv[1].val - 6.0) <= 1e-12);
+
+        let cov = Matrix3::from_diagonal(&v.map(|x| x.unc.0.powi(2)));
+        let (val, cov) = mul_vector_cov(&m, &v.map(|x| x.val), &cov);
+        assert_eq!(val, mv.map(|x| x.val));
+        assert!(f64::abs(cov[(0, 0)].sqrt() - mv[0].unc.0) <= 1e-12);
+        assert!(f64::abs(cov[(1, 1)].sqrt() - mv[1].unc.0) <= 1e-12);
+        assert!(f64::abs(cov[(0, 1)] - (-0.01 + 0.04 + 0.54)) <= 1e-12);
+    }
+}
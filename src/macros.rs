@@ -0,0 +1,309 @@
+/// Defines a newtype uncertainty with all of the uncertainty traits implemented
+///
+/// Each definition gives the name of the type, the wrapped type, which must implement
+/// [`Float`](crate::Float), and the rule used to combine uncertainties:
+///
+/// - `quadrature`: `√(a² + b²)`, for independent uncertainties, like [`Unc`](crate::Unc)
+/// - `linear`: `a + b`, for fully correlated uncertainties
+/// - `max`: `max(a, b)`, for conservative estimates
+///
+/// For `Add` and `Sub`, the rule is applied to the absolute uncertainties. For `Mul` and `Div`,
+/// it is applied to the relative uncertainties, so these are only implemented when the value has
+/// the same type as the wrapped type.
+///
+/// The type also implements `UncRound`, `UncValidate`, `UncZero`, `UncDisplay`, `Default`,
+/// `Display` (which displays the wrapped value), `From` the wrapped type, and, if the `serde`
+/// feature is enabled, `Serialize` and `Deserialize` (as the wrapped value).
+///
+/// A label can be given after the rule, in which case it is displayed after the uncertainty by
+/// `UncDisplay` (see [`fmt_labeled`](crate::traits::display::fmt_labeled)). This requires the
+/// wrapped type to implement [`UncDisplay`](crate::UncDisplay), e.g. `f64`.
+///
+/// ```
+/// use val_unc::{define_unc, ValUnc};
+///
+/// define_unc! {
+///     /// Statistical uncertainties
+///     pub struct StatUnc(f64) = quadrature, "stat";
+///     /// Systematic uncertainties
+///     pub struct SysUnc(f64) = linear, "sys";
+/// }
+///
+/// let v1 = ValUnc::new(10.2, (StatUnc(4.0), SysUnc(1.25)));
+/// let v2 = ValUnc::new(8.5, (StatUnc(3.0), SysUnc(1.25)));
+/// let sum = v1 + v2;
+/// let ValUnc { val, unc: (stat, sys) } = sum;
+///
+/// assert!(f64::abs(stat.0 - 5.0) <= f64::EPSILON);
+/// assert!(f64::abs(sys.0 - 2.5) <= f64::EPSILON);
+/// assert_eq!(format!("{:.1}", sum), "18.7 ± 5.0 (stat) ± 2.5 (sys)");
+/// ```
+#[macro_export]
+macro_rules! define_unc {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($T:ty) = $rule:ident $(, $label:literal)?;
+    )+) => {$(
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+        $vis struct $name(pub $T);
+
+        impl ::core::convert::From<$T> for $name {
+            fn from(unc: $T) -> Self {
+                $name(unc)
+            }
+        }
+
+        impl<V> $crate::UncAdd<V> for $name {
+            fn unc_add(self, _self_val: V, other: Self, _other_val: V) -> Self {
+                $name($crate::__define_unc_rule!($rule, self.0, other.0))
+            }
+        }
+
+        impl $crate::UncDiv<$T> for $name {
+            fn unc_div(self, self_val: $T, other: Self, other_val: $T) -> Self {
+                $name(
+                    $crate::__define_unc_rule!(
+                        $rule,
+                        self.0 / $crate::Float::abs(self_val),
+                        other.0 / $crate::Float::abs(other_val)
+                    ) * $crate::Float::abs(self_val / other_val),
+                )
+            }
+        }
+
+        impl $crate::UncMul<$T> for $name {
+            fn unc_mul(self, self_val: $T, other: Self, other_val: $T) -> Self {
+                $name(
+                    $crate::__define_unc_rule!(
+                        $rule,
+                        self.0 / $crate::Float::abs(self_val),
+                        other.0 / $crate::Float::abs(other_val)
+                    ) * $crate::Float::abs(self_val * other_val),
+                )
+            }
+        }
+
+        impl<V> $crate::UncNeg<V> for $name {
+            fn unc_neg(self, _self_val: V) -> Self {
+                self
+            }
+        }
+
+        impl $crate::UncRound for $name {
+            fn round_at(&self, places: i32) -> Self {
+                $name($crate::traits::round::round_float(self.0, places))
+            }
+        }
+
+        impl<V> $crate::UncSub<V> for $name {
+            fn unc_sub(self, _self_val: V, other: Self, _other_val: V) -> Self {
+                $name($crate::__define_unc_rule!($rule, self.0, other.0))
+            }
+        }
+
+        impl $crate::UncValidate for $name {
+            fn is_valid(&self) -> bool {
+                // This is false for NaN
+                self.0 >= $crate::Zero::zero()
+            }
+
+            fn clamp_invalid(&mut self) {
+                if !$crate::UncValidate::is_valid(self) {
+                    self.0 = $crate::Zero::zero();
+                }
+            }
+        }
+
+        impl $crate::UncZero for $name {
+            fn zero() -> Self {
+                $name($crate::Zero::zero())
+            }
+
+            fn is_zero(&self) -> bool {
+                $crate::Zero::is_zero(&self.0)
+            }
+
+            fn set_zero(&mut self) {
+                $crate::Zero::set_zero(&mut self.0)
+            }
+        }
+
+        $crate::__define_unc_display!($name $(, $label)?);
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        $crate::__define_unc_serde!($name, $T);
+    )+};
+}
+
+/// Creates a [`ValUnc`](crate::ValUnc)
+///
+/// `val_unc!(1.23 +- 0.04)` (or `+/-`) creates a `ValUnc` with an [`Unc`](crate::Unc). `±` can't
+/// be used, since it isn't a Rust token. The value must be a literal, e.g. `1.23` or `-1.23`.
+///
+/// Importing the macro with `use val_unc::val_unc` also imports the [`val_unc`](mod@crate::val_unc)
+/// module, which shadows the crate, so call it with its path or import it from the
+/// [`prelude`](crate::prelude).
+///
+/// `val_unc!(1.23, stat = 0.04, sys = 0.01)` creates a `ValUnc` with a tuple of uncertainties,
+/// each converted with `From`, so the types are inferred. The names are only for readability and
+/// aren't checked.
+///
+/// ```
+/// use val_unc::{define_unc, Unc, ValUnc};
+///
+/// define_unc! {
+///     pub struct StatUnc(f64) = quadrature, "stat";
+///     pub struct SysUnc(f64) = linear, "sys";
+/// }
+///
+/// assert_eq!(val_unc::val_unc!(1.23 +- 0.04), ValUnc::new(1.23, Unc(0.04)));
+/// assert_eq!(val_unc::val_unc!(-1.23 +/- 0.04), ValUnc::new(-1.23, Unc(0.04)));
+///
+/// let v: ValUnc<f64, (StatUnc, SysUnc)> = val_unc::val_unc!(1.23, stat = 0.04, sys = 0.01);
+/// assert_eq!(v, ValUnc::new(1.23, (StatUnc(0.04), SysUnc(0.01))));
+/// ```
+#[macro_export]
+macro_rules! val_unc {
+    ($val:literal +- $unc:expr) => {
+        $crate::ValUnc::new($val, $crate::Unc($unc))
+    };
+    ($val:literal +/- $unc:expr) => {
+        $crate::ValUnc::new($val, $crate::Unc($unc))
+    };
+    ($val:expr, $($name:ident = $unc:expr),+ $(,)?) => {
+        $crate::ValUnc::new($val, ($(::core::convert::From::from($unc),)+))
+    };
+}
+
+// Only in the macro namespace, so that the prelude can export it without the `val_unc` module
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __val_unc {
+    ($($tt:tt)*) => {
+        $crate::val_unc!($($tt)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_unc_rule {
+    (quadrature, $a:expr, $b:expr) => {
+        $crate::Float::sqrt($crate::Float::powi($a, 2) + $crate::Float::powi($b, 2))
+    };
+    (linear, $a:expr, $b:expr) => {
+        $a + $b
+    };
+    (max, $a:expr, $b:expr) => {
+        $crate::Float::max($a, $b)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_unc_display {
+    ($name:ident) => {
+        impl<V> $crate::UncDisplay<V> for $name {
+            fn fmt_unc(&self, _val: &V, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.write_str(" ± ")?;
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+    ($name:ident, $label:literal) => {
+        impl<V> $crate::UncDisplay<V> for $name {
+            fn fmt_unc(&self, val: &V, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                $crate::traits::display::fmt_labeled(&self.0, val, $label, f)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_unc_serde {
+    ($name:ident, $T:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                <$T as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map($name)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_unc_serde {
+    ($name:ident, $T:ty) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UncDiv, UncMul, ValUnc};
+
+    define_unc! {
+        struct QuadUnc(f64) = quadrature;
+        struct LinUnc(f64) = linear;
+        struct MaxUnc(f32) = max;
+    }
+
+    #[test]
+    fn define_unc() {
+        let v1 = ValUnc::new(2.0, (QuadUnc(0.3), LinUnc(0.3)));
+        let v2 = ValUnc::new(1.0, (QuadUnc(0.4), LinUnc(0.4)));
+
+        let ValUnc {
+            val,
+            unc: (quad, lin),
+        } = v1 - v2;
+        assert!(f64::abs(val - 1.0) <= f64::EPSILON);
+        assert!(f64::abs(quad.0 - 0.5) <= f64::EPSILON);
+        assert!(f64::abs(lin.0 - 0.7) <= f64::EPSILON);
+
+        // Relative uncertainties of 0.15 and 0.4
+        let ValUnc {
+            val,
+            unc: (quad, lin),
+        } = v1 * v2;
+        assert!(f64::abs(val - 2.0) <= f64::EPSILON);
+        assert!(f64::abs(quad.0 - 2.0 * f64::sqrt(0.15 * 0.15 + 0.16)) <= f64::EPSILON);
+        assert!(f64::abs(lin.0 - 1.1) <= 1e-12);
+
+        let quot = LinUnc(0.3).unc_div(2.0, LinUnc(0.4), 1.0);
+        assert!(f64::abs(quot.0 - 1.1) <= 1e-12);
+
+        let prod = MaxUnc(0.3).unc_mul(-2.0, MaxUnc(0.4), 1.0);
+        assert!(f32::abs(prod.0 - 0.8) <= f32::EPSILON);
+        assert_eq!(format!("{:.2}", prod), "0.80");
+    }
+
+    #[test]
+    fn val_unc() {
+        assert_eq!(val_unc!(2.0 + -0.3), ValUnc::new(2.0, crate::Unc(0.3)));
+
+        let v: ValUnc<f64, (QuadUnc,)> = val_unc!(2.0, quad = 0.3);
+        assert_eq!(v, ValUnc::new(2.0, (QuadUnc(0.3),)));
+        let v: ValUnc<f64, (QuadUnc, LinUnc)> = val_unc!(2.0, quad = 0.3, lin = 0.4,);
+        assert_eq!(v, ValUnc::new(2.0, (QuadUnc(0.3), LinUnc(0.4))));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{unc::*, val_unc::ValUnc};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Fluent construction of `ValUnc`s from numbers
+///
+/// ```
+/// use val_unc::{PlusMinus, Unc, ValUnc};
+///
+/// assert_eq!(1.23.pm(0.04), ValUnc::new(1.23, Unc(0.04)));
+///
+/// let m = 1.23.with_stat(0.04).with_sys(0.01);
+/// assert_eq!(m.unc.0["stat"], Unc(0.04));
+/// assert_eq!(m.unc.0["sys"], Unc(0.01));
+/// ```
+pub trait PlusMinus: Sized {
+    /// Returns a `ValUnc` with an [`Unc`].
+    fn pm(self, unc: Self) -> ValUnc<Self, Unc<Self>>;
+
+    /// Returns a [`Measurement`] with a statistical uncertainty.
+    #[cfg(feature = "alloc")]
+    fn with_stat(self, unc: Self) -> Measurement<Self>;
+
+    /// Returns a [`Measurement`] with a systematic uncertainty.
+    #[cfg(feature = "alloc")]
+    fn with_sys(self, unc: Self) -> Measurement<Self>;
+}
+
+macro_rules! plus_minus_impls {
+    ($($T:ty),*) => {$(
+        impl PlusMinus for $T {
+            fn pm(self, unc: Self) -> ValUnc<Self, Unc<Self>> {
+                ValUnc::new(self, Unc(unc))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn with_stat(self, unc: Self) -> Measurement<Self> {
+                ValUnc::new(self, UncMap::new()).with_stat(unc)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn with_sys(self, unc: Self) -> Measurement<Self> {
+                ValUnc::new(self, UncMap::new()).with_sys(unc)
+            }
+        }
+    )*}
+}
+
+plus_minus_impls!(f32, f64);
+
+/// A value with named uncertainty components
+#[cfg(feature = "alloc")]
+pub type Measurement<T> = ValUnc<T, UncMap<Unc<T>>>;
+
+#[cfg(feature = "alloc")]
+impl<T> Measurement<T>
+where
+    T: Default,
+{
+    /// Returns a builder for a `Measurement`.
+    ///
+    /// ```
+    /// use val_unc::Measurement;
+    ///
+    /// let m = Measurement::builder()
+    ///     .value(10.2)
+    ///     .stat(4.0)
+    ///     .sys("jes", 1.1)
+    ///     .sys("lumi", 0.4)
+    ///     .build();
+    ///
+    /// assert_eq!(m.unc.0["jes"].0, 1.1);
+    /// ```
+    pub fn builder() -> MeasurementBuilder<T> {
+        MeasurementBuilder {
+            val: T::default(),
+            unc: UncMap::new(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Measurement<T> {
+    /// Sets the statistical uncertainty, stored under the key
+    /// [`MeasurementBuilder::STAT`].
+    pub fn with_stat(self, unc: T) -> Self {
+        self.with(MeasurementBuilder::<T>::STAT, unc)
+    }
+
+    /// Sets the systematic uncertainty, stored under the key [`MeasurementBuilder::SYS`].
+    pub fn with_sys(self, unc: T) -> Self {
+        self.with(MeasurementBuilder::<T>::SYS, unc)
+    }
+
+    /// Sets the uncertainty named `name`, replacing any earlier uncertainty with that name.
+    pub fn with<K: Into<String>>(mut self, name: K, unc: T) -> Self {
+        self.unc.0.insert(name.into(), Unc(unc));
+        self
+    }
+}
+
+/// A builder for [`Measurement`]s
+///
+/// Setting the same component more than once replaces the earlier uncertainty.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct MeasurementBuilder<T> {
+    val: T,
+    unc: UncMap<Unc<T>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> MeasurementBuilder<T> {
+    /// The key used for the statistical uncertainty.
+    pub const STAT: &'static str = "stat";
+    /// The key used for the systematic uncertainty of [`Measurement::with_sys`].
+    pub const SYS: &'static str = "sys";
+
+    pub fn value(mut self, val: T) -> Self {
+        self.val = val;
+        self
+    }
+
+    /// Sets the statistical uncertainty, stored under the key [`STAT`](Self::STAT).
+    pub fn stat(self, unc: T) -> Self {
+        self.sys(Self::STAT, unc)
+    }
+
+    /// Sets the systematic uncertainty named `name`.
+    pub fn sys<K: Into<String>>(mut self, name: K, unc: T) -> Self {
+        self.unc.0.insert(name.into(), Unc(unc));
+        self
+    }
+
+    pub fn build(self) -> Measurement<T> {
+        ValUnc::new(self.val, self.unc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_and_add() {
+        let m1 = Measurement::builder()
+            .value(10.2)
+            .stat(4.0)
+            .sys("jes", 1.1)
+            .build();
+        let m2 = Measurement::builder()
+            .value(8.5)
+            .stat(3.0)
+            .sys("lumi", 0.4)
+            .build();
+
+        let ValUnc { val, unc } = m1 + m2;
+        assert!(f64::abs(val - 18.7) <= f64::EPSILON);
+        assert_eq!(unc.0.len(), 3);
+        assert!(f64::abs(unc.0["stat"].0 - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(unc.0["jes"].0 - 1.1) <= f64::EPSILON);
+        assert!(f64::abs(unc.0["lumi"].0 - 0.4) <= f64::EPSILON);
+    }
+
+    #[test]
+    fn plus_minus() {
+        let m = 10.2.with_stat(4.0).with_sys(1.1).with("lumi", 0.4);
+        let built = Measurement::builder()
+            .value(10.2)
+            .stat(4.0)
+            .sys("sys", 1.1)
+            .sys("lumi", 0.4)
+            .build();
+        assert_eq!(m, built);
+        assert_eq!(2.0f32.pm(0.5), ValUnc::new(2.0, Unc(0.5)));
+    }
+}
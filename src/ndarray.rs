@@ -0,0 +1,94 @@
+//! Using `ValUnc`s as the elements of `ndarray` arrays
+//!
+//! The arithmetic operators of `ndarray` work element-wise on arrays of `ValUnc`s, propagating
+//! the uncertainties of each element, and `ValUnc` implements [`ScalarOperand`], so an array can
+//! also be combined with a single `ValUnc`. [`split_vals_uncs`] and [`from_vals_uncs`] convert to
+//! and from parallel arrays of values and uncertainties, e.g. for use with numeric code that
+//! doesn't know about this crate.
+//!
+//! ```
+//! use ndarray::array;
+//! use val_unc::{ndarray::{from_vals_uncs, split_vals_uncs}, Unc, ValUnc};
+//!
+//! let a = from_vals_uncs(&array![1.0, 2.0], &array![Unc(0.75), Unc(1.5)]).unwrap();
+//! let b = from_vals_uncs(&array![3.0, 4.0], &array![Unc(1.0), Unc(2.0)]).unwrap();
+//! let (vals, uncs) = split_vals_uncs(&(&a + &b));
+//!
+//! assert_eq!(vals, array![4.0, 6.0]);
+//! assert_eq!(uncs, array![Unc(1.25), Unc(2.5)]);
+//! ```
+
+use crate::val_unc::ValUnc;
+use ::ndarray::{Array, ArrayBase, Data, Dimension, ErrorKind, ScalarOperand, ShapeError, Zip};
+
+impl<V, U> ScalarOperand for ValUnc<V, U>
+where
+    V: Clone + 'static,
+    U: Clone + 'static,
+{
+}
+
+/// Splits an array of `ValUnc`s into an array of the values and an array of the uncertainties.
+pub fn split_vals_uncs<S, D, V, U>(a: &ArrayBase<S, D>) -> (Array<V, D>, Array<U, D>)
+where
+    S: Data<Elem = ValUnc<V, U>>,
+    D: Dimension,
+    V: Clone,
+    U: Clone,
+{
+    (a.map(|v| v.val.clone()), a.map(|v| v.unc.clone()))
+}
+
+/// Combines an array of values and an array of uncertainties into an array of `ValUnc`s.
+///
+/// The arrays must have the same shape.
+pub fn from_vals_uncs<S1, S2, D, V, U>(
+    vals: &ArrayBase<S1, D>,
+    uncs: &ArrayBase<S2, D>,
+) -> Result<Array<ValUnc<V, U>, D>, ShapeError>
+where
+    S1: Data<Elem = V>,
+    S2: Data<Elem = U>,
+    D: Dimension,
+    V: Clone,
+    U: Clone,
+{
+    if vals.shape() != uncs.shape() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    }
+    Ok(Zip::from(vals)
+        .and(uncs)
+        .map_collect(|v, u| ValUnc::new(v.clone(), u.clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unc::Unc;
+    use ::ndarray::array;
+
+    #[test]
+    fn element_wise() {
+        let a = array![
+            [ValUnc::new(1.0, Unc(0.3)), ValUnc::new(2.0, Unc(0.6))],
+            [ValUnc::new(3.0, Unc(0.9)), ValUnc::new(4.0, Unc(1.2))],
+        ];
+        let b = a.map(|v| ValUnc::new(v.val * 3.0, Unc(v.unc.0 * 4.0)));
+        let c = ValUnc::new(2.0, Unc(0.1));
+
+        assert_eq!(&a - &b, Zip::from(&a).and(&b).map_collect(|x, y| *x - *y));
+        assert_eq!(&a / &b, Zip::from(&a).and(&b).map_collect(|x, y| *x / *y));
+        assert_eq!(&a * c, a.map(|x| *x * c));
+    }
+
+    #[test]
+    fn split_combine() {
+        let vals = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let uncs = vals.map(|v| Unc(v / 10.0));
+        let a = from_vals_uncs(&vals, &uncs).unwrap();
+
+        assert_eq!(a[[1, 2]], ValUnc::new(6.0, Unc(0.6)));
+        assert_eq!(split_vals_uncs(&a), (vals.clone(), uncs));
+        assert!(from_vals_uncs(&vals, &vals.t()).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Ordering of `ValUnc`s by their float values
+//!
+//! The derived `Ord` of `ValUnc` requires `V: Ord`, which floats don't implement, so these use
+//! the IEEE 754 total order (see [`f64::total_cmp`]) instead.
+
+use crate::val_unc::ValUnc;
+use core::cmp::Ordering;
+
+/// A total order, e.g. [`f64::total_cmp`]
+pub trait TotalCmp {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! total_cmp_impl {
+    ($($T:ident),+) => {$(
+        impl TotalCmp for $T {
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $T::total_cmp(self, other)
+            }
+        }
+    )+}
+}
+
+total_cmp_impl!(f32, f64);
+
+/// Compares the values of `a` and `b` with their total order, ignoring the uncertainties.
+pub fn total_cmp_by_val<V, U>(a: &ValUnc<V, U>, b: &ValUnc<V, U>) -> Ordering
+where
+    V: TotalCmp,
+{
+    a.val.total_cmp(&b.val)
+}
+
+/// Sorts `slice` by value (see [`total_cmp_by_val`]).
+///
+/// The sort is stable, so elements with equal values keep their order.
+///
+/// ```
+/// use val_unc::{sort_by_val, Unc, ValUnc};
+///
+/// let mut v = vec![
+///     ValUnc::new(2.0, Unc(0.1)),
+///     ValUnc::new(f64::NAN, Unc(0.1)),
+///     ValUnc::new(1.0, Unc(0.2)),
+/// ];
+/// sort_by_val(&mut v);
+///
+/// assert_eq!(v[0].val, 1.0);
+/// assert_eq!(v[1].val, 2.0);
+/// assert!(v[2].val.is_nan());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sort_by_val<V, U>(slice: &mut [ValUnc<V, U>])
+where
+    V: TotalCmp,
+{
+    slice.sort_by(total_cmp_by_val)
+}
+
+/// A `ValUnc` that is ordered by its value (see [`total_cmp_by_val`]), e.g. for use as a
+/// `BTreeMap` key
+///
+/// The uncertainties are ignored, so two `OrderedValUnc`s with the same value are equal.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrderedValUnc<V, U>(pub ValUnc<V, U>);
+
+impl<V, U> From<ValUnc<V, U>> for OrderedValUnc<V, U> {
+    fn from(v: ValUnc<V, U>) -> Self {
+        Self(v)
+    }
+}
+
+impl<V, U> PartialEq for OrderedValUnc<V, U>
+where
+    V: TotalCmp,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<V, U> Eq for OrderedValUnc<V, U> where V: TotalCmp {}
+
+impl<V, U> PartialOrd for OrderedValUnc<V, U>
+where
+    V: TotalCmp,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V, U> Ord for OrderedValUnc<V, U>
+where
+    V: TotalCmp,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        total_cmp_by_val(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unc;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn btree_key() {
+        let mut m = BTreeMap::new();
+        m.insert(OrderedValUnc(ValUnc::new(2.0, Unc(0.1))), "b");
+        m.insert(OrderedValUnc(ValUnc::new(-0.0, Unc(0.1))), "z");
+        m.insert(OrderedValUnc(ValUnc::new(1.0, Unc(0.1))), "a");
+
+        assert_eq!(m.values().copied().collect::<Vec<_>>(), vec!["z", "a", "b"]);
+        assert_eq!(m[&OrderedValUnc(ValUnc::new(1.0, Unc(0.5)))], "a");
+        assert!(
+            OrderedValUnc(ValUnc::new(-0.0, Unc(0.0))) < OrderedValUnc(ValUnc::new(0.0, Unc(0.0)))
+        );
+    }
+}
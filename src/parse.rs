@@ -0,0 +1,260 @@
+//! Parsing of `ValUnc`s from text
+//!
+//! The accepted notations are `1.23 ± 0.04`, `1.23 +/- 0.04` (or `+-`), and the parenthesis
+//! notation `1.23(4)`, where the digits in parentheses are the uncertainty in the last digits of
+//! the value. Numbers can have exponents, e.g. `1.23e3 ± 5e1` or `1.23(4)e3`. With
+//! [`ParseOptions`], a bare number can also be given an uncertainty implied by its last decimal
+//! place.
+
+use crate::val_unc::ValUnc;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::{fmt, str::FromStr};
+
+/// The error returned when a `ValUnc` can't be parsed
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ParseValUncError {
+    /// The string isn't in one of the accepted notations.
+    Syntax,
+    /// The value can't be parsed.
+    Val,
+    /// The uncertainty can't be parsed.
+    Unc,
+}
+
+impl fmt::Display for ParseValUncError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Syntax => write!(f, "invalid value with uncertainty syntax"),
+            Self::Val => write!(f, "invalid value"),
+            Self::Unc => write!(f, "invalid uncertainty"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseValUncError {}
+
+/// The uncertainty implied by the last decimal place of a bare number
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum ImpliedUnc {
+    /// A bare number is an error.
+    #[default]
+    None,
+    /// Half a unit in the last place, e.g. `1.230` is `1.230 ± 0.0005`
+    HalfUnit,
+    /// One unit in the last place, e.g. `1.230` is `1.230 ± 0.001`
+    OneUnit,
+}
+
+/// Options for parsing `ValUnc`s
+///
+/// ```
+/// use val_unc::{ImpliedUnc, ParseOptions, Unc, ValUnc};
+///
+/// let options = ParseOptions::new().implied_unc(ImpliedUnc::HalfUnit);
+/// let v: ValUnc<f64, Unc<f64>> = options.parse("1.230").unwrap();
+/// assert_eq!(v, ValUnc::new(1.23, Unc(0.0005)));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct ParseOptions {
+    implied_unc: ImpliedUnc,
+}
+
+impl ParseOptions {
+    pub const fn new() -> Self {
+        Self {
+            implied_unc: ImpliedUnc::None,
+        }
+    }
+
+    /// Sets the uncertainty of a bare number.
+    pub fn implied_unc(mut self, implied_unc: ImpliedUnc) -> Self {
+        self.implied_unc = implied_unc;
+        self
+    }
+
+    /// Parses a `ValUnc` in one of the accepted notations (see the [module
+    /// documentation](self)).
+    pub fn parse<V, U>(&self, s: &str) -> Result<ValUnc<V, U>, ParseValUncError>
+    where
+        V: FromStr,
+        U: From<V>,
+    {
+        let (val, unc) = self.split(s.trim())?;
+        let val = val.parse().map_err(|_| ParseValUncError::Val)?;
+        let unc = unc.parse::<V>().map_err(|_| ParseValUncError::Unc)?;
+        Ok(ValUnc::new(val, unc.into()))
+    }
+
+    /// Splits `s` into the value and the uncertainty, converting the parenthesis notation and
+    /// implied uncertainties to absolute numbers.
+    fn split(&self, s: &str) -> Result<(String, String), ParseValUncError> {
+        for sep in &["±", "+/-", "+-"] {
+            if let Some(i) = s.find(sep) {
+                let (val, unc) = (s[..i].trim(), s[i + sep.len()..].trim());
+                if val.is_empty() || unc.is_empty() {
+                    return Err(ParseValUncError::Syntax);
+                }
+                return Ok((val.to_string(), unc.to_string()));
+            }
+        }
+
+        let open = match s.find('(') {
+            Some(open) => open,
+            None => return self.implied(s),
+        };
+        let close = s.find(')').ok_or(ParseValUncError::Syntax)?;
+        if close < open {
+            return Err(ParseValUncError::Syntax);
+        }
+        let (mantissa, digits, exp) = (&s[..open], &s[open + 1..close], &s[close + 1..]);
+        if mantissa.is_empty() || digits.is_empty() {
+            return Err(ParseValUncError::Syntax);
+        }
+        if !digits.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return Err(ParseValUncError::Unc);
+        }
+
+        let exp_val = match exp.strip_prefix(['e', 'E']) {
+            Some(e) => e.parse::<i32>().map_err(|_| ParseValUncError::Val)?,
+            None if exp.is_empty() => 0,
+            None => return Err(ParseValUncError::Syntax),
+        };
+        let val = format!("{}{}", mantissa, exp);
+        let unc = if digits.contains('.') {
+            // The uncertainty is written out, e.g. `12345.6(5.7)`
+            format!("{}e{}", digits, exp_val)
+        } else {
+            format!("{}e{}", digits, exp_val - decimals(mantissa))
+        };
+        Ok((val, unc))
+    }
+
+    /// Returns the uncertainty implied by the bare number `s`.
+    fn implied(&self, s: &str) -> Result<(String, String), ParseValUncError> {
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(i) => (
+                &s[..i],
+                s[i + 1..]
+                    .parse::<i32>()
+                    .map_err(|_| ParseValUncError::Val)?,
+            ),
+            None => (s, 0),
+        };
+        if mantissa.is_empty() {
+            return Err(ParseValUncError::Syntax);
+        }
+
+        let place = exp - decimals(mantissa);
+        let unc = match self.implied_unc {
+            ImpliedUnc::None => return Err(ParseValUncError::Syntax),
+            ImpliedUnc::HalfUnit => format!("5e{}", place - 1),
+            ImpliedUnc::OneUnit => format!("1e{}", place),
+        };
+        Ok((s.to_string(), unc))
+    }
+}
+
+/// Returns the number of digits after the decimal point of `mantissa`.
+fn decimals(mantissa: &str) -> i32 {
+    mantissa.find('.').map_or(0, |i| mantissa.len() - i - 1) as i32
+}
+
+/// Parses a `ValUnc` in one of the accepted notations (see the [module documentation](self)),
+/// with the default [`ParseOptions`].
+///
+/// ```
+/// use val_unc::{parse_val_unc, Unc, ValUnc};
+///
+/// let v: ValUnc<f32, Unc<f32>> = parse_val_unc("1.23(4)").unwrap();
+/// assert_eq!(v, ValUnc::new(1.23, Unc(0.04)));
+/// ```
+pub fn parse_val_unc<V, U>(s: &str) -> Result<ValUnc<V, U>, ParseValUncError>
+where
+    V: FromStr,
+    U: From<V>,
+{
+    ParseOptions::default().parse(s)
+}
+
+/// Parses a `ValUnc` with [`parse_val_unc`].
+///
+/// ```
+/// use val_unc::{Unc, ValUnc};
+///
+/// let v: ValUnc<f64, Unc<f64>> = "1.23e3 ± 5e1".parse().unwrap();
+/// assert_eq!(v, ValUnc::new(1230.0, Unc(50.0)));
+/// ```
+impl<V, U> FromStr for ValUnc<V, U>
+where
+    V: FromStr,
+    U: From<V>,
+{
+    type Err = ParseValUncError;
+
+    fn from_str(s: &str) -> Result<Self, ParseValUncError> {
+        parse_val_unc(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unc;
+
+    fn parse(s: &str) -> Result<ValUnc<f64, Unc<f64>>, ParseValUncError> {
+        s.parse()
+    }
+
+    #[test]
+    fn plus_minus() {
+        assert_eq!(parse("1.23 ± 0.04"), Ok(ValUnc::new(1.23, Unc(0.04))));
+        assert_eq!(parse(" 1.23+/-0.04 "), Ok(ValUnc::new(1.23, Unc(0.04))));
+        assert_eq!(parse("-1.23 +- 4e-2"), Ok(ValUnc::new(-1.23, Unc(0.04))));
+        assert_eq!(parse("1.23e3 ± 5e1"), Ok(ValUnc::new(1230.0, Unc(50.0))));
+    }
+
+    #[test]
+    fn parenthesis() {
+        assert_eq!(parse("1.23(4)"), Ok(ValUnc::new(1.23, Unc(0.04))));
+        assert_eq!(
+            parse("1.234568(67)"),
+            Ok(ValUnc::new(1.234568, Unc(0.000067)))
+        );
+        assert_eq!(parse("12345.6(5.7)"), Ok(ValUnc::new(12345.6, Unc(5.7))));
+        assert_eq!(parse("1230(40)"), Ok(ValUnc::new(1230.0, Unc(40.0))));
+        assert_eq!(
+            parse("6.67430(15)e-11"),
+            Ok(ValUnc::new(6.67430e-11, Unc(0.00015e-11)))
+        );
+    }
+
+    #[test]
+    fn implied() {
+        let half = ParseOptions::new().implied_unc(ImpliedUnc::HalfUnit);
+        let one = ParseOptions::new().implied_unc(ImpliedUnc::OneUnit);
+
+        assert_eq!(half.parse("1.230"), Ok(ValUnc::new(1.23, Unc(0.0005))));
+        assert_eq!(half.parse("-1200"), Ok(ValUnc::new(-1200.0, Unc(0.5))));
+        assert_eq!(one.parse("1.23e3"), Ok(ValUnc::new(1230.0, Unc(10.0))));
+        assert_eq!(one.parse("1.23 ± 0.04"), Ok(ValUnc::new(1.23, Unc(0.04))));
+        assert_eq!(
+            one.parse::<f64, Unc<f64>>("1.23e"),
+            Err(ParseValUncError::Val)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("1.23"), Err(ParseValUncError::Syntax));
+        assert_eq!(parse("± 0.04"), Err(ParseValUncError::Syntax));
+        assert_eq!(parse("1.23(4"), Err(ParseValUncError::Syntax));
+        assert_eq!(parse("1.23(4)x"), Err(ParseValUncError::Syntax));
+        assert_eq!(parse("1.23(a)"), Err(ParseValUncError::Unc));
+        assert_eq!(parse("a ± 0.04"), Err(ParseValUncError::Val));
+        assert_eq!(parse("1.23 ± a"), Err(ParseValUncError::Unc));
+    }
+}
@@ -0,0 +1,154 @@
+//! Drawing `ValUnc`s as error bars with `plotters`
+//!
+//! [`ErrorBarSeries`] turns a slice of `(x, y)` points, where the `y`s are `ValUnc`s, into
+//! vertical [`ErrorBar`]s, which can be passed to `ChartContext::draw_series`. The bars cover the
+//! [interval](ValUnc::interval) of each `y`, or, with [`ErrorBarSeries::asymmetric`], any lower
+//! and upper uncertainties.
+//!
+//! ```
+//! use plotters::{element::PointCollection, style::BLACK};
+//! use val_unc::{plotters::ErrorBarSeries, Unc, ValUnc};
+//!
+//! let data = [(1.0, ValUnc::new(2.0, Unc(0.5))), (2.0, ValUnc::new(3.0, Unc(0.25)))];
+//! let bars: Vec<_> = ErrorBarSeries::new(&data, BLACK, 5).collect();
+//!
+//! assert_eq!((&bars[0]).point_iter(), [(1.0, 1.5), (1.0, 2.0), (1.0, 2.5)]);
+//! ```
+
+use crate::{traits::UncInterval, val_unc::ValUnc};
+use ::plotters::{
+    element::{ErrorBar, ErrorBarOrientV},
+    style::ShapeStyle,
+};
+use core::slice;
+
+/// An iterator of vertical error bars, one for each point of a slice
+///
+/// See the [module documentation](self).
+pub struct ErrorBarSeries<'a, U, F> {
+    data: slice::Iter<'a, (f64, ValUnc<f64, U>)>,
+    uncs: F,
+    style: ShapeStyle,
+    width: u32,
+}
+
+impl<'a, U> ErrorBarSeries<'a, U, fn(&ValUnc<f64, U>) -> (f64, f64)>
+where
+    U: UncInterval<f64>,
+{
+    /// Creates error bars covering the interval of each point with a coverage factor of 1.
+    ///
+    /// `style` and `width` are those of [`ErrorBar::new_vertical`].
+    pub fn new<S>(data: &'a [(f64, ValUnc<f64, U>)], style: S, width: u32) -> Self
+    where
+        S: Into<ShapeStyle>,
+    {
+        Self::asymmetric(
+            data,
+            |v| {
+                let (lower, upper) = v.interval(1.0);
+                (v.val - lower, upper - v.val)
+            },
+            style,
+            width,
+        )
+    }
+}
+
+impl<'a, U, F> ErrorBarSeries<'a, U, F>
+where
+    F: Fn(&ValUnc<f64, U>) -> (f64, f64),
+{
+    /// Creates error bars from `val - minus` to `val + plus`, where `uncs` returns the
+    /// uncertainties `(minus, plus)` of each point.
+    ///
+    /// `style` and `width` are those of [`ErrorBar::new_vertical`].
+    ///
+    /// ```
+    /// use plotters::{element::PointCollection, style::BLACK};
+    /// use val_unc::{plotters::ErrorBarSeries, Unc, ValUnc};
+    ///
+    /// // The uncertainties below and above the value
+    /// let data = [(1.0, ValUnc::new(2.0, (Unc(0.5), Unc(1.0))))];
+    /// let mut bars = ErrorBarSeries::asymmetric(&data, |v| (v.unc.0 .0, v.unc.1 .0), BLACK, 5);
+    ///
+    /// assert_eq!((&bars.next().unwrap()).point_iter(), [(1.0, 1.5), (1.0, 2.0), (1.0, 3.0)]);
+    /// ```
+    pub fn asymmetric<S>(data: &'a [(f64, ValUnc<f64, U>)], uncs: F, style: S, width: u32) -> Self
+    where
+        S: Into<ShapeStyle>,
+    {
+        Self {
+            data: data.iter(),
+            uncs,
+            style: style.into(),
+            width,
+        }
+    }
+}
+
+impl<'a, U, F> Iterator for ErrorBarSeries<'a, U, F>
+where
+    F: Fn(&ValUnc<f64, U>) -> (f64, f64),
+{
+    type Item = ErrorBar<f64, f64, ErrorBarOrientV<f64, f64>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.data.next()?;
+        let (minus, plus) = (self.uncs)(y);
+        Some(ErrorBar::new_vertical(
+            *x,
+            y.val - minus,
+            y.val,
+            y.val + plus,
+            self.style,
+            self.width,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.data.size_hint()
+    }
+}
+
+impl<'a, U, F> ExactSizeIterator for ErrorBarSeries<'a, U, F> where
+    F: Fn(&ValUnc<f64, U>) -> (f64, f64)
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unc::{BoundUnc, Unc};
+    use ::plotters::{element::PointCollection, style::RED};
+
+    #[test]
+    fn symmetric() {
+        let data = [
+            (0.0, ValUnc::new(1.0, BoundUnc(0.5))),
+            (2.0, ValUnc::new(-1.0, BoundUnc(0.25))),
+        ];
+        let series = ErrorBarSeries::new(&data, RED, 3);
+        assert_eq!(series.len(), 2);
+
+        let points: Vec<_> = series.map(|b| (&b).point_iter()).collect();
+        assert_eq!(
+            points,
+            [
+                [(0.0, 0.5), (0.0, 1.0), (0.0, 1.5)],
+                [(2.0, -1.25), (2.0, -1.0), (2.0, -0.75)],
+            ]
+        );
+    }
+
+    #[test]
+    fn asymmetric() {
+        // Only the uncertainty above the value
+        let data = [(1.0, ValUnc::new(0.0, Unc(0.5)))];
+        let bars: Vec<_> = ErrorBarSeries::asymmetric(&data, |v| (0.0, v.unc.0), RED, 3).collect();
+        assert_eq!(
+            (&bars[0]).point_iter(),
+            [(1.0, 0.0), (1.0, 0.0), (1.0, 0.5)]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+// Index loops read better than iterators for the matrix code in this module.
+#![allow(clippy::needless_range_loop)]
+
+use crate::{unc::Unc, val_unc::ValUnc};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A point in `N` dimensions with a covariance matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointUnc<const N: usize> {
+    pub val: [f64; N],
+    pub cov: [[f64; N]; N],
+}
+
+impl<const N: usize> PointUnc<N> {
+    pub const fn new(val: [f64; N], cov: [[f64; N]; N]) -> Self {
+        Self { val, cov }
+    }
+
+    /// Creates a point with independent uncertainties `unc` in each coordinate.
+    pub fn from_uncs(val: [f64; N], unc: [f64; N]) -> Self {
+        let mut cov = [[0.0; N]; N];
+        for i in 0..N {
+            cov[i][i] = unc[i] * unc[i];
+        }
+        Self { val, cov }
+    }
+
+    /// The coordinates as `ValUnc`s, ignoring correlations.
+    pub fn coords(&self) -> [ValUnc<f64, Unc<f64>>; N] {
+        let mut out = [ValUnc::new(0.0, Unc(0.0)); N];
+        for (i, c) in out.iter_mut().enumerate() {
+            *c = ValUnc::new(self.val[i], Unc(self.cov[i][i].sqrt()));
+        }
+        out
+    }
+
+    /// Applies the affine transform `a * x + b`.
+    pub fn transform<const M: usize>(&self, a: &[[f64; N]; M], b: &[f64; M]) -> PointUnc<M> {
+        let mut val = *b;
+        for i in 0..M {
+            for j in 0..N {
+                val[i] += a[i][j] * self.val[j];
+            }
+        }
+
+        // a * cov * a^T
+        let mut a_cov = [[0.0; N]; M];
+        for i in 0..M {
+            for j in 0..N {
+                a_cov[i][j] = (0..N).map(|k| a[i][k] * self.cov[k][j]).sum();
+            }
+        }
+        let mut cov = [[0.0; M]; M];
+        for i in 0..M {
+            for j in 0..M {
+                cov[i][j] = (0..N).map(|k| a_cov[i][k] * a[j][k]).sum();
+            }
+        }
+
+        PointUnc { val, cov }
+    }
+
+    /// The Euclidean distance between two independent points.
+    pub fn distance(&self, other: &Self) -> ValUnc<f64, Unc<f64>> {
+        let diff = self.diff(other);
+        let cov = self.sum_cov(other);
+        let d = diff.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if d == 0.0 {
+            return ValUnc::new(0.0, Unc(0.0));
+        }
+
+        // The gradient of the distance is diff / d.
+        let var: f64 = (0..N)
+            .map(|i| (0..N).map(|j| diff[i] * cov[i][j] * diff[j]).sum::<f64>())
+            .sum::<f64>()
+            / (d * d);
+        ValUnc::new(d, Unc(var.sqrt()))
+    }
+
+    /// The Mahalanobis distance between two independent points, using their combined covariance.
+    ///
+    /// Returns `None` if the combined covariance is singular.
+    pub fn mahalanobis(&self, other: &Self) -> Option<f64> {
+        let diff = self.diff(other);
+        let x = solve(self.sum_cov(other), diff)?;
+        Some((0..N).map(|i| diff[i] * x[i]).sum::<f64>().sqrt())
+    }
+
+    /// The Mahalanobis distance between this point and an exact point `p`.
+    ///
+    /// Returns `None` if the covariance is singular.
+    pub fn mahalanobis_to(&self, p: &[f64; N]) -> Option<f64> {
+        self.mahalanobis(&Self::new(*p, [[0.0; N]; N]))
+    }
+
+    fn diff(&self, other: &Self) -> [f64; N] {
+        let mut diff = self.val;
+        for (d, o) in diff.iter_mut().zip(other.val.iter()) {
+            *d -= o;
+        }
+        diff
+    }
+
+    fn sum_cov(&self, other: &Self) -> [[f64; N]; N] {
+        let mut cov = self.cov;
+        for i in 0..N {
+            for j in 0..N {
+                cov[i][j] += other.cov[i][j];
+            }
+        }
+        cov
+    }
+}
+
+impl PointUnc<2> {
+    /// The `k`-sigma covariance ellipse, as `(semi_major, semi_minor, angle)`, where `angle` is
+    /// the angle of the major axis from the first axis, in radians.
+    pub fn ellipse(&self, k: f64) -> (f64, f64, f64) {
+        let [[a, b], [_, c]] = self.cov;
+        let mean = (a + c) / 2.0;
+        let r = f64::sqrt(((a - c) / 2.0).powi(2) + b * b);
+        let angle = 0.5 * f64::atan2(2.0 * b, a - c);
+        (k * (mean + r).sqrt(), k * (mean - r).max(0.0).sqrt(), angle)
+    }
+}
+
+/// Solves `a * x = b` using Gaussian elimination with partial pivoting.
+fn solve<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> Option<[f64; N]> {
+    for col in 0..N {
+        let pivot = (col..N).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col] == 0.0 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        for row in (col + 1)..N {
+            let f = a[row][col] / a[col][col];
+            for k in col..N {
+                a[row][k] -= f * a[col][k];
+            }
+            b[row] -= f * b[col];
+        }
+    }
+
+    let mut x = [0.0; N];
+    for row in (0..N).rev() {
+        let s: f64 = ((row + 1)..N).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - s) / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_unc() {
+        let p1 = PointUnc::from_uncs([0.0, 0.0], [0.3, 0.4]);
+        let p2 = PointUnc::from_uncs([3.0, 4.0], [0.0, 0.0]);
+
+        // var = (3^2 * 0.09 + 4^2 * 0.16) / 25
+        let ValUnc { val, unc } = p1.distance(&p2);
+        assert!(f64::abs(val - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(unc.0 - f64::sqrt(3.37 / 25.0)) <= 1e-12);
+
+        assert!(f64::abs(p1.mahalanobis(&p2).unwrap() - f64::sqrt(200.0)) <= 1e-12);
+        assert_eq!(p2.mahalanobis_to(&[0.0, 0.0]), None);
+
+        // Swap the axes and scale by 2.
+        let p3 = p1.transform(&[[0.0, 2.0], [2.0, 0.0], [1.0, 1.0]], &[1.0, 0.0, 0.0]);
+        assert_eq!(p3.val, [1.0, 0.0, 0.0]);
+        assert!(f64::abs(p3.cov[0][0] - 0.64) <= 1e-12);
+        assert!(f64::abs(p3.cov[1][1] - 0.36) <= 1e-12);
+        assert!(f64::abs(p3.cov[2][2] - 0.25) <= 1e-12);
+
+        let (major, minor, angle) = p1.ellipse(2.0);
+        assert!(f64::abs(major - 0.8) <= 1e-12);
+        assert!(f64::abs(minor - 0.6) <= 1e-12);
+        assert!(f64::abs(angle.abs() - core::f64::consts::FRAC_PI_2) <= 1e-12);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{traits::*, unc::Unc, val_unc::ValUnc};
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// What to do when an uncertainty is invalid, i.e. negative or NaN (see [`UncValidate`])
+///
+/// The operators of `ValUnc` don't check their results. The policy is applied after every
+/// operation of [`Checked`], or once with [`ValUnc::check`], e.g. at the end of a computation.
+///
+/// ```
+/// use val_unc::{InvalidPolicy, Unc, ValUnc};
+///
+/// let v = ValUnc::new(1.0, Unc(0.1)) * ValUnc::new(2.0, Unc(f64::NAN));
+///
+/// assert!(v.check(InvalidPolicy::Error).is_err());
+/// assert_eq!(v.check(InvalidPolicy::Clamp).unwrap().unc, Unc(0.0));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum InvalidPolicy {
+    /// Keep the invalid uncertainty.
+    Ignore,
+    /// Panic.
+    Panic,
+    /// Panic in debug builds, and keep the invalid uncertainty otherwise.
+    #[default]
+    DebugPanic,
+    /// Set the invalid components of the uncertainty to zero (see
+    /// [`UncValidate::clamp_invalid`]).
+    Clamp,
+    /// Return an error.
+    Error,
+}
+
+/// The error returned for an invalid uncertainty
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct InvalidUnc;
+
+impl fmt::Display for InvalidUnc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid uncertainty (negative or NaN)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidUnc {}
+
+impl<V, U> ValUnc<V, U>
+where
+    U: UncValidate,
+{
+    /// Creates a new `ValUnc`, or returns an error if `unc` is invalid.
+    ///
+    /// ```
+    /// use val_unc::{Unc, ValUnc};
+    ///
+    /// assert!(ValUnc::try_new(1.0, (Unc(0.1), Unc(0.2))).is_ok());
+    /// assert!(ValUnc::try_new(1.0, (Unc(0.1), Unc(-0.2))).is_err());
+    /// ```
+    pub fn try_new(val: V, unc: U) -> Result<Self, InvalidUnc> {
+        if unc.is_valid() {
+            Ok(Self::new(val, unc))
+        } else {
+            Err(InvalidUnc)
+        }
+    }
+
+    /// Checks that the uncertainty is valid, and applies `policy` if it isn't.
+    pub fn check(mut self, policy: InvalidPolicy) -> Result<Self, InvalidUnc> {
+        self.apply(policy).map(|()| self)
+    }
+
+    /// Applies `policy` in place, leaving the uncertainty as it is for [`InvalidPolicy::Error`].
+    fn apply(&mut self, policy: InvalidPolicy) -> Result<(), InvalidUnc> {
+        if self.unc.is_valid() {
+            return Ok(());
+        }
+
+        match policy {
+            InvalidPolicy::Ignore => Ok(()),
+            InvalidPolicy::Panic => panic!("{}", InvalidUnc),
+            InvalidPolicy::DebugPanic => {
+                debug_assert!(false, "{}", InvalidUnc);
+                Ok(())
+            }
+            InvalidPolicy::Clamp => {
+                self.unc.clamp_invalid();
+                Ok(())
+            }
+            InvalidPolicy::Error => Err(InvalidUnc),
+        }
+    }
+}
+
+/// A `ValUnc` whose operations apply an [`InvalidPolicy`] to their results
+///
+/// The result of a binary operation uses the policy of its left operand. With
+/// [`InvalidPolicy::Error`], an invalid result is kept and marks the value, and everything computed
+/// from it, as failed, so that the error is returned by [`into_result`](Checked::into_result).
+///
+/// ```
+/// use val_unc::{Checked, InvalidPolicy, Unc, ValUnc};
+///
+/// let a = Checked::new(ValUnc::new(1.0, Unc(0.1)), InvalidPolicy::Error);
+/// let b = Checked::new(ValUnc::new(2.0, Unc(f64::NAN)), InvalidPolicy::Error);
+///
+/// assert!((a + b - a).into_result().is_err());
+/// assert!((a * a).into_result().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Checked<V, U = Unc<V>> {
+    val_unc: ValUnc<V, U>,
+    policy: InvalidPolicy,
+    failed: bool,
+}
+
+impl<V, U> Checked<V, U>
+where
+    U: UncValidate,
+{
+    /// Creates a new `Checked`, applying `policy` to `val_unc` itself.
+    pub fn new(val_unc: ValUnc<V, U>, policy: InvalidPolicy) -> Self {
+        Self::with_policy(val_unc, policy, false)
+    }
+
+    fn with_policy(mut val_unc: ValUnc<V, U>, policy: InvalidPolicy, failed: bool) -> Self {
+        let failed = val_unc.apply(policy).is_err() || failed;
+        Self {
+            val_unc,
+            policy,
+            failed,
+        }
+    }
+}
+
+impl<V, U> Checked<V, U> {
+    /// Returns the policy applied to the results of the operations.
+    pub fn policy(&self) -> InvalidPolicy {
+        self.policy
+    }
+
+    /// Returns the value, even if an operation has failed.
+    pub fn get(&self) -> &ValUnc<V, U> {
+        &self.val_unc
+    }
+
+    /// Returns the value, or an error if an operation has failed under [`InvalidPolicy::Error`].
+    pub fn into_result(self) -> Result<ValUnc<V, U>, InvalidUnc> {
+        if self.failed {
+            Err(InvalidUnc)
+        } else {
+            Ok(self.val_unc)
+        }
+    }
+}
+
+/// This uses the default policy, [`InvalidPolicy::DebugPanic`].
+impl<V, U> From<ValUnc<V, U>> for Checked<V, U>
+where
+    U: UncValidate,
+{
+    fn from(v: ValUnc<V, U>) -> Self {
+        Self::new(v, InvalidPolicy::default())
+    }
+}
+
+// This implements the checked binary operators in terms of those of `ValUnc`.
+macro_rules! checked_ops {
+    ($(($Tr:ident, $f:ident)),+) => {$(
+        impl<V, U> $Tr for Checked<V, U>
+        where
+            ValUnc<V, U>: $Tr<Output = ValUnc<V, U>>,
+            U: UncValidate,
+        {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self {
+                Self::with_policy(
+                    self.val_unc.$f(other.val_unc),
+                    self.policy,
+                    self.failed || other.failed,
+                )
+            }
+        }
+    )+}
+}
+
+checked_ops!((Add, add), (Div, div), (Mul, mul), (Sub, sub));
+
+impl<V, U> Neg for Checked<V, U>
+where
+    ValUnc<V, U>: Neg<Output = ValUnc<V, U>>,
+    U: UncValidate,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::with_policy(self.val_unc.neg(), self.policy, self.failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NonNegUnc, Unc};
+
+    #[test]
+    fn policies() {
+        let good = ValUnc::new(1.0, (Unc(0.1), Unc(0.2)));
+        let bad = ValUnc::new(1.0, (Unc(0.1), Unc(f64::NAN)));
+
+        for &p in &[
+            InvalidPolicy::Ignore,
+            InvalidPolicy::Panic,
+            InvalidPolicy::DebugPanic,
+            InvalidPolicy::Clamp,
+            InvalidPolicy::Error,
+        ] {
+            assert_eq!(good.check(p), Ok(good));
+        }
+
+        assert!(bad.check(InvalidPolicy::Ignore).unwrap().unc.1 .0.is_nan());
+        assert_eq!(
+            bad.check(InvalidPolicy::Clamp),
+            Ok(ValUnc::new(1.0, (Unc(0.1), Unc(0.0))))
+        );
+        assert_eq!(bad.check(InvalidPolicy::Error), Err(InvalidUnc));
+
+        let bad = ValUnc::new(1.0, [Unc(-0.1), Unc(0.2)]);
+        assert_eq!(
+            bad.check(InvalidPolicy::Clamp),
+            Ok(ValUnc::new(1.0, [Unc(0.0), Unc(0.2)]))
+        );
+    }
+
+    #[test]
+    fn try_new() {
+        assert_eq!(
+            ValUnc::try_new(1.0, Unc(0.1)),
+            Ok(ValUnc::new(1.0, Unc(0.1)))
+        );
+        assert_eq!(ValUnc::try_new(1.0, Unc(-0.1)), Err(InvalidUnc));
+        assert_eq!(
+            ValUnc::try_new(1.0, [Unc(0.1), Unc(f64::NAN)]),
+            Err(InvalidUnc)
+        );
+        assert!(NonNegUnc::new(Unc(f64::NAN)).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic() {
+        let _ = ValUnc::new(1.0, Unc(-1.0)).check(InvalidPolicy::Panic);
+    }
+
+    #[test]
+    fn checked() {
+        let good = ValUnc::new(1.0, Unc(0.1));
+        let bad = ValUnc::new(2.0, Unc(f64::NAN));
+
+        let a = Checked::new(good, InvalidPolicy::Clamp);
+        let b = Checked::new(bad, InvalidPolicy::Ignore);
+        assert!((b - a).get().unc.0.is_nan());
+        // The left operand's policy clamps the result
+        assert_eq!((a * b).into_result(), Ok(ValUnc::new(2.0, Unc(0.0))));
+
+        let a = Checked::new(good, InvalidPolicy::Error);
+        let failed = a + b;
+        assert!(failed.get().unc.0.is_nan());
+        assert_eq!((-(failed * a) + a).into_result(), Err(InvalidUnc));
+        assert_eq!((a / a).into_result(), Ok(good / good));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn checked_default() {
+        let a = Checked::from(ValUnc::new(1.0, Unc(0.1)));
+        let _ = a * Checked::new(ValUnc::new(2.0, Unc(f64::NAN)), InvalidPolicy::Ignore);
+    }
+}
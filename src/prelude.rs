@@ -0,0 +1,36 @@
+//! The commonly used items, for importing with a glob
+//!
+//! ```
+//! use val_unc::prelude::*;
+//!
+//! define_unc! {
+//!     pub struct SysUnc(f64) = linear, "sys";
+//! }
+//!
+//! let v = val_unc!(1.23 +- 0.04) + 2.0.pm(0.03);
+//! let total = v.unc.total(v.val);
+//!
+//! assert!(f64::abs(total - 0.05) <= 1e-12);
+//! assert_eq!(val_unc!(1.0, sys = 0.5), ValUnc::new(1.0, (SysUnc(0.5),)));
+//! ```
+
+/// Creates a `ValUnc`, see [`val_unc!`](macro@crate::val_unc).
+#[doc(inline)]
+pub use crate::__val_unc as val_unc;
+#[cfg(feature = "complex")]
+pub use crate::unc::ComplexUnc;
+pub use crate::{
+    define_unc,
+    traits::{
+        GetUnc, UncAdd, UncCoverage, UncDisplay, UncDiv, UncFrom, UncInterval, UncInto, UncMul,
+        UncNeg, UncRound, UncSub, UncTotal, UncValidate, UncWeight, UncZero,
+    },
+    unc::{
+        BoundUnc, DofUnc, ExpandedUnc, MomentsUnc, NonNegUnc, RelUnc, ResolutionUnc, ScaleUnc,
+        Tagged, Unc, ZeroUnc,
+    },
+    val_unc::{SimpleValUnc, ValUnc},
+    IterValUncExt, PlusMinus,
+};
+#[cfg(feature = "alloc")]
+pub use crate::{unc::UncMap, Measurement};
@@ -0,0 +1,369 @@
+// Index loops read better than iterators for the derivative code in this module.
+#![allow(clippy::needless_range_loop)]
+
+use crate::{unc::Unc, val_unc::ValUnc};
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The step used for numerical derivatives, as a fraction of the input uncertainty.
+const STEP: f64 = 1e-3;
+
+/// Propagates independent inputs through an arbitrary function, to first order.
+///
+/// The derivatives are calculated numerically.
+///
+/// ```
+/// use val_unc::{propagate, Unc, ValUnc};
+///
+/// let x = ValUnc::new(2.0, Unc(0.2));
+/// let ValUnc { val, unc } = propagate(|[x]| 1.0 / x, [x]);
+///
+/// assert!(f64::abs(val - 0.5) <= 1e-9);
+/// assert!(f64::abs(unc.0 - 0.05) <= 1e-9);
+/// ```
+pub fn propagate<F, const N: usize>(
+    f: F,
+    inputs: [ValUnc<f64, Unc<f64>>; N],
+) -> ValUnc<f64, Unc<f64>>
+where
+    F: Fn([f64; N]) -> f64,
+{
+    let (x, sigma) = split(&inputs);
+    let grad = gradient(&f, x, sigma);
+    let var = (0..N).map(|i| (grad[i] * sigma[i]).powi(2)).sum::<f64>();
+    ValUnc::new(f(x), Unc(var.sqrt()))
+}
+
+/// Propagates independent, Gaussian inputs through an arbitrary function, to second order.
+///
+/// Compared to [`propagate`], the mean is shifted by `½ Σ ∂²f/∂xᵢ² σᵢ²`, and the variance
+/// includes the term `½ Σ (∂²f/∂xᵢ∂xⱼ)² σᵢ² σⱼ²`. This reduces the bias for strongly non-linear
+/// functions. The derivatives are calculated numerically.
+pub fn propagate_second_order<F, const N: usize>(
+    f: F,
+    inputs: [ValUnc<f64, Unc<f64>>; N],
+) -> ValUnc<f64, Unc<f64>>
+where
+    F: Fn([f64; N]) -> f64,
+{
+    let (x, sigma) = split(&inputs);
+    let grad = gradient(&f, x, sigma);
+    let hess = hessian(&f, x, sigma);
+
+    let mut shift = 0.0;
+    let mut var = 0.0;
+    for i in 0..N {
+        shift += 0.5 * hess[i][i] * sigma[i].powi(2);
+        var += (grad[i] * sigma[i]).powi(2);
+        for j in 0..N {
+            var += 0.5 * (hess[i][j] * sigma[i] * sigma[j]).powi(2);
+        }
+    }
+    ValUnc::new(f(x) + shift, Unc(var.sqrt()))
+}
+
+/// Propagates independent inputs through a function with discontinuities or branch points, to
+/// first order.
+///
+/// Linear propagation is meaningless if an input's `±k·σ` range crosses a point where the
+/// function is discontinuous. Each entry of `discontinuities` is the index of an input and the
+/// location of a discontinuity in that input. If any of them is within `k` standard deviations of
+/// the input's value, the nearest one is returned as an error instead of a result. An exact input
+/// is only too close to a discontinuity at its value.
+///
+/// ```
+/// use val_unc::{propagate_piecewise, PiecewiseError, Unc, ValUnc};
+///
+/// let f = |[x]: [f64; 1]| if x < 1.0 { x } else { 2.0 * x };
+///
+/// let x = ValUnc::new(0.5, Unc(0.1));
+/// assert!(propagate_piecewise(f, [x], 2.0, &[(0, 1.0)]).is_ok());
+///
+/// let x = ValUnc::new(0.9, Unc(0.1));
+/// match propagate_piecewise(f, [x], 2.0, &[(0, 1.0)]) {
+///     Err(PiecewiseError::Discontinuity(d)) => assert_eq!(d.input, 0),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn propagate_piecewise<F, const N: usize>(
+    f: F,
+    inputs: [ValUnc<f64, Unc<f64>>; N],
+    k: f64,
+    discontinuities: &[(usize, f64)],
+) -> Result<ValUnc<f64, Unc<f64>>, PiecewiseError>
+where
+    F: Fn([f64; N]) -> f64,
+{
+    if let Some(&(input, _)) = discontinuities.iter().find(|&&(input, _)| input >= N) {
+        return Err(PiecewiseError::Input(input));
+    }
+
+    let nearest = discontinuities
+        .iter()
+        .map(|&(input, at)| {
+            let ValUnc { val, unc } = inputs[input];
+            let dist = (at - val).abs();
+            Discontinuity {
+                input,
+                at,
+                // This avoids 0 / 0 for a discontinuity at the value of an exact input
+                n_sigma: if dist == 0.0 { 0.0 } else { dist / unc.0 },
+            }
+        })
+        .filter(|d| d.n_sigma <= k)
+        .min_by(|a, b| a.n_sigma.total_cmp(&b.n_sigma));
+
+    match nearest {
+        Some(d) => Err(PiecewiseError::Discontinuity(d)),
+        None => Ok(propagate(f, inputs)),
+    }
+}
+
+/// The error returned by [`propagate_piecewise`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PiecewiseError {
+    /// A discontinuity is too close to an input value.
+    Discontinuity(Discontinuity),
+    /// A discontinuity refers to an input index that is out of range.
+    Input(usize),
+}
+
+impl fmt::Display for PiecewiseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Discontinuity(d) => d.fmt(f),
+            Self::Input(i) => write!(f, "there is no input {}", i),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PiecewiseError {}
+
+/// A discontinuity that is too close to an input value for linear propagation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Discontinuity {
+    /// The index of the input
+    pub input: usize,
+    /// The location of the discontinuity
+    pub at: f64,
+    /// The distance from the input value to the discontinuity, in standard deviations
+    pub n_sigma: f64,
+}
+
+impl fmt::Display for Discontinuity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "input {} is within {} standard deviations of a discontinuity at {}",
+            self.input, self.n_sigma, self.at
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Discontinuity {}
+
+/// The result `val` of a function of two inputs with standard deviations `sa` and `sb` and
+/// correlation `rho`, where `da` and `db` are the partial derivatives.
+fn two_inputs(val: f64, (da, sa): (f64, f64), (db, sb): (f64, f64), rho: f64) -> ValUnc<f64> {
+    let var = (da * sa).powi(2) + (db * sb).powi(2) + 2.0 * rho * da * sa * db * sb;
+    ValUnc::new(val, Unc(var.max(0.0).sqrt()))
+}
+
+/// The ratio `a / b` of independent inputs.
+///
+/// This is the same as `a / b`, except that the derivatives are used directly, so a zero
+/// numerator is handled. See [`ratio_correlated`] for correlated inputs.
+pub fn ratio(a: ValUnc<f64>, b: ValUnc<f64>) -> ValUnc<f64> {
+    ratio_correlated(a, b, 0.0)
+}
+
+/// The ratio `a / b` of inputs with correlation coefficient `rho`.
+///
+/// Measurements that share a fully correlated component, e.g. a normalization, have a positive
+/// correlation, which reduces the uncertainty of the ratio. Treating them as independent, as the
+/// operators do, overestimates it.
+///
+/// ```
+/// use val_unc::{ratio_correlated, Unc, ValUnc};
+///
+/// // Both are known to 10 %, from the same calibration
+/// let a = ValUnc::new(4.0, Unc(0.4));
+/// let b = ValUnc::new(2.0, Unc(0.2));
+///
+/// assert_eq!(ratio_correlated(a, b, 1.0), ValUnc::new(2.0, Unc(0.0)));
+/// ```
+pub fn ratio_correlated(a: ValUnc<f64>, b: ValUnc<f64>, rho: f64) -> ValUnc<f64> {
+    let val = a.val / b.val;
+    two_inputs(val, (1.0 / b.val, a.unc.0), (-val / b.val, b.unc.0), rho)
+}
+
+/// The asymmetry `(a - b) / (a + b)` of independent inputs.
+///
+/// The numerator and the denominator both depend on `a` and `b`, so calculating them separately
+/// and dividing, e.g. `(a - b) / (a + b)` with the operators, treats them as independent and
+/// gets the wrong uncertainty. Here, the uncertainty is propagated from `a` and `b` directly.
+///
+/// ```
+/// use val_unc::{asymmetry, Unc, ValUnc};
+///
+/// // Counts, with Poisson uncertainties
+/// let a = ValUnc::new(60.0, Unc(f64::sqrt(60.0)));
+/// let b = ValUnc::new(40.0, Unc(f64::sqrt(40.0)));
+/// let asym = asymmetry(a, b);
+///
+/// // The binomial uncertainty, √((1 - A²) / N)
+/// assert!(f64::abs(asym.val - 0.2) <= 1e-12);
+/// assert!(f64::abs(asym.unc.0 - f64::sqrt(0.96 / 100.0)) <= 1e-12);
+/// ```
+pub fn asymmetry(a: ValUnc<f64>, b: ValUnc<f64>) -> ValUnc<f64> {
+    asymmetry_correlated(a, b, 0.0)
+}
+
+/// The asymmetry `(a - b) / (a + b)` of inputs with correlation coefficient `rho`.
+pub fn asymmetry_correlated(a: ValUnc<f64>, b: ValUnc<f64>, rho: f64) -> ValUnc<f64> {
+    let sum = a.val + b.val;
+    two_inputs(
+        (a.val - b.val) / sum,
+        (2.0 * b.val / sum.powi(2), a.unc.0),
+        (-2.0 * a.val / sum.powi(2), b.unc.0),
+        rho,
+    )
+}
+
+pub(crate) fn split<const N: usize>(inputs: &[ValUnc<f64, Unc<f64>>; N]) -> ([f64; N], [f64; N]) {
+    let mut x = [0.0; N];
+    let mut sigma = [0.0; N];
+    for i in 0..N {
+        x[i] = inputs[i].val;
+        sigma[i] = inputs[i].unc.0;
+    }
+    (x, sigma)
+}
+
+/// Calculates the gradient with central differences. Inputs without uncertainty are skipped.
+pub(crate) fn gradient<F, const N: usize>(f: &F, x: [f64; N], sigma: [f64; N]) -> [f64; N]
+where
+    F: Fn([f64; N]) -> f64,
+{
+    let mut grad = [0.0; N];
+    for i in 0..N {
+        if sigma[i] == 0.0 {
+            continue;
+        }
+        let h = sigma[i] * STEP;
+        grad[i] = (f(shifted(x, &[(i, h)])) - f(shifted(x, &[(i, -h)]))) / (2.0 * h);
+    }
+    grad
+}
+
+/// Calculates the Hessian with central differences. Inputs without uncertainty are skipped.
+fn hessian<F, const N: usize>(f: &F, x: [f64; N], sigma: [f64; N]) -> [[f64; N]; N]
+where
+    F: Fn([f64; N]) -> f64,
+{
+    let f0 = f(x);
+    let mut hess = [[0.0; N]; N];
+    for i in 0..N {
+        if sigma[i] == 0.0 {
+            continue;
+        }
+        let hi = sigma[i] * STEP;
+        hess[i][i] =
+            (f(shifted(x, &[(i, hi)])) - 2.0 * f0 + f(shifted(x, &[(i, -hi)]))) / (hi * hi);
+        for j in 0..i {
+            if sigma[j] == 0.0 {
+                continue;
+            }
+            let hj = sigma[j] * STEP;
+            let h = (f(shifted(x, &[(i, hi), (j, hj)]))
+                - f(shifted(x, &[(i, hi), (j, -hj)]))
+                - f(shifted(x, &[(i, -hi), (j, hj)]))
+                + f(shifted(x, &[(i, -hi), (j, -hj)])))
+                / (4.0 * hi * hj);
+            hess[i][j] = h;
+            hess[j][i] = h;
+        }
+    }
+    hess
+}
+
+fn shifted<const N: usize>(mut x: [f64; N], shifts: &[(usize, f64)]) -> [f64; N] {
+    for &(i, h) in shifts {
+        x[i] += h;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_order() {
+        let x = ValUnc::new(2.0, Unc(0.2));
+
+        let ValUnc { val, unc } = propagate_second_order(|[x]| 1.0 / x, [x]);
+        assert!(f64::abs(val - 0.505) <= 1e-6);
+        assert!(f64::abs(unc.0 - f64::sqrt(0.00255)) <= 1e-6);
+
+        // x * y has a mixed second derivative of 1.
+        let y = ValUnc::new(3.0, Unc(0.1));
+        let ValUnc { val, unc } = propagate_second_order(|[x, y]| x * y, [x, y]);
+        assert!(f64::abs(val - 6.0) <= 1e-6);
+        assert!(f64::abs(unc.0 - f64::sqrt(0.36 + 0.04 + 0.0004)) <= 1e-6);
+
+        // Exact inputs have no effect.
+        let z = ValUnc::new(3.0, Unc(0.0));
+        let ValUnc { unc, .. } = propagate(|[x, z]| x * z, [x, z]);
+        assert!(f64::abs(unc.0 - 0.6) <= 1e-6);
+    }
+
+    #[test]
+    fn ratios() {
+        let a = ValUnc::new(3.0, Unc(0.3));
+        let b = ValUnc::new(5.0, Unc(0.4));
+
+        let r = ratio(a, b);
+        assert!(f64::abs(r.val - (a / b).val) <= 1e-15);
+        assert!(f64::abs(r.unc.0 - (a / b).unc.0) <= 1e-15);
+        assert_eq!(ratio(ValUnc::new(0.0, Unc(0.3)), b).unc, Unc(0.06));
+
+        // The operators overestimate the uncertainty of an asymmetry
+        let naive = (a - b) / (a + b);
+        let asym = asymmetry(a, b);
+        let f = |[a, b]: [f64; 2]| (a - b) / (a + b);
+        assert!(f64::abs(asym.val - naive.val) <= 1e-15);
+        assert!(f64::abs(asym.unc.0 - propagate(f, [a, b]).unc.0) <= 1e-9);
+        assert!(asym.unc.0 < naive.unc.0.abs());
+
+        // A shared 10 % uncertainty cancels in both
+        let (a, b) = (ValUnc::new(3.0, Unc(0.3)), ValUnc::new(5.0, Unc(0.5)));
+        assert!(ratio_correlated(a, b, 1.0).unc.0 <= 1e-15);
+        assert!(asymmetry_correlated(a, b, 1.0).unc.0 <= 1e-15);
+    }
+
+    #[test]
+    fn piecewise() {
+        let f = |[x, y]: [f64; 2]| if x < 1.0 { y } else { 2.0 * y };
+        let (x, y) = (ValUnc::new(1.0, Unc(0.0)), ValUnc::new(2.0, Unc(0.1)));
+
+        // An exact input at the discontinuity
+        assert_eq!(
+            propagate_piecewise(f, [x, y], 2.0, &[(0, 1.0)]),
+            Err(PiecewiseError::Discontinuity(Discontinuity {
+                input: 0,
+                at: 1.0,
+                n_sigma: 0.0
+            }))
+        );
+        assert!(propagate_piecewise(f, [x, y], 2.0, &[(0, 1.5)]).is_ok());
+
+        assert_eq!(
+            propagate_piecewise(f, [x, y], 2.0, &[(1, 5.0), (2, 1.0)]),
+            Err(PiecewiseError::Input(2))
+        );
+    }
+}
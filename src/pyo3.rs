@@ -0,0 +1,97 @@
+//! Converting `ValUnc`s to and from Python objects with `pyo3`
+//!
+//! A `ValUnc<f64, Unc<f64>>` can be extracted from any object with `nominal_value` and `std_dev`
+//! attributes, e.g. the `ufloat`s of the [`uncertainties`] package, or from a
+//! `(nominal, std_dev)` tuple. It's converted into a `(nominal, std_dev)` tuple, which doesn't
+//! need `uncertainties` to be installed, and [`to_ufloat`] converts it into a `ufloat`.
+//!
+//! [`uncertainties`]: https://pypi.org/project/uncertainties/
+//!
+//! ```
+//! use pyo3::{types::PyAnyMethods, IntoPyObject, Python};
+//! use val_unc::{Unc, ValUnc};
+//!
+//! Python::initialize();
+//! Python::attach(|py| {
+//!     let obj = ValUnc::new(1.5, Unc(0.25)).into_pyobject(py).unwrap();
+//!     assert_eq!(obj.extract::<(f64, f64)>().unwrap(), (1.5, 0.25));
+//!     assert_eq!(obj.extract::<ValUnc<f64>>().unwrap(), ValUnc::new(1.5, Unc(0.25)));
+//! });
+//! ```
+
+use crate::{unc::Unc, val_unc::ValUnc};
+use ::pyo3::{
+    types::{PyAnyMethods, PyTuple},
+    Borrowed, Bound, FromPyObject, IntoPyObject, PyAny, PyErr, PyResult, Python,
+};
+
+impl<'a, 'py> FromPyObject<'a, 'py> for ValUnc<f64, Unc<f64>> {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let (val, unc) = if obj.hasattr("nominal_value")? && obj.hasattr("std_dev")? {
+            (
+                obj.getattr("nominal_value")?.extract()?,
+                obj.getattr("std_dev")?.extract()?,
+            )
+        } else {
+            obj.extract()?
+        };
+        Ok(ValUnc::new(val, Unc(unc)))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for ValUnc<f64, Unc<f64>> {
+    type Target = PyTuple;
+    type Output = Bound<'py, PyTuple>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        (self.val, self.unc.0).into_pyobject(py)
+    }
+}
+
+/// Converts `v` into a `ufloat` of the `uncertainties` package.
+///
+/// # Errors
+///
+/// Returns an error if `uncertainties` can't be imported.
+pub fn to_ufloat<'py>(py: Python<'py>, v: &ValUnc<f64, Unc<f64>>) -> PyResult<Bound<'py, PyAny>> {
+    py.import("uncertainties")?
+        .getattr("ufloat")?
+        .call1((v.val, v.unc.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::pyo3::ffi::c_str;
+
+    #[test]
+    fn from_py() {
+        Python::initialize();
+        Python::attach(|py| {
+            // The attributes of a `ufloat`, without needing `uncertainties`
+            let obj = py
+                .eval(
+                    c_str!("type('UFloat', (), {'nominal_value': 2.0, 'std_dev': 0.5})()"),
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert_eq!(
+                obj.extract::<ValUnc<f64>>().unwrap(),
+                ValUnc::new(2.0, Unc(0.5))
+            );
+
+            let obj = py.eval(c_str!("(3.0, 0.75)"), None, None).unwrap();
+            assert_eq!(
+                obj.extract::<ValUnc<f64>>().unwrap(),
+                ValUnc::new(3.0, Unc(0.75))
+            );
+
+            let obj = py.eval(c_str!("'3.0+/-0.75'"), None, None).unwrap();
+            assert!(obj.extract::<ValUnc<f64>>().is_err());
+        });
+    }
+}
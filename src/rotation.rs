@@ -0,0 +1,88 @@
+use nalgebra::{Matrix3, UnitQuaternion, Vector3};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A rotation with an uncertainty, e.g. the orientation reported by an IMU
+///
+/// The uncertainty is the covariance of a small rotation vector `δ` applied on the left, i.e. the
+/// true rotation is `exp(δ) * rot`. All propagation is first order in `δ`, and the operands of
+/// the binary operations are assumed to be independent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RotationUnc {
+    pub rot: UnitQuaternion<f64>,
+    pub cov: Matrix3<f64>,
+}
+
+impl RotationUnc {
+    pub fn new(rot: UnitQuaternion<f64>, cov: Matrix3<f64>) -> Self {
+        Self { rot, cov }
+    }
+
+    /// The rotation `self * other`, i.e. `other` followed by `self`.
+    pub fn compose(&self, other: &Self) -> Self {
+        let r = self.rot.to_rotation_matrix();
+        Self {
+            rot: self.rot * other.rot,
+            cov: self.cov + r.matrix() * other.cov * r.matrix().transpose(),
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        let r = self.rot.to_rotation_matrix();
+        Self {
+            rot: self.rot.inverse(),
+            cov: r.matrix().transpose() * self.cov * r.matrix(),
+        }
+    }
+
+    /// Rotates an exact vector, returning the result and its covariance.
+    pub fn apply(&self, v: &Vector3<f64>) -> (Vector3<f64>, Matrix3<f64>) {
+        self.apply_with_cov(v, &Matrix3::zeros())
+    }
+
+    /// Rotates a vector with covariance `v_cov`, returning the result and its covariance.
+    pub fn apply_with_cov(
+        &self,
+        v: &Vector3<f64>,
+        v_cov: &Matrix3<f64>,
+    ) -> (Vector3<f64>, Matrix3<f64>) {
+        let r = self.rot.to_rotation_matrix();
+        let rv = self.rot * v;
+        // exp(δ) R v ≈ R v + δ × R v = R v - [R v]× δ
+        let j = rv.cross_matrix();
+        let cov = j * self.cov * j.transpose() + r.matrix() * v_cov * r.matrix().transpose();
+        (rv, cov)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn rotation_unc() {
+        let var = 0.01;
+        let z90 = RotationUnc::new(
+            UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2),
+            Matrix3::from_diagonal(&Vector3::new(var, 0.0, 0.0)),
+        );
+
+        // The x uncertainty is rotated onto y.
+        let inv = z90.inverse();
+        assert!(f64::abs(inv.cov[(1, 1)] - var) <= 1e-12);
+        assert!(f64::abs(inv.cov[(0, 0)]) <= 1e-12);
+
+        let composed = z90.compose(&z90);
+        assert!(f64::abs(composed.cov[(0, 0)] - var) <= 1e-12);
+        assert!(f64::abs(composed.cov[(1, 1)] - var) <= 1e-12);
+        assert!(composed.rot.angle_to(&z90.rot.powf(2.0)) <= 1e-12);
+
+        // Rotating x onto y. An uncertainty about the x axis tilts y towards z.
+        let (v, cov) = z90.apply(&Vector3::x());
+        assert!((v - Vector3::y()).norm() <= 1e-12);
+        assert!(f64::abs(cov[(2, 2)] - var) <= 1e-12);
+        assert!(f64::abs(cov[(0, 0)]) <= 1e-12);
+    }
+}
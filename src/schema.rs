@@ -0,0 +1,143 @@
+//! JSON Schemas of the serde representations of `ValUnc`s (see [`serde_repr`](crate::serde_repr))
+
+use crate::{
+    serde_repr::{FixedRepr, Named},
+    unc::NonNegUnc,
+    val_unc::ValUnc,
+};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+impl<V, U> JsonSchema for ValUnc<V, U>
+where
+    V: JsonSchema,
+    U: JsonSchema,
+{
+    fn schema_name() -> Cow<'static, str> {
+        "ValUnc".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("val_unc::ValUnc<{}, {}>", V::schema_id(), U::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        map_schema::<V, U>(generator)
+    }
+}
+
+/// Returns the schema of the map with `val` and `unc` fields.
+fn map_schema<V, U>(generator: &mut SchemaGenerator) -> Schema
+where
+    V: JsonSchema,
+    U: JsonSchema,
+{
+    json_schema!({
+        "type": "object",
+        "properties": {
+            "val": generator.subschema_for::<V>(),
+            "unc": generator.subschema_for::<U>(),
+        },
+        "required": ["val", "unc"],
+    })
+}
+
+impl<V, U> JsonSchema for Named<ValUnc<V, U>>
+where
+    V: JsonSchema,
+    U: JsonSchema,
+{
+    fn schema_name() -> Cow<'static, str> {
+        "NamedValUnc".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!(
+            "val_unc::serde_repr::Named<val_unc::ValUnc<{}, {}>>",
+            V::schema_id(),
+            U::schema_id()
+        )
+        .into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        map_schema::<V, U>(generator)
+    }
+}
+
+impl<V, U> JsonSchema for FixedRepr<ValUnc<V, U>>
+where
+    V: JsonSchema,
+    U: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        <(V, U)>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        <(V, U)>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        <(V, U)>::json_schema(generator)
+    }
+}
+
+impl<U> JsonSchema for NonNegUnc<U>
+where
+    U: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        U::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        U::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        U::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        U::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unc;
+    use schemars::schema_for;
+    use serde_json::json;
+
+    #[test]
+    fn val_unc() {
+        let schema = schema_for!(ValUnc<f64, (Unc<f64>, Unc<f64>)>);
+        assert_eq!(schema.get("required"), Some(&json!(["val", "unc"])));
+        assert_eq!(
+            schema.get("properties"),
+            Some(&json!({
+                "val": { "type": "number", "format": "double" },
+                "unc": {
+                    "type": "array",
+                    "prefixItems": [
+                        { "$ref": "#/$defs/Unc" },
+                        { "$ref": "#/$defs/Unc" },
+                    ],
+                    "minItems": 2,
+                    "maxItems": 2,
+                },
+            }))
+        );
+
+        let named = schema_for!(Named<ValUnc<f64, Unc<f64>>>);
+        assert_eq!(named.get("required"), Some(&json!(["val", "unc"])));
+        let fixed = schema_for!(FixedRepr<ValUnc<f64, Unc<f64>>>);
+        assert_eq!(fixed.get("type"), Some(&json!("array")));
+    }
+}
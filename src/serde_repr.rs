@@ -0,0 +1,400 @@
+//! Serde representations of `ValUnc`s
+//!
+//! By default, a `ValUnc<V, U>` is (de)serialized as a map with `val` and `unc` fields, e.g.
+//! `{"val": 1.2, "unc": 0.1}`. Wrapping it in [`Named`] gives the same map, and also accepts
+//! common aliases of the field names when deserializing.
+//!
+//! Wrapping it in [`FixedRepr`] (de)serializes it as a `(V, U)` instead, which is more compact,
+//! e.g. for bincode, and flattens into the columns of a CSV record. For fields of structs,
+//! [`fixed`] can be used with `#[serde(with = "val_unc::serde_repr::fixed")]` instead.
+//!
+//! To use the map for human-readable formats, e.g. JSON, and the `(V, U)` for other formats, e.g.
+//! bincode, use [`human_readable`] with `#[serde(with = "val_unc::serde_repr::human_readable")]`.
+//!
+//! Data written by earlier versions of the crate as a `ValSysStat` can be read with [`legacy`].
+
+use crate::val_unc::ValUnc;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A `ValUnc` that is (de)serialized as a map, e.g. `{"val": 1.2, "unc": 0.1}`
+///
+/// When deserializing, `value` is also accepted for `val`, and `error`, `sigma`, and
+/// `uncertainty` for `unc`.
+///
+/// ```
+/// use val_unc::{serde_repr::Named, Unc, ValUnc};
+///
+/// let v = Named(ValUnc::new(1.2, Unc(0.1)));
+/// let json = serde_json::to_string(&v).unwrap();
+///
+/// assert_eq!(json, r#"{"val":1.2,"unc":0.1}"#);
+/// assert_eq!(serde_json::from_str::<Named<_>>(&json).unwrap(), v);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Named<T>(pub T);
+
+impl<V, U> From<ValUnc<V, U>> for Named<ValUnc<V, U>> {
+    fn from(val_unc: ValUnc<V, U>) -> Self {
+        Self(val_unc)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "ValUnc")]
+struct NamedRef<'a, V, U> {
+    val: &'a V,
+    unc: &'a U,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "ValUnc")]
+struct NamedOwned<V, U> {
+    #[serde(alias = "value")]
+    val: V,
+    #[serde(alias = "error", alias = "sigma", alias = "uncertainty")]
+    unc: U,
+}
+
+impl<V, U> Named<ValUnc<V, U>> {
+    fn serialize_ref<S>(val_unc: &ValUnc<V, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Serialize,
+        U: Serialize,
+        S: Serializer,
+    {
+        NamedRef {
+            val: &val_unc.val,
+            unc: &val_unc.unc,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<V, U> Serialize for Named<ValUnc<V, U>>
+where
+    V: Serialize,
+    U: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize_ref(&self.0, serializer)
+    }
+}
+
+impl<'de, V, U> Deserialize<'de> for Named<ValUnc<V, U>>
+where
+    V: Deserialize<'de>,
+    U: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let NamedOwned { val, unc } = NamedOwned::deserialize(deserializer)?;
+        Ok(Named(ValUnc::new(val, unc)))
+    }
+}
+
+/// A `ValUnc` that is always (de)serialized as a `(V, U)`, so that it can be used with formats
+/// that aren't self-describing
+///
+/// ```
+/// use val_unc::{serde_repr::FixedRepr, Unc, ValUnc};
+///
+/// let v = FixedRepr(ValUnc::new(1.2, Unc(0.0)));
+/// let json = serde_json::to_string(&v).unwrap();
+///
+/// assert_eq!(json, "[1.2,0.0]");
+/// assert_eq!(serde_json::from_str::<FixedRepr<_>>(&json).unwrap(), v);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct FixedRepr<T>(pub T);
+
+impl<V, U> From<ValUnc<V, U>> for FixedRepr<ValUnc<V, U>> {
+    fn from(val_unc: ValUnc<V, U>) -> Self {
+        Self(val_unc)
+    }
+}
+
+impl<V, U> Serialize for FixedRepr<ValUnc<V, U>>
+where
+    V: Serialize,
+    U: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        fixed::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, V, U> Deserialize<'de> for FixedRepr<ValUnc<V, U>>
+where
+    V: Deserialize<'de>,
+    U: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (val, unc) = Deserialize::deserialize(deserializer)?;
+        Ok(FixedRepr(ValUnc::new(val, unc)))
+    }
+}
+
+/// (De)serializes a `ValUnc` as a [`FixedRepr`], for use with `#[serde(with = "...")]`
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use val_unc::{Unc, ValUnc};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "val_unc::serde_repr::fixed")]
+///     energy: ValUnc<f64, Unc<f64>>,
+/// }
+///
+/// let row = Row { energy: ValUnc::new(1.2, Unc(0.0)) };
+/// let json = serde_json::to_string(&row).unwrap();
+///
+/// assert_eq!(json, r#"{"energy":[1.2,0.0]}"#);
+/// assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+/// ```
+pub mod fixed {
+    use super::*;
+
+    pub fn serialize<V, U, S>(val_unc: &ValUnc<V, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Serialize,
+        U: Serialize,
+        S: Serializer,
+    {
+        (&val_unc.val, &val_unc.unc).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, V, U, D>(deserializer: D) -> Result<ValUnc<V, U>, D::Error>
+    where
+        V: Deserialize<'de>,
+        U: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        FixedRepr::deserialize(deserializer).map(|FixedRepr(val_unc)| val_unc)
+    }
+}
+
+/// (De)serializes a `ValUnc` according to [`Serializer::is_human_readable`], for use with
+/// `#[serde(with = "...")]`
+///
+/// Human-readable formats, e.g. JSON, use the map of [`Named`], and other formats, e.g. bincode,
+/// use the `(V, U)` of [`FixedRepr`]. When deserializing a human-readable format, a `(V, U)` is
+/// also accepted.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use val_unc::{Unc, ValUnc};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "val_unc::serde_repr::human_readable")]
+///     energy: ValUnc<f64, Unc<f64>>,
+/// }
+///
+/// let row = Row { energy: ValUnc::new(1.2, Unc(0.1)) };
+/// let json = serde_json::to_string(&row).unwrap();
+///
+/// assert_eq!(json, r#"{"energy":{"val":1.2,"unc":0.1}}"#);
+/// assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+/// assert_eq!(serde_json::from_str::<Row>(r#"{"energy":[1.2,0.1]}"#).unwrap(), row);
+/// ```
+pub mod human_readable {
+    use super::*;
+
+    // Any of the representations, for deserializing human-readable formats
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AnyRepr<V, U> {
+        Named(NamedOwned<V, U>),
+        Tuple(V, U),
+    }
+
+    pub fn serialize<V, U, S>(val_unc: &ValUnc<V, U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Serialize,
+        U: Serialize,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            Named::serialize_ref(val_unc, serializer)
+        } else {
+            fixed::serialize(val_unc, serializer)
+        }
+    }
+
+    pub fn deserialize<'de, V, U, D>(deserializer: D) -> Result<ValUnc<V, U>, D::Error>
+    where
+        V: Deserialize<'de>,
+        U: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            Ok(match AnyRepr::deserialize(deserializer)? {
+                AnyRepr::Named(NamedOwned { val, unc }) | AnyRepr::Tuple(val, unc) => {
+                    ValUnc::new(val, unc)
+                }
+            })
+        } else {
+            fixed::deserialize(deserializer)
+        }
+    }
+}
+
+/// Deserializes the `(val, sys, stat)` layout of `ValSysStat` from earlier versions of the crate,
+/// for use with `#[serde(deserialize_with = "...")]`
+///
+/// The default representation of a `ValUnc<V, (Sys, Stat)>` is also accepted, so that archives
+/// with both can be read. This requires a self-describing format, e.g. JSON.
+///
+/// ```
+/// use serde::Deserialize;
+/// use val_unc::{define_unc, ValUnc};
+///
+/// define_unc! {
+///     pub struct SysUnc(f64) = linear, "sys";
+///     pub struct StatUnc(f64) = quadrature, "stat";
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Row {
+///     #[serde(deserialize_with = "val_unc::serde_repr::legacy::deserialize")]
+///     energy: ValUnc<f64, (SysUnc, StatUnc)>,
+/// }
+///
+/// let row: Row = serde_json::from_str(r#"{"energy":[18.7,2.5,5.0]}"#).unwrap();
+///
+/// assert_eq!(row.energy, ValUnc::new(18.7, (SysUnc(2.5), StatUnc(5.0))));
+/// ```
+pub mod legacy {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Versioned<V, Sys, Stat> {
+        ValSysStat(V, Sys, Stat),
+        ValUnc(ValUnc<V, (Sys, Stat)>),
+    }
+
+    pub fn deserialize<'de, V, Sys, Stat, D>(
+        deserializer: D,
+    ) -> Result<ValUnc<V, (Sys, Stat)>, D::Error>
+    where
+        V: Deserialize<'de>,
+        Sys: Deserialize<'de>,
+        Stat: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(match Versioned::deserialize(deserializer)? {
+            Versioned::ValSysStat(val, sys, stat) => ValUnc::new(val, (sys, stat)),
+            Versioned::ValUnc(val_unc) => val_unc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DofUnc, Unc};
+
+    #[test]
+    fn default() {
+        let v = ValUnc::new(1.5, (Unc(0.5), Unc(0.25)));
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"val":1.5,"unc":[0.5,0.25]}"#);
+        assert_eq!(serde_json::from_str::<ValUnc<_, _>>(&json).unwrap(), v);
+
+        // Uncertainty types without `UncZero` can be (de)serialized too
+        let v = ValUnc::new(1.5, DofUnc::new(0.5, 4.0));
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(serde_json::from_str::<ValUnc<_, _>>(&json).unwrap(), v);
+        assert_eq!(serde_json::to_string(&Named(v)).unwrap(), json);
+    }
+
+    #[test]
+    fn human_readable() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Row {
+            #[serde(with = "human_readable")]
+            x: ValUnc<f64, Unc<f64>>,
+        }
+
+        let row = Row {
+            x: ValUnc::new(1.5, Unc(0.0)),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"x":{"val":1.5,"unc":0.0}}"#);
+        for json in &[
+            &json[..],
+            r#"{"x":[1.5,0.0]}"#,
+            r#"{"x":{"value":1.5,"sigma":0.0}}"#,
+        ] {
+            assert_eq!(serde_json::from_str::<Row>(json).unwrap(), row);
+        }
+
+        let bytes = bincode::serialize(&row).unwrap();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bincode::deserialize::<Row>(&bytes).unwrap(), row);
+
+        // Without it, the default map is used for bincode too
+        assert_eq!(bincode::serialize(&row.x).unwrap().len(), 16);
+    }
+
+    #[test]
+    fn fixed() {
+        let v = FixedRepr(ValUnc::new(1.5, (Unc(0.5), Unc(0.0))));
+        let bytes = bincode::serialize(&v).unwrap();
+        assert_eq!(bincode::deserialize::<FixedRepr<_>>(&bytes).unwrap(), v);
+
+        let exact = FixedRepr(ValUnc::new(1.5, Unc(0.0)));
+        let bytes = bincode::serialize(&exact).unwrap();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bincode::deserialize::<FixedRepr<_>>(&bytes).unwrap(), exact);
+
+        let named = Named(ValUnc::new(1.5, Unc(0.5)));
+        let bytes = bincode::serialize(&named).unwrap();
+        assert_eq!(bincode::deserialize::<Named<_>>(&bytes).unwrap(), named);
+    }
+
+    #[test]
+    fn named() {
+        let v = Named(ValUnc::new(1.5, (Unc(0.5), Unc(0.0))));
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"val":1.5,"unc":[0.5,0.0]}"#);
+        assert_eq!(serde_json::from_str::<Named<_>>(&json).unwrap(), v);
+    }
+
+    #[test]
+    fn legacy() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row {
+            #[serde(deserialize_with = "legacy::deserialize")]
+            x: ValUnc<f64, (Unc<f64>, Unc<f64>)>,
+        }
+
+        let row = Row {
+            x: ValUnc::new(1.5, (Unc(0.5), Unc(0.25))),
+        };
+        for json in &[
+            r#"{"x":[1.5,0.5,0.25]}"#,
+            r#"{"x":{"val":1.5,"unc":[0.5,0.25]}}"#,
+        ] {
+            assert_eq!(serde_json::from_str::<Row>(json).unwrap(), row);
+        }
+    }
+
+    #[test]
+    fn aliases() {
+        let v = Named(ValUnc::new(1.5, Unc(0.5)));
+        for json in &[
+            r#"{"value":1.5,"unc":0.5}"#,
+            r#"{"val":1.5,"error":0.5}"#,
+            r#"{"value":1.5,"sigma":0.5}"#,
+            r#"{"val":1.5,"uncertainty":0.5}"#,
+        ] {
+            assert_eq!(serde_json::from_str::<Named<_>>(json).unwrap(), v);
+        }
+        assert!(
+            serde_json::from_str::<Named<ValUnc<f64, Unc<f64>>>>(r#"{"val":1.5,"err":0.5}"#)
+                .is_err()
+        );
+    }
+}
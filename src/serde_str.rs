@@ -0,0 +1,69 @@
+//! (De)serializes a `ValUnc` as a string, for use with `#[serde(with = "val_unc::serde_str")]`
+//!
+//! A `ValUnc<V, Unc<V>>` is serialized like `"1.23+/-0.04"`, as by the Python `uncertainties`
+//! package, and deserialized from any of the notations accepted by [`parse_val_unc`].
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use val_unc::{Unc, ValUnc};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Row {
+//!     #[serde(with = "val_unc::serde_str")]
+//!     energy: ValUnc<f64, Unc<f64>>,
+//! }
+//!
+//! let row = Row { energy: ValUnc::new(1.23, Unc(0.04)) };
+//! let json = serde_json::to_string(&row).unwrap();
+//!
+//! assert_eq!(json, r#"{"energy":"1.23+/-0.04"}"#);
+//! assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+//! ```
+
+use crate::{parse::parse_val_unc, unc::Unc, val_unc::ValUnc};
+use alloc::string::String;
+use core::{fmt::Display, str::FromStr};
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<V, S>(val_unc: &ValUnc<V, Unc<V>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    V: Display,
+    S: Serializer,
+{
+    serializer.collect_str(&format_args!("{}+/-{}", val_unc.val, val_unc.unc.0))
+}
+
+pub fn deserialize<'de, V, D>(deserializer: D) -> Result<ValUnc<V, Unc<V>>, D::Error>
+where
+    V: FromStr,
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_val_unc(&s).map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "crate::serde_str")]
+        x: ValUnc<f64, Unc<f64>>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let row = Row {
+            x: ValUnc::new(-1.5e-10, Unc(2.5e-12)),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"x":"-0.00000000015+/-0.0000000000025"}"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        let parsed: Row = serde_json::from_str(r#"{"x":"1.23(4)"}"#).unwrap();
+        assert_eq!(parsed.x, ValUnc::new(1.23, Unc(0.04)));
+        assert!(serde_json::from_str::<Row>(r#"{"x":"1.23"}"#).is_err());
+    }
+}
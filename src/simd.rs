@@ -0,0 +1,155 @@
+//! Bulk operations on slices of `ValUnc<f64, Unc<f64>>`, processing four elements at a time with
+//! SIMD instructions
+//!
+//! The results are identical to applying the operators element by element, since each lane does
+//! the same floating point operations in the same order. Like the operators, they aren't checked
+//! for invalid results; the `diagnostics` and `check-dims` features only check the operations of
+//! `diagnostics::Traced` and the `checked_*` methods of `ValUnc`.
+//!
+//! ```
+//! use val_unc::{simd, Unc, ValUnc};
+//!
+//! let a: Vec<_> = (1..=10).map(|i| ValUnc::new(i as f64, Unc(0.1))).collect();
+//! let b: Vec<_> = (1..=10).map(|i| ValUnc::new(2.0 * i as f64, Unc(0.2))).collect();
+//! let sum = simd::add(&a, &b);
+//!
+//! assert_eq!(sum[9], a[9] + b[9]);
+//! ```
+
+use crate::{unc::Unc, val_unc::ValUnc};
+use alloc::vec::Vec;
+use wide::f64x4;
+
+const LANES: usize = 4;
+
+/// Applies `f` to the values and uncertainties of four elements of `a` and `b` at a time, and
+/// `g` to the remaining elements.
+fn zip_lanes<F, G>(a: &[ValUnc<f64>], b: &[ValUnc<f64>], f: F, g: G) -> Vec<ValUnc<f64>>
+where
+    F: Fn(f64x4, f64x4, f64x4, f64x4) -> (f64x4, f64x4),
+    G: Fn(ValUnc<f64>, ValUnc<f64>) -> ValUnc<f64>,
+{
+    assert_eq!(a.len(), b.len(), "the lengths are different");
+    let mut out = Vec::with_capacity(a.len());
+    let chunks = a.chunks_exact(LANES).zip(b.chunks_exact(LANES));
+    for (a, b) in chunks {
+        let (val, unc) = f(vals(a), uncs(a), vals(b), uncs(b));
+        out.extend(
+            val.to_array()
+                .iter()
+                .zip(unc.to_array().iter())
+                .map(|(&v, &u)| ValUnc::new(v, Unc(u))),
+        );
+    }
+    let rest = a.len() - a.len() % LANES;
+    out.extend(a[rest..].iter().zip(&b[rest..]).map(|(&a, &b)| g(a, b)));
+    out
+}
+
+fn vals(a: &[ValUnc<f64>]) -> f64x4 {
+    f64x4::new([a[0].val, a[1].val, a[2].val, a[3].val])
+}
+
+fn uncs(a: &[ValUnc<f64>]) -> f64x4 {
+    f64x4::new([a[0].unc.0, a[1].unc.0, a[2].unc.0, a[3].unc.0])
+}
+
+/// Adds `a` and `b` element-wise.
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn add(a: &[ValUnc<f64>], b: &[ValUnc<f64>]) -> Vec<ValUnc<f64>> {
+    zip_lanes(
+        a,
+        b,
+        |av, au, bv, bu| (av + bv, (au * au + bu * bu).sqrt()),
+        |a, b| a + b,
+    )
+}
+
+/// Multiplies `a` and `b` element-wise.
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn mul(a: &[ValUnc<f64>], b: &[ValUnc<f64>]) -> Vec<ValUnc<f64>> {
+    zip_lanes(
+        a,
+        b,
+        |av, au, bv, bu| {
+            let (ar, br) = (au / av, bu / bv);
+            (av * bv, (ar * ar + br * br).sqrt() * av * bv)
+        },
+        |a, b| a * b,
+    )
+}
+
+/// Multiplies each element of `a` by the exact factor `k`.
+///
+/// Unlike multiplying by `ValUnc::new(k, Unc(0.0))`, the uncertainties are scaled directly, so
+/// they are always non-negative, and elements with a value of zero are handled.
+pub fn scale(a: &[ValUnc<f64>], k: f64) -> Vec<ValUnc<f64>> {
+    let kv = f64x4::splat(k);
+    let ku = f64x4::splat(k.abs());
+    let mut out = Vec::with_capacity(a.len());
+    for a in a.chunks_exact(LANES) {
+        let (val, unc) = (vals(a) * kv, uncs(a) * ku);
+        out.extend(
+            val.to_array()
+                .iter()
+                .zip(unc.to_array().iter())
+                .map(|(&v, &u)| ValUnc::new(v, Unc(u))),
+        );
+    }
+    let rest = a.len() - a.len() % LANES;
+    out.extend(
+        a[rest..]
+            .iter()
+            .map(|a| ValUnc::new(a.val * k, Unc(a.unc.0 * k.abs()))),
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(n: usize, seed: f64) -> Vec<ValUnc<f64>> {
+        (1..=n)
+            .map(|i| {
+                let x = seed * i as f64;
+                ValUnc::new(x.sin() + 2.0, Unc(x.cos().abs() / 10.0))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_scalar() {
+        // Not a multiple of the number of lanes, to check the remainder
+        let a = data(11, 0.7);
+        let b = data(11, 1.3);
+
+        let sum: Vec<_> = a.iter().zip(&b).map(|(&a, &b)| a + b).collect();
+        let prod: Vec<_> = a.iter().zip(&b).map(|(&a, &b)| a * b).collect();
+        assert_eq!(add(&a, &b), sum);
+        assert_eq!(mul(&a, &b), prod);
+    }
+
+    #[test]
+    fn scaled() {
+        let a = data(6, 0.7);
+        let scaled = scale(&a, -2.0);
+
+        for (a, s) in a.iter().zip(&scaled) {
+            assert_eq!(s.val, -2.0 * a.val);
+            assert_eq!(s.unc.0, 2.0 * a.unc.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn lengths() {
+        add(&data(4, 0.7), &data(5, 0.7));
+    }
+}
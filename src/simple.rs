@@ -0,0 +1,70 @@
+//! Implementations of the uncertainty traits for bare floats, so that e.g. `ValUnc<f64, f64>` can
+//! be used without defining an uncertainty type. The uncertainties are propagated like
+//! [`Unc`](crate::Unc).
+
+use crate::traits::{UncAdd, UncDiv, UncMul, UncNeg, UncSub, UncTotal, UncWeight};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+macro_rules! unc_float_impl {
+    ($($T:ty),+) => {$(
+        impl<V> UncAdd<V> for $T {
+            fn unc_add(self, _self_val: V, other: Self, _other_val: V) -> Self {
+                (self.powi(2) + other.powi(2)).sqrt()
+            }
+        }
+
+        impl UncDiv<$T> for $T {
+            fn unc_div(self, self_val: $T, other: Self, other_val: $T) -> Self {
+                ((self / self_val).powi(2) + (other / other_val).powi(2)).sqrt()
+                    * (self_val / other_val).abs()
+            }
+        }
+
+        impl UncMul<$T> for $T {
+            fn unc_mul(self, self_val: $T, other: Self, other_val: $T) -> Self {
+                ((self / self_val).powi(2) + (other / other_val).powi(2)).sqrt()
+                    * (self_val * other_val).abs()
+            }
+        }
+
+        impl<V> UncNeg<V> for $T {
+            fn unc_neg(self, _self_val: V) -> Self {
+                self
+            }
+        }
+
+        impl<V> UncSub<V> for $T {
+            fn unc_sub(self, _self_val: V, other: Self, _other_val: V) -> Self {
+                (self.powi(2) + other.powi(2)).sqrt()
+            }
+        }
+
+        impl UncTotal<$T> for $T {
+            fn total(&self, _val: $T) -> $T {
+                *self
+            }
+        }
+
+        impl UncWeight<$T> for $T {}
+    )+}
+}
+
+unc_float_impl!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::ValUnc;
+
+    #[test]
+    fn simple_f64() {
+        let v1 = ValUnc::new(2.0f64, 0.3f64);
+        let v2 = ValUnc::new(-1.0f64, 0.4f64);
+
+        assert!(f64::abs((v1 + v2).unc - 0.5) <= f64::EPSILON);
+        assert!(f64::abs((v1 - v2).unc - 0.5) <= f64::EPSILON);
+        assert!(f64::abs((-v1).unc - 0.3) <= f64::EPSILON);
+        assert!(f64::abs((v1 * v2).unc - f64::sqrt(0.09 + 0.64)) <= 1e-12);
+        assert!(f64::abs((v1 / v2).unc - f64::sqrt(0.09 + 0.64)) <= 1e-12);
+    }
+}
@@ -0,0 +1,325 @@
+//! A structure-of-arrays collection of [`ValUnc`]s
+//!
+//! A `Vec<ValUnc<V, U>>` interleaves the values and the uncertainty components, so a pass over
+//! just the values, or over one component, touches every element. [`ValUncVec`] instead stores
+//! the values and each uncertainty component in their own contiguous buffer, through
+//! [`UncColumns`].
+//!
+//! ```
+//! use val_unc::{Unc, UncStorage, ValUnc, ValUncVec};
+//!
+//! let a: ValUncVec<f64, (Unc<f64>, Unc<f64>)> = vec![
+//!     ValUnc::new(1.0, (Unc(0.3), Unc(0.1))),
+//!     ValUnc::new(2.0, (Unc(0.6), Unc(0.2))),
+//! ]
+//! .into_iter()
+//! .collect();
+//!
+//! assert_eq!(a.vals(), &[1.0, 2.0]);
+//! // The statistical uncertainties are contiguous
+//! assert_eq!(a.uncs().0, vec![0.3, 0.6]);
+//!
+//! let sum = &a + &a;
+//! assert_eq!(sum.get(1).unwrap(), a.get(1).unwrap() + a.get(1).unwrap());
+//! ```
+
+use crate::{unc::Unc, val_unc::ValUnc, UncStorage};
+use alloc::vec::Vec;
+use core::{
+    iter::FromIterator,
+    ops::{Add, Div, Mul, Sub},
+};
+
+/// An uncertainty that can be stored as separate buffers of its components
+///
+/// This is implemented for [`Unc`], `f32`, `f64`, and tuples and arrays of these. An uncertainty
+/// type with a single component can implement it with a `Vec` of itself as the `Columns`.
+pub trait UncColumns: Sized {
+    /// The buffers of the components
+    type Columns;
+
+    /// Creates empty buffers, with room for `capacity` elements.
+    fn with_capacity(capacity: usize) -> Self::Columns;
+
+    /// Appends the components of `self`.
+    fn push(self, columns: &mut Self::Columns);
+
+    /// Returns the element at index `i`, which must be in bounds.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `i` is out of bounds.
+    fn get(columns: &Self::Columns, i: usize) -> Self;
+}
+
+impl<T> UncColumns for Unc<T>
+where
+    T: Clone,
+{
+    type Columns = Vec<T>;
+
+    fn with_capacity(capacity: usize) -> Vec<T> {
+        Vec::with_capacity(capacity)
+    }
+
+    fn push(self, columns: &mut Vec<T>) {
+        columns.push(self.0);
+    }
+
+    fn get(columns: &Vec<T>, i: usize) -> Self {
+        Unc(columns[i].clone())
+    }
+}
+
+macro_rules! unc_columns_impl {
+    ($($T:ty),+) => {$(
+        impl UncColumns for $T {
+            type Columns = Vec<$T>;
+
+            fn with_capacity(capacity: usize) -> Vec<$T> {
+                Vec::with_capacity(capacity)
+            }
+
+            fn push(self, columns: &mut Vec<$T>) {
+                columns.push(self);
+            }
+
+            fn get(columns: &Vec<$T>, i: usize) -> Self {
+                columns[i]
+            }
+        }
+    )+}
+}
+
+unc_columns_impl!(f32, f64);
+
+impl<U, const N: usize> UncColumns for [U; N]
+where
+    U: UncColumns,
+{
+    type Columns = [U::Columns; N];
+
+    fn with_capacity(capacity: usize) -> Self::Columns {
+        core::array::from_fn(|_| U::with_capacity(capacity))
+    }
+
+    fn push(self, columns: &mut Self::Columns) {
+        for (u, c) in IntoIterator::into_iter(self).zip(columns.iter_mut()) {
+            u.push(c);
+        }
+    }
+
+    fn get(columns: &Self::Columns, i: usize) -> Self {
+        core::array::from_fn(|k| U::get(&columns[k], i))
+    }
+}
+
+macro_rules! unc_columns_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<$($T),*> UncColumns for ($($T,)*)
+        where
+            $($T: UncColumns),*
+        {
+            type Columns = ($($T::Columns,)*);
+
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn with_capacity(capacity: usize) -> Self::Columns {
+                ($($T::with_capacity(capacity),)*)
+            }
+
+            #[allow(unused_variables)]
+            fn push(self, columns: &mut Self::Columns) {
+                $(self.$idx.push(&mut columns.$idx);)*
+            }
+
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn get(columns: &Self::Columns, i: usize) -> Self {
+                ($($T::get(&columns.$idx, i),)*)
+            }
+        }
+    )+}
+}
+
+unc_columns_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
+
+/// A collection of `ValUnc`s that stores the values and each uncertainty component in separate
+/// buffers
+///
+/// The elements are accessed through [`UncStorage`], which returns them by value. Two
+/// `ValUncVec`s of the same length can be combined element-wise with the arithmetic operators on
+/// references, e.g. `&a + &b`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValUncVec<V, U>
+where
+    U: UncColumns,
+{
+    vals: Vec<V>,
+    uncs: U::Columns,
+}
+
+impl<V, U> ValUncVec<V, U>
+where
+    U: UncColumns,
+{
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vals: Vec::with_capacity(capacity),
+            uncs: U::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, v: ValUnc<V, U>) {
+        self.vals.push(v.val);
+        v.unc.push(&mut self.uncs);
+    }
+
+    /// The values.
+    pub fn vals(&self) -> &[V] {
+        &self.vals
+    }
+
+    /// The buffers of the uncertainty components, e.g. a tuple of `Vec`s for a tuple of
+    /// uncertainties.
+    pub fn uncs(&self) -> &U::Columns {
+        &self.uncs
+    }
+}
+
+impl<V, U> Default for ValUncVec<V, U>
+where
+    U: UncColumns,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, U> UncStorage for ValUncVec<V, U>
+where
+    V: Clone,
+    U: UncColumns,
+{
+    type Val = V;
+    type Unc = U;
+
+    fn len(&self) -> usize {
+        self.vals.len()
+    }
+
+    fn get(&self, i: usize) -> Option<ValUnc<V, U>> {
+        let val = self.vals.get(i)?.clone();
+        Some(ValUnc::new(val, U::get(&self.uncs, i)))
+    }
+}
+
+impl<V, U> FromIterator<ValUnc<V, U>> for ValUncVec<V, U>
+where
+    U: UncColumns,
+{
+    fn from_iter<I: IntoIterator<Item = ValUnc<V, U>>>(iter: I) -> Self {
+        let mut v = Self::new();
+        v.extend(iter);
+        v
+    }
+}
+
+impl<V, U> Extend<ValUnc<V, U>> for ValUncVec<V, U>
+where
+    U: UncColumns,
+{
+    fn extend<I: IntoIterator<Item = ValUnc<V, U>>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+// This implements the binary operators element-wise for references to `ValUncVec`s.
+macro_rules! soa_ops {
+    ($(($Tr:ident, $f:ident)),+) => {$(
+        /// # Panics
+        ///
+        /// Panics if the lengths are different.
+        impl<V, U> $Tr<&ValUncVec<V, U>> for &ValUncVec<V, U>
+        where
+            V: Clone,
+            U: UncColumns,
+            ValUnc<V, U>: $Tr<Output = ValUnc<V, U>>,
+        {
+            type Output = ValUncVec<V, U>;
+
+            fn $f(self, other: &ValUncVec<V, U>) -> ValUncVec<V, U> {
+                assert_eq!(self.len(), other.len(), "the lengths are different");
+                let mut out = ValUncVec::with_capacity(self.len());
+                out.extend(self.iter().zip(other.iter()).map(|(a, b)| a.$f(b)));
+                out
+            }
+        }
+    )+}
+}
+
+soa_ops!((Add, add), (Div, div), (Mul, mul), (Sub, sub));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        let aos = [
+            ValUnc::new(1.0, [Unc(0.1), Unc(0.2)]),
+            ValUnc::new(2.0, [Unc(0.3), Unc(0.4)]),
+            ValUnc::new(3.0, [Unc(0.5), Unc(0.6)]),
+        ];
+        let soa: ValUncVec<_, _> = aos.iter().cloned().collect();
+
+        assert_eq!(soa.len(), 3);
+        assert_eq!(soa.vals(), &[1.0, 2.0, 3.0]);
+        assert_eq!(soa.uncs()[1], vec![0.2, 0.4, 0.6]);
+        assert!(soa.iter().eq(aos.iter().cloned()));
+        assert_eq!(soa.get(3), None);
+        assert_eq!(soa.sum(), aos[..].sum());
+    }
+
+    #[test]
+    fn ops() {
+        let a: ValUncVec<f64, (Unc<f64>,)> = (1..=4)
+            .map(|i| ValUnc::new(i as f64, (Unc(0.1 * i as f64),)))
+            .collect();
+        let b: ValUncVec<f64, (Unc<f64>,)> = (1..=4)
+            .map(|i| ValUnc::new(10.0 * i as f64, (Unc(0.5),)))
+            .collect();
+
+        let check = |c: ValUncVec<_, _>, f: fn(_, _) -> _| {
+            assert!(c.iter().eq(a.iter().zip(b.iter()).map(|(x, y)| f(x, y))));
+        };
+        check(&a + &b, |x: ValUnc<_, _>, y| x + y);
+        check(&a - &b, |x: ValUnc<_, _>, y| x - y);
+        check(&a * &b, |x: ValUnc<_, _>, y| x * y);
+        check(&a / &b, |x: ValUnc<_, _>, y| x / y);
+    }
+}
@@ -0,0 +1,185 @@
+//! Converting `ValUnc`s to and from `statrs` distributions
+//!
+//! [`UncDistribution`] gives the distribution of a measurement, according to the kind of its
+//! uncertainty: normal for [`Unc`], Student's t for [`DofUnc`], and uniform for
+//! [`ResolutionUnc`], matching their coverage factors (see [`UncCoverage`]). With it,
+//! [`ValUnc::p_value`] and [`ValUnc::quantile`] test values against a measurement.
+//!
+//! In the other direction, [`ValUnc::from_distribution`] takes the mean and the standard
+//! deviation of any distribution, e.g. one fitted to data.
+//!
+//! [`UncCoverage`]: crate::UncCoverage
+//!
+//! ```
+//! use statrs::distribution::Normal;
+//! use val_unc::{DofUnc, Unc, ValUnc};
+//!
+//! let v = ValUnc::new(10.0, Unc(0.5));
+//! assert!(f64::abs(v.p_value(11.0).unwrap() - 0.0455) <= 1e-4);
+//!
+//! // With few degrees of freedom, the tails are heavier
+//! let v = ValUnc::new(10.0, DofUnc::new(0.5, 4.0));
+//! assert!(f64::abs(v.p_value(11.0).unwrap() - 0.1161) <= 1e-4);
+//!
+//! let normal = Normal::new(1.0, 0.25).unwrap();
+//! assert_eq!(ValUnc::from(normal), ValUnc::new(1.0, Unc(0.25)));
+//! ```
+
+use crate::{
+    unc::{DofUnc, NonNegUnc, ResolutionUnc, Unc},
+    val_unc::ValUnc,
+};
+use ::statrs::{
+    distribution::{ContinuousCDF, Normal, NormalError, StudentsT, Uniform},
+    statistics::Distribution,
+};
+use core::convert::TryFrom;
+
+/// The distribution of a measurement with an uncertainty
+///
+/// This is implemented for [`Unc<f64>`], [`DofUnc`], [`ResolutionUnc<f64>`], and [`NonNegUnc`]
+/// of these.
+pub trait UncDistribution {
+    /// The type of the distribution
+    type Dist: ContinuousCDF<f64, f64>;
+
+    /// Returns the distribution of a measurement with value `val` and this uncertainty, or `None`
+    /// if the uncertainty doesn't define one, e.g. if it's zero.
+    fn distribution(&self, val: f64) -> Option<Self::Dist>;
+}
+
+impl UncDistribution for Unc<f64> {
+    type Dist = Normal;
+
+    fn distribution(&self, val: f64) -> Option<Normal> {
+        Normal::new(val, self.0).ok()
+    }
+}
+
+impl UncDistribution for DofUnc {
+    type Dist = StudentsT;
+
+    fn distribution(&self, val: f64) -> Option<StudentsT> {
+        StudentsT::new(val, self.u, self.nu).ok()
+    }
+}
+
+impl UncDistribution for ResolutionUnc<f64> {
+    type Dist = Uniform;
+
+    fn distribution(&self, val: f64) -> Option<Uniform> {
+        let half_width = 0.5 * self.resolution();
+        Uniform::new(val - half_width, val + half_width).ok()
+    }
+}
+
+impl<U> UncDistribution for NonNegUnc<U>
+where
+    U: UncDistribution,
+{
+    type Dist = U::Dist;
+
+    fn distribution(&self, val: f64) -> Option<U::Dist> {
+        self.get().distribution(val)
+    }
+}
+
+impl<U> ValUnc<f64, U>
+where
+    U: UncDistribution,
+{
+    /// Returns the distribution of the measurement (see [`UncDistribution`]).
+    pub fn distribution(&self) -> Option<U::Dist> {
+        self.unc.distribution(self.val)
+    }
+
+    /// Returns the two-sided p-value of `x`, i.e. the probability of a value at least as far from
+    /// `val` as `x`, or `None` if the uncertainty doesn't define a distribution.
+    pub fn p_value(&self, x: f64) -> Option<f64> {
+        let dist = self.distribution()?;
+        Some(f64::min(2.0 * f64::min(dist.cdf(x), dist.sf(x)), 1.0))
+    }
+
+    /// Returns the value below which the fraction `p` of the distribution lies, or `None` if `p`
+    /// isn't in `[0, 1]` or the uncertainty doesn't define a distribution.
+    pub fn quantile(&self, p: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        Some(self.distribution()?.inverse_cdf(p))
+    }
+}
+
+impl ValUnc<f64, Unc<f64>> {
+    /// Creates a `ValUnc` from the mean and the standard deviation of `dist`, or returns `None` if
+    /// they aren't defined.
+    ///
+    /// ```
+    /// use statrs::distribution::StudentsT;
+    /// use val_unc::{Unc, ValUnc};
+    ///
+    /// let t = StudentsT::new(2.0, 1.0, 4.0).unwrap();
+    /// assert_eq!(ValUnc::from_distribution(&t), Some(ValUnc::new(2.0, Unc(f64::sqrt(2.0)))));
+    ///
+    /// // The standard deviation of the Cauchy distribution isn't defined
+    /// let t = StudentsT::new(2.0, 1.0, 1.0).unwrap();
+    /// assert_eq!(ValUnc::from_distribution(&t), None);
+    /// ```
+    pub fn from_distribution<D>(dist: &D) -> Option<Self>
+    where
+        D: Distribution<f64>,
+    {
+        Some(ValUnc::new(dist.mean()?, Unc(dist.std_dev()?)))
+    }
+}
+
+impl TryFrom<ValUnc<f64, Unc<f64>>> for Normal {
+    type Error = NormalError;
+
+    fn try_from(v: ValUnc<f64, Unc<f64>>) -> Result<Self, NormalError> {
+        Normal::new(v.val, v.unc.0)
+    }
+}
+
+impl From<Normal> for ValUnc<f64, Unc<f64>> {
+    fn from(dist: Normal) -> Self {
+        ValUnc::new(dist.mean().unwrap(), Unc(dist.std_dev().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normal() {
+        let v = ValUnc::new(1.0, Unc(2.0));
+        let normal = Normal::try_from(v).unwrap();
+        assert_eq!((normal.mean(), normal.std_dev()), (Some(1.0), Some(2.0)));
+        assert_eq!(ValUnc::from(normal), v);
+        assert!(Normal::try_from(ValUnc::new(1.0, Unc(0.0))).is_err());
+
+        assert_eq!(v.p_value(1.0), Some(1.0));
+        assert!(f64::abs(v.p_value(-1.0).unwrap() - 0.317_310_5) <= 1e-7);
+        assert!(f64::abs(v.quantile(0.975).unwrap() - (1.0 + 2.0 * 1.959_964)) <= 1e-5);
+        assert_eq!(v.quantile(1.5), None);
+        assert_eq!(ValUnc::new(1.0, Unc(0.0)).p_value(1.0), None);
+    }
+
+    #[test]
+    fn kinds() {
+        // The quantiles match the coverage factors
+        let v = ValUnc::new(10.0, DofUnc::new(0.5, 4.0));
+        let (_, upper) = v.at_confidence(0.95);
+        assert!(f64::abs(v.quantile(0.975).unwrap() - upper) <= 1e-6);
+
+        // A uniform distribution of width 2
+        let v = ValUnc::new(1.0, ResolutionUnc::new(2.0));
+        assert!(f64::abs(v.quantile(0.75).unwrap() - 1.5) <= 1e-12);
+        assert!(f64::abs(v.p_value(0.5).unwrap() - 0.5) <= 1e-12);
+        assert_eq!(v.p_value(2.5), Some(0.0));
+
+        let v = ValUnc::new(1.0, NonNegUnc::new(Unc(2.0)).unwrap());
+        assert_eq!(v.p_value(3.0), ValUnc::new(1.0, Unc(2.0)).p_value(3.0));
+    }
+}
@@ -0,0 +1,574 @@
+//! Statistical combinations of measurements
+//!
+//! [`weighted_mean`] averages independent measurements of the same quantity.
+//! [`scaled_weighted_mean`] also checks their consistency, and inflates the uncertainty of the
+//! average if they are inconsistent. [`robust_combine`] uses the median instead, so that a few
+//! outliers don't pull the result.
+//!
+//! For data that arrives as a stream, [`Accumulator`] computes the mean and standard error of
+//! readings without storing them, and [`ValUncAccumulator`] sums `ValUnc`s. For long sums,
+//! [`sum_compensated`] is more accurate and faster than adding the `ValUnc`s one by one.
+//!
+//! [`chi2`] and [`chi2_p_value`] test the agreement of measured points with a model.
+//!
+//! ```
+//! use val_unc::{stats, Unc, ValUnc};
+//!
+//! let v = [ValUnc::new(9.0, Unc(1.0)), ValUnc::new(10.5, Unc(0.5))];
+//! let mean = stats::weighted_mean(v.iter().cloned()).unwrap();
+//!
+//! assert!(f64::abs(mean.val - 10.2) <= 1e-12);
+//! assert!(f64::abs(mean.unc.0 - f64::sqrt(0.2)) <= 1e-12);
+//! ```
+
+use crate::{
+    dist::chi2_sf,
+    traits::{Real, UncTotal, UncWeight},
+    unc::{DofUnc, Unc},
+    val_unc::ValUnc,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    iter::FromIterator,
+    ops::{Add, Div},
+};
+
+/// The mean of independent measurements of the same quantity, weighted according to
+/// [`UncWeight`], and its uncertainty, or `None` if there are no measurements.
+///
+/// With the default weights, the inverses of the variances, this is the maximum likelihood
+/// estimate for Gaussian measurements. The uncertainty is `1 / √Σw`. A measurement with no
+/// uncertainty gets an infinite weight, and the result is NaN.
+pub fn weighted_mean<I, V, U>(iter: I) -> Option<ValUnc<V, Unc<V>>>
+where
+    I: IntoIterator<Item = ValUnc<V, U>>,
+    V: Real,
+    U: UncWeight<V>,
+{
+    let mut n = 0;
+    let (sum_w, sum_wx) = iter
+        .into_iter()
+        .fold((V::zero(), V::zero()), |(sum_w, sum_wx), v| {
+            n += 1;
+            let w = v.unc.weight(v.val.clone());
+            (sum_w + w.clone(), sum_wx + w * v.val)
+        });
+    if n == 0 {
+        return None;
+    }
+    Some(ValUnc::new(
+        sum_wx / sum_w.clone(),
+        Unc(V::one() / sum_w.sqrt()),
+    ))
+}
+
+/// A weighted mean with its goodness of fit, returned by [`scaled_weighted_mean`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ScaledMean<V> {
+    /// The weighted mean, with its uncertainty multiplied by `scale`
+    pub mean: ValUnc<V, Unc<V>>,
+    /// The χ² of the measurements with respect to the mean
+    pub chi2: V,
+    /// The number of degrees of freedom, one less than the number of measurements
+    pub ndf: usize,
+    /// The factor the uncertainty was multiplied by, `√(χ²/ndf)` or 1, whichever is larger
+    pub scale: V,
+}
+
+/// The weighted mean of independent measurements of the same quantity, with its uncertainty
+/// scaled up if the measurements are inconsistent, or `None` if there are no measurements.
+///
+/// This follows the procedure of the Particle Data Group: the uncertainty of the
+/// [`weighted_mean`] is multiplied by `√(χ²/ndf)` if that is greater than 1, where `χ² = Σw(x -
+/// x̄)²` and `ndf` is one less than the number of measurements. The PDG's exclusion of
+/// measurements with much larger uncertainties than the rest from the scale factor isn't done
+/// here. With a single measurement, there are no degrees of freedom, and the scale is 1.
+///
+/// The iterator is traversed twice, once for the mean and once for the χ².
+///
+/// ```
+/// use val_unc::{stats, Unc, ValUnc};
+///
+/// let v = [ValUnc::new(1.0, Unc(0.5)), ValUnc::new(3.0, Unc(0.5))];
+/// let m = stats::scaled_weighted_mean(v.iter().cloned()).unwrap();
+///
+/// assert_eq!(m.chi2, 8.0);
+/// assert_eq!(m.ndf, 1);
+/// assert!(f64::abs(m.mean.unc.0 - 1.0) <= 1e-12);
+/// ```
+pub fn scaled_weighted_mean<I, V, U>(iter: I) -> Option<ScaledMean<V>>
+where
+    I: IntoIterator<Item = ValUnc<V, U>>,
+    I::IntoIter: Clone,
+    V: Real,
+    U: UncWeight<V>,
+{
+    let iter = iter.into_iter();
+    let mean = weighted_mean(iter.clone())?;
+    let (ndf, chi2) = iter.fold((0, V::zero()), |(ndf, chi2), v| {
+        let w = v.unc.weight(v.val.clone());
+        (ndf + 1, chi2 + w * (v.val - mean.val.clone()).powi(2))
+    });
+    let ndf = ndf - 1;
+    // The number of degrees of freedom, as a V
+    let ndf_v = (0..ndf).fold(V::zero(), |n, _| n + V::one());
+    let scale = if ndf > 0 && chi2 > ndf_v {
+        (chi2.clone() / ndf_v).sqrt()
+    } else {
+        V::one()
+    };
+    Some(ScaledMean {
+        mean: ValUnc::new(mean.val, Unc(mean.unc.0 * scale.clone())),
+        chi2,
+        ndf,
+        scale,
+    })
+}
+
+/// The median of `x`, which must not be empty.
+#[cfg(feature = "alloc")]
+fn median(mut x: Vec<f64>) -> f64 {
+    x.sort_by(f64::total_cmp);
+    let n = x.len();
+    if n % 2 == 1 {
+        x[n / 2]
+    } else {
+        0.5 * (x[n / 2 - 1] + x[n / 2])
+    }
+}
+
+/// Combines measurements of the same quantity that may include outliers, with the median, or
+/// returns `None` if there are no measurements.
+///
+/// The spread of the measurements is estimated from their median absolute deviation, scaled by
+/// 1.4826 to be the standard deviation for Gaussian measurements, or from the median of their
+/// total uncertainties, whichever is larger. The uncertainty of the result is the standard
+/// error of the median for that spread, `1.2533 σ / √n`. Unlike the weighted mean, the result
+/// doesn't change if a few measurements are moved arbitrarily far away.
+///
+/// ```
+/// use val_unc::{stats, Unc, ValUnc};
+///
+/// let v = [10.1, 9.9, 10.0, 10.2, 25.0].map(|x| ValUnc::new(x, Unc(0.1)));
+/// let combined = stats::robust_combine(&v).unwrap();
+///
+/// assert_eq!(combined.val, 10.1);
+/// assert!(combined.unc.0 < 0.2);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn robust_combine<U>(data: &[ValUnc<f64, U>]) -> Option<ValUnc<f64>>
+where
+    U: UncTotal<f64>,
+{
+    if data.is_empty() {
+        return None;
+    }
+    let med = median(data.iter().map(|v| v.val).collect());
+    let mad = median(data.iter().map(|v| (v.val - med).abs()).collect());
+    let reported = median(data.iter().map(|v| v.unc.total(v.val)).collect());
+    let sigma = f64::max(1.4826 * mad, reported);
+    let n = data.len() as f64;
+    Some(ValUnc::new(med, Unc(1.2533 * sigma / n.sqrt())))
+}
+
+/// Adds `x` to a sum with Neumaier's compensation, where `c` accumulates the lost low-order
+/// bits.
+fn add_compensated<V: Real>((sum, c): (V, V), x: V) -> (V, V) {
+    let t = sum.clone() + x.clone();
+    let c = if sum.clone().abs() >= x.clone().abs() {
+        c + ((sum - t.clone()) + x)
+    } else {
+        c + ((x - t.clone()) + sum)
+    };
+    (t, c)
+}
+
+/// Sums independent `ValUnc`s, with compensated summation for the values and for the squares of
+/// the total uncertainties.
+///
+/// Adding `ValUnc`s with `+` rounds the value at every step and takes a square root for every
+/// uncertainty. Here, the rounding errors of the values are accumulated separately, with
+/// Neumaier's variant of Kahan summation, and added back at the end, and the uncertainty is the
+/// square root of the sum of the squares, taken once. The sum of nothing is zero.
+///
+/// ```
+/// use val_unc::{stats, Unc, ValUnc};
+///
+/// let v = [1.0, 1e100, 1.0, -1e100].map(|x| ValUnc::new(x, Unc(0.0)));
+///
+/// assert_eq!(stats::sum_compensated(v.iter().cloned()).val, 2.0);
+/// ```
+pub fn sum_compensated<I, V, U>(iter: I) -> ValUnc<V, Unc<V>>
+where
+    I: IntoIterator<Item = ValUnc<V, U>>,
+    V: Real,
+    U: UncTotal<V>,
+{
+    let zero = || (V::zero(), V::zero());
+    let (val, var) = iter.into_iter().fold((zero(), zero()), |(val, var), v| {
+        let u = v.unc.total(v.val.clone());
+        (
+            add_compensated(val, v.val),
+            add_compensated(var, u.clone() * u),
+        )
+    });
+    ValUnc::new(val.0 + val.1, Unc((var.0 + var.1).sqrt()))
+}
+
+/// The χ² of measured points `data` with respect to the predictions `model`, `Σ((x - m) / u)²`
+/// where `u` is the total uncertainty of each point, and the number of points.
+///
+/// The number of points is the number of degrees of freedom for a model without free
+/// parameters. For a fitted model, subtract the number of fitted parameters.
+///
+/// ```
+/// use val_unc::{stats, Unc, ValUnc};
+///
+/// let data = [ValUnc::new(1.0, Unc(0.5)), ValUnc::new(2.5, Unc(0.5)), ValUnc::new(3.0, Unc(1.0))];
+/// let (chi2, n) = stats::chi2(&data, &[1.0, 2.0, 3.0]);
+///
+/// assert_eq!((chi2, n), (1.0, 3));
+/// assert!(f64::abs(stats::chi2_p_value(chi2, n) - 0.801_251_7) <= 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the lengths are different.
+pub fn chi2<U>(data: &[ValUnc<f64, U>], model: &[f64]) -> (f64, usize)
+where
+    U: UncTotal<f64>,
+{
+    assert_eq!(data.len(), model.len(), "the lengths are different");
+    let chi2 = data
+        .iter()
+        .zip(model)
+        .map(|(d, m)| ((d.val - m) / d.unc.total(d.val)).powi(2))
+        .sum();
+    (chi2, data.len())
+}
+
+/// The probability of a χ² at least as large as `chi2` with `ndf` degrees of freedom, if the
+/// model is correct and the uncertainties are Gaussian.
+///
+/// A small p-value means that the model, or the uncertainties, are unlikely to be right.
+pub fn chi2_p_value(chi2: f64, ndf: usize) -> f64 {
+    chi2_sf(chi2, ndf as f64)
+}
+
+/// Accumulates the mean and standard error of a stream of readings
+///
+/// The mean and the sum of squared deviations are updated with Welford's algorithm, which is
+/// numerically stable, and the result is the same as [`ValUnc::from_samples`].
+///
+/// ```
+/// use val_unc::stats::Accumulator;
+///
+/// let mut acc = Accumulator::new();
+/// for x in [9.0, 10.0, 11.0, 10.0] {
+///     acc.push(x);
+/// }
+/// let v = acc.finalize().unwrap();
+///
+/// assert_eq!(v.val, 10.0);
+/// assert_eq!(v.unc.nu, 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Accumulator {
+    n: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Accumulator {
+    pub const fn new() -> Self {
+        Self {
+            n: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Adds a reading.
+    pub fn push(&mut self, x: f64) {
+        self.n += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// The number of readings.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// The mean of the readings so far, with the standard error of the mean and `n - 1` degrees
+    /// of freedom, or `None` if there are fewer than two readings.
+    pub fn finalize(&self) -> Option<ValUnc<f64, DofUnc>> {
+        if self.n < 2 {
+            return None;
+        }
+        let n = self.n as f64;
+        let u = f64::sqrt(self.m2 / (n - 1.0) / n);
+        Some(ValUnc::new(self.mean, DofUnc::new(u, n - 1.0)))
+    }
+}
+
+impl Extend<f64> for Accumulator {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl FromIterator<f64> for Accumulator {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+/// Accumulates the sum of a stream of `ValUnc`s, propagating their uncertainties
+///
+/// Unlike [`Accumulator`], the spread of the values isn't used. The uncertainty of the result
+/// comes only from the uncertainties of the inputs, so this is for e.g. adding up contributions
+/// or averaging measurements with known uncertainties.
+///
+/// ```
+/// use val_unc::{stats::ValUncAccumulator, Unc, ValUnc};
+///
+/// let mut acc = ValUncAccumulator::new();
+/// acc.push(ValUnc::new(1.0, Unc(0.3)));
+/// acc.push(ValUnc::new(2.0, Unc(0.4)));
+///
+/// assert_eq!(acc.sum(), Some(&ValUnc::new(3.0, Unc(0.5))));
+/// assert_eq!(acc.finalize(), Some(ValUnc::new(1.5, Unc(0.25))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValUncAccumulator<V, U> {
+    n: usize,
+    sum: Option<ValUnc<V, U>>,
+}
+
+impl<V, U> ValUncAccumulator<V, U> {
+    pub const fn new() -> Self {
+        Self { n: 0, sum: None }
+    }
+
+    /// Adds `v` to the sum.
+    pub fn push(&mut self, v: ValUnc<V, U>)
+    where
+        ValUnc<V, U>: Add<Output = ValUnc<V, U>>,
+    {
+        self.n += 1;
+        self.sum = Some(match self.sum.take() {
+            Some(sum) => sum + v,
+            None => v,
+        });
+    }
+
+    /// The number of `ValUnc`s.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// The sum so far, or `None` if nothing has been added.
+    pub fn sum(&self) -> Option<&ValUnc<V, U>> {
+        self.sum.as_ref()
+    }
+
+    /// The mean so far, or `None` if nothing has been added.
+    ///
+    /// The sum is divided by the exact count, so the uncertainty is divided by it too.
+    pub fn finalize(&self) -> Option<ValUnc<V, U>>
+    where
+        V: Real,
+        U: Clone + Div<V, Output = U>,
+    {
+        let sum = self.sum.clone()?;
+        let n = (0..self.n).fold(V::zero(), |n, _| n + V::one());
+        Some(ValUnc::new(sum.val / n.clone(), sum.unc / n))
+    }
+}
+
+impl<V, U> Default for ValUncAccumulator<V, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, U> Extend<ValUnc<V, U>> for ValUncAccumulator<V, U>
+where
+    ValUnc<V, U>: Add<Output = ValUnc<V, U>>,
+{
+    fn extend<I: IntoIterator<Item = ValUnc<V, U>>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl<V, U> FromIterator<ValUnc<V, U>> for ValUncAccumulator<V, U>
+where
+    ValUnc<V, U>: Add<Output = ValUnc<V, U>>,
+{
+    fn from_iter<I: IntoIterator<Item = ValUnc<V, U>>>(iter: I) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unc::{MomentsUnc, RelUnc};
+
+    #[test]
+    fn weights() {
+        let v = [
+            ValUnc::new(2.0, RelUnc(0.5)),
+            ValUnc::new(4.0, RelUnc(0.125)),
+        ];
+        let mean = weighted_mean(v.iter().cloned()).unwrap();
+
+        // The total uncertainties are 1 and 0.5, so the weights are 1 and 4
+        assert!(f64::abs(mean.val - 18.0 / 5.0) <= f64::EPSILON);
+        assert!(f64::abs(mean.unc.0 - f64::sqrt(0.2)) <= f64::EPSILON);
+        assert_eq!(weighted_mean(v[..0].iter().cloned()), None);
+    }
+
+    #[test]
+    fn moments() {
+        let v = [
+            ValUnc::new(1.0, MomentsUnc::new(0.25, 0.1, 0.3)),
+            ValUnc::new(3.0, MomentsUnc::new(0.25, -0.1, 0.3)),
+        ];
+        let mean = weighted_mean(v.iter().cloned()).unwrap();
+
+        assert_eq!(MomentsUnc::new(0.25, 0.0, 0.0).weight(0.0), 4.0);
+        assert_eq!(mean.val, 2.0);
+        assert!(f64::abs(mean.unc.0 - f64::sqrt(0.125)) <= f64::EPSILON);
+    }
+
+    #[test]
+    fn scaled() {
+        // Consistent measurements aren't scaled
+        let v = [
+            ValUnc::new(1.0, Unc(1.0)),
+            ValUnc::new(1.5, Unc(1.0)),
+            ValUnc::new(2.0, Unc(1.0)),
+        ];
+        let m = scaled_weighted_mean(v.iter().cloned()).unwrap();
+        assert_eq!(m.chi2, 0.5);
+        assert_eq!(m.ndf, 2);
+        assert_eq!(m.scale, 1.0);
+        assert_eq!(m.mean, weighted_mean(v.iter().cloned()).unwrap());
+
+        // Inconsistent ones are
+        let v = [
+            ValUnc::new(0.0, Unc(0.5)),
+            ValUnc::new(3.0, Unc(0.5)),
+            ValUnc::new(6.0, Unc(0.5)),
+        ];
+        let m = scaled_weighted_mean(v.iter().cloned()).unwrap();
+        assert_eq!(m.chi2, 72.0);
+        assert_eq!(m.scale, 6.0);
+        assert!(f64::abs(m.mean.val - 3.0) <= 1e-12);
+        assert!(f64::abs(m.mean.unc.0 - 6.0 * f64::sqrt(1.0 / 12.0)) <= 1e-12);
+
+        let m = scaled_weighted_mean(v[..1].iter().cloned()).unwrap();
+        assert_eq!((m.chi2, m.ndf, m.scale), (0.0, 0, 1.0));
+        assert_eq!(scaled_weighted_mean(v[..0].iter().cloned()), None);
+    }
+
+    #[test]
+    fn accumulators() {
+        let x = [
+            1.0e9 + 1.0,
+            1.0e9 + 2.0,
+            1.0e9 + 4.0,
+            1.0e9 + 7.0,
+            1.0e9 + 11.0,
+        ];
+        let acc: Accumulator = x.iter().cloned().collect();
+        let v = ValUnc::from_samples(&x).unwrap();
+
+        // Large offsets don't lose the spread
+        assert_eq!(acc.len(), 5);
+        assert!(f64::abs(acc.finalize().unwrap().val - v.val) <= 1e-6);
+        assert!(f64::abs(acc.finalize().unwrap().unc.u - v.unc.u) <= 1e-6);
+        assert_eq!(Accumulator::from_iter([1.0]).finalize(), None);
+
+        let acc: ValUncAccumulator<_, _> = [
+            ValUnc::new(2.0, Unc(0.3)),
+            ValUnc::new(4.0, Unc(0.4)),
+            ValUnc::new(6.0, Unc(1.2)),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        let mean = acc.finalize().unwrap();
+        assert_eq!(acc.len(), 3);
+        assert!(f64::abs(mean.val - 4.0) <= 1e-12);
+        assert!(f64::abs(mean.unc.0 - 1.3 / 3.0) <= 1e-12);
+        assert_eq!(ValUncAccumulator::<f64, Unc<f64>>::new().finalize(), None);
+    }
+
+    #[test]
+    fn compensated() {
+        let v: Vec<_> = (0..10_000).map(|_| ValUnc::new(0.1, RelUnc(0.1))).collect();
+        let sum = sum_compensated(v.iter().cloned());
+
+        assert!(f64::abs(sum.val - 1000.0) <= 1e-12);
+        assert!(f64::abs(sum.unc.0 - 1.0) <= 1e-12);
+        assert_eq!(
+            sum_compensated(v[..0].iter().cloned()),
+            ValUnc::new(0.0, Unc(0.0))
+        );
+    }
+
+    #[test]
+    fn chi2_test() {
+        let data = [
+            ValUnc::new(1.5, RelUnc(0.5)),
+            ValUnc::new(3.5, Unc(0.5)).convert_unc::<RelUnc<f64>>(),
+        ];
+        let (chi2, ndf) = chi2(&data, &[1.0, 2.5]);
+
+        assert!(f64::abs(chi2 - (4.0 / 9.0 + 4.0)) <= 1e-12);
+        assert_eq!(ndf, 2);
+        // For 2 degrees of freedom, the p-value is exp(-χ² / 2)
+        assert!(f64::abs(chi2_p_value(chi2, ndf) - f64::exp(-chi2 / 2.0)) <= 1e-12);
+    }
+
+    #[test]
+    fn robust() {
+        let mut v = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].map(|x| ValUnc::new(x, Unc(0.5)));
+        let combined = robust_combine(&v).unwrap();
+
+        // The median absolute deviation is 1.5
+        assert_eq!(combined.val, 3.5);
+        let sigma = 1.4826 * 1.5;
+        assert!(f64::abs(combined.unc.0 - 1.2533 * sigma / f64::sqrt(6.0)) <= 1e-12);
+
+        // An outlier doesn't change anything
+        v[5].val = 1e6;
+        assert_eq!(robust_combine(&v), Some(combined));
+
+        // The reported uncertainties are a lower limit
+        let v = [ValUnc::new(2.0, RelUnc(0.5))];
+        assert_eq!(robust_combine(&v), Some(ValUnc::new(2.0, Unc(1.2533))));
+        assert_eq!(robust_combine::<Unc<f64>>(&[]), None);
+    }
+}
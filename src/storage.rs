@@ -0,0 +1,145 @@
+//! An abstraction over how collections of [`ValUnc`]s are stored
+//!
+//! Other layouts than slices, e.g. a structure of arrays, memory-mapped files, or columnar
+//! formats, can implement [`UncStorage`] to get its reductions, e.g. [`UncStorage::sum`]. Its
+//! [`iter`](UncStorage::iter) can also be passed to the functions in [`stats`](crate::stats) that
+//! take iterators, e.g. [`weighted_mean`](crate::stats::weighted_mean), but those that take
+//! slices, e.g. [`chi2`](crate::stats::chi2), need the elements to be collected first.
+
+use crate::val_unc::ValUnc;
+use core::ops::Add;
+
+/// A collection of [`ValUnc`]s with random access
+///
+/// Elements are returned by value, since a storage isn't required to hold `ValUnc`s directly.
+///
+/// This is implemented for slices, but not `Vec`s or arrays, so that it doesn't shadow their
+/// methods, e.g. `get`. Use `as_slice()` or `&v[..]` for those.
+pub trait UncStorage {
+    type Val;
+    type Unc;
+
+    fn len(&self) -> usize;
+
+    /// Returns the element at index `i`, or `None` if it is out of bounds.
+    fn get(&self, i: usize) -> Option<ValUnc<Self::Val, Self::Unc>>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the elements.
+    fn iter(&self) -> StorageIter<'_, Self> {
+        StorageIter {
+            storage: self,
+            pos: 0,
+        }
+    }
+
+    /// Sums the elements, or returns `None` if the storage is empty.
+    ///
+    /// ```
+    /// use val_unc::{Unc, UncStorage, ValUnc};
+    ///
+    /// let v = vec![ValUnc::new(1.0, Unc(3.0)), ValUnc::new(2.0, Unc(4.0))];
+    /// let sum = v[..].sum().unwrap();
+    ///
+    /// assert!(f64::abs(sum.val - 3.0) <= f64::EPSILON);
+    /// assert!(f64::abs(sum.unc.0 - 5.0) <= f64::EPSILON);
+    /// ```
+    fn sum(&self) -> Option<ValUnc<Self::Val, Self::Unc>>
+    where
+        ValUnc<Self::Val, Self::Unc>: Add<Output = ValUnc<Self::Val, Self::Unc>>,
+    {
+        self.iter().reduce(|a, b| a + b)
+    }
+}
+
+/// An iterator over the elements of an [`UncStorage`]
+#[derive(Debug, Clone)]
+pub struct StorageIter<'a, S: ?Sized> {
+    storage: &'a S,
+    pos: usize,
+}
+
+impl<'a, S> Iterator for StorageIter<'a, S>
+where
+    S: UncStorage + ?Sized,
+{
+    type Item = ValUnc<S::Val, S::Unc>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.storage.get(self.pos)?;
+        self.pos += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.storage.len().saturating_sub(self.pos);
+        (n, Some(n))
+    }
+}
+
+impl<'a, S> ExactSizeIterator for StorageIter<'a, S> where S: UncStorage + ?Sized {}
+
+impl<V, U> UncStorage for [ValUnc<V, U>]
+where
+    V: Clone,
+    U: Clone,
+{
+    type Val = V;
+    type Unc = U;
+
+    fn len(&self) -> usize {
+        <[_]>::len(self)
+    }
+
+    fn get(&self, i: usize) -> Option<ValUnc<V, U>> {
+        <[_]>::get(self, i).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unc::Unc;
+
+    // A structure of arrays, to check that the reductions don't depend on the layout
+    struct Soa {
+        val: Vec<f64>,
+        unc: Vec<f64>,
+    }
+
+    impl UncStorage for Soa {
+        type Val = f64;
+        type Unc = Unc<f64>;
+
+        fn len(&self) -> usize {
+            self.val.len()
+        }
+
+        fn get(&self, i: usize) -> Option<ValUnc<f64, Unc<f64>>> {
+            Some(ValUnc::new(*self.val.get(i)?, Unc(*self.unc.get(i)?)))
+        }
+    }
+
+    #[test]
+    fn soa() {
+        let soa = Soa {
+            val: vec![1.0, 2.0],
+            unc: vec![3.0, 4.0],
+        };
+        let aos = [ValUnc::new(1.0, Unc(3.0)), ValUnc::new(2.0, Unc(4.0))];
+
+        assert_eq!(soa.iter().len(), 2);
+        assert!(soa.iter().eq(UncStorage::iter(&aos[..])));
+        assert_eq!(soa.sum(), aos[..].sum());
+        assert_eq!(
+            crate::stats::weighted_mean(soa.iter()),
+            crate::stats::weighted_mean(aos.iter().cloned())
+        );
+        assert_eq!(aos[..0].sum(), None);
+        // The slice's own methods aren't shadowed
+        assert_eq!(aos[..].get(1), Some(&aos[1]));
+    }
+}
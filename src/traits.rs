@@ -1,5 +1,29 @@
+#[cfg(any(feature = "arrow", feature = "csv"))]
+pub use components::UncComponents;
+pub use convert::*;
+pub use coverage::*;
+pub use display::UncDisplay;
+pub use get::GetUnc;
+pub use interval::UncInterval;
 pub use num::*;
 pub use ops::*;
+pub use real::*;
+pub use round::UncRound;
+pub use total::*;
+pub use validate::*;
+pub use weight::*;
 
+#[cfg(any(feature = "arrow", feature = "csv"))]
+pub mod components;
+pub mod convert;
+pub mod coverage;
+pub mod display;
+pub mod get;
+pub mod interval;
 pub mod num;
 pub mod ops;
+pub mod real;
+pub mod round;
+pub mod total;
+pub mod validate;
+pub mod weight;
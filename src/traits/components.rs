@@ -0,0 +1,104 @@
+use crate::unc::Unc;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+/// An uncertainty that can be flattened into a fixed number of `f64` components
+///
+/// Tuples and arrays are flattened component by component, in order.
+pub trait UncComponents: Sized {
+    /// The number of components
+    const LEN: usize;
+
+    /// Appends the components to `out`.
+    fn push_components(&self, out: &mut Vec<f64>);
+
+    /// Takes the components from `components`, or returns `None` if there are too few.
+    fn from_components<I: Iterator<Item = f64>>(components: &mut I) -> Option<Self>;
+}
+
+impl UncComponents for f64 {
+    const LEN: usize = 1;
+
+    fn push_components(&self, out: &mut Vec<f64>) {
+        out.push(*self);
+    }
+
+    fn from_components<I: Iterator<Item = f64>>(components: &mut I) -> Option<Self> {
+        components.next()
+    }
+}
+
+impl UncComponents for Unc<f64> {
+    const LEN: usize = 1;
+
+    fn push_components(&self, out: &mut Vec<f64>) {
+        out.push(self.0);
+    }
+
+    fn from_components<I: Iterator<Item = f64>>(components: &mut I) -> Option<Self> {
+        components.next().map(Unc)
+    }
+}
+
+impl<U, const N: usize> UncComponents for [U; N]
+where
+    U: UncComponents,
+{
+    const LEN: usize = N * U::LEN;
+
+    fn push_components(&self, out: &mut Vec<f64>) {
+        for u in self {
+            u.push_components(out);
+        }
+    }
+
+    fn from_components<I: Iterator<Item = f64>>(components: &mut I) -> Option<Self> {
+        let v = (0..N)
+            .map(|_| U::from_components(components))
+            .collect::<Option<Vec<_>>>()?;
+        v.try_into().ok()
+    }
+}
+
+macro_rules! unc_components_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<$($T),*> UncComponents for ($($T,)*)
+        where
+            $($T: UncComponents),*
+        {
+            const LEN: usize = 0 $(+ $T::LEN)*;
+
+            #[allow(unused_variables)]
+            fn push_components(&self, out: &mut Vec<f64>) {
+                $(self.$idx.push_components(out);)*
+            }
+
+            #[allow(unused_variables)]
+            fn from_components<I: Iterator<Item = f64>>(components: &mut I) -> Option<Self> {
+                Some(($($T::from_components(components)?,)*))
+            }
+        }
+    )+}
+}
+
+unc_components_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
@@ -0,0 +1,70 @@
+use crate::{traits::real::Real, unc::Unc};
+
+/// Conversion between uncertainty types
+///
+/// Since some conversions, e.g. between absolute and relative uncertainties, depend on the value,
+/// the value is also provided.
+pub trait UncFrom<T, V> {
+    fn unc_from(unc: T, val: V) -> Self;
+}
+
+/// The reciprocal of [`UncFrom`], which should be implemented instead
+pub trait UncInto<T, V> {
+    fn unc_into(self, val: V) -> T;
+}
+
+impl<T, U, V> UncInto<U, V> for T
+where
+    U: UncFrom<T, V>,
+{
+    fn unc_into(self, val: V) -> U {
+        U::unc_from(self, val)
+    }
+}
+
+impl<T, V> UncFrom<T, V> for T {
+    fn unc_from(unc: T, _val: V) -> T {
+        unc
+    }
+}
+
+// This implements conversion from tuples of uncertainties to a single uncertainty, by converting
+// each one and adding them in quadrature (up to 12-tuples).
+macro_rules! unc_convert_tuples {
+    ($({
+        ($idx0:tt, $T0:ident) $(, ($idx:tt, $T:ident))*
+    })+) => {$(
+        impl<V, T, $T0, $($T),*> UncFrom<($T0, $($T,)*), V> for Unc<T>
+        where
+            V: Copy,
+            T: Real,
+            $T0: UncInto<Unc<T>, V>,
+            $($T: UncInto<Unc<T>, V>),*
+        {
+            fn unc_from(unc: ($T0, $($T,)*), val: V) -> Self {
+                let sum_sq = unc.$idx0.unc_into(val).0.powi(2)
+                    $(+ unc.$idx.unc_into(val).0.powi(2))*;
+                Unc(sum_sq.sqrt())
+            }
+        }
+    )+}
+}
+
+unc_convert_tuples!(
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
@@ -0,0 +1,124 @@
+use crate::dist::{normal_quantile, student_t_quantile};
+
+/// The coverage factor of an uncertainty for a confidence level
+///
+/// By default, the distribution is assumed to be Gaussian, or Student's t if
+/// [`variance_dof`] gives finite degrees of freedom. Types with other distributions, e.g.
+/// uniform, override [`coverage_factor`].
+///
+/// Tuples, arrays, and [`UncMap`](crate::UncMap) combine the variances and degrees of freedom of
+/// their components with the Welch–Satterthwaite formula, leaving out the components that don't
+/// have a variance.
+///
+/// [`coverage_factor`]: UncCoverage::coverage_factor
+/// [`variance_dof`]: UncCoverage::variance_dof
+pub trait UncCoverage {
+    /// Returns the factor `k` such that `k` times the uncertainty of a value `val` on either side
+    /// of it (see [`UncInterval`](crate::UncInterval)) covers the fraction `confidence`, e.g.
+    /// 0.95, of the distribution.
+    fn coverage_factor(&self, val: f64, confidence: f64) -> f64 {
+        let p = 0.5 * (1.0 + confidence);
+        match self.variance_dof(val) {
+            Some((_, nu)) if nu.is_finite() => student_t_quantile(p, nu),
+            _ => normal_quantile(p),
+        }
+    }
+
+    /// Returns the variance of a value `val` with this uncertainty and its (effective) degrees of
+    /// freedom, or `None` if it doesn't have a variance.
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        None
+    }
+}
+
+/// Combines variances and degrees of freedom with the Welch–Satterthwaite formula, or returns
+/// `None` if there are none.
+pub(crate) fn welch_satterthwaite<I>(parts: I) -> Option<(f64, f64)>
+where
+    I: IntoIterator<Item = Option<(f64, f64)>>,
+{
+    let mut combined = None;
+    let mut denom = 0.0;
+    for (var, nu) in parts.into_iter().flatten() {
+        *combined.get_or_insert(0.0) += var;
+        denom += var * var / nu;
+    }
+    combined.map(|var| {
+        let nu = if denom == 0.0 {
+            f64::INFINITY
+        } else {
+            var * var / denom
+        };
+        (var, nu)
+    })
+}
+
+impl<U, const N: usize> UncCoverage for [U; N]
+where
+    U: UncCoverage,
+{
+    fn variance_dof(&self, val: f64) -> Option<(f64, f64)> {
+        welch_satterthwaite(self.iter().map(|u| u.variance_dof(val)))
+    }
+}
+
+macro_rules! unc_coverage_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<$($T),*> UncCoverage for ($($T,)*)
+        where
+            $($T: UncCoverage),*
+        {
+            #[allow(unused_variables)]
+            fn variance_dof(&self, val: f64) -> Option<(f64, f64)> {
+                welch_satterthwaite([$(self.$idx.variance_dof(val)),*])
+            }
+        }
+    )+}
+}
+
+unc_coverage_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DofUnc, Unc, ValUnc};
+
+    #[test]
+    fn welch_satterthwaite_dof() {
+        let u = (DofUnc::new(1.0, 2.0), DofUnc::new(1.0, 2.0));
+        assert_eq!(u.variance_dof(1.0), Some((2.0, 4.0)));
+        assert!(f64::abs(u.coverage_factor(1.0, 0.95) - 2.776_445) <= 1e-5);
+
+        let (lower, upper) = ValUnc::new(1.0, u).at_confidence(0.95);
+        assert!(f64::abs(lower - (1.0 - 2.776_445 * f64::sqrt(2.0))) <= 1e-5);
+        assert!(f64::abs(upper - (1.0 + 2.776_445 * f64::sqrt(2.0))) <= 1e-5);
+
+        // Components with infinite degrees of freedom only add to the variance
+        let u = [DofUnc::new(1.0, 2.0), DofUnc::new(1.0, f64::INFINITY)];
+        assert_eq!(u.variance_dof(1.0), Some((2.0, 8.0)));
+        let u = (Unc(1.0), Unc(1.0));
+        assert_eq!(u.variance_dof(1.0), Some((2.0, f64::INFINITY)));
+        assert!(f64::abs(u.coverage_factor(1.0, 0.95) - 1.959_964) <= 1e-6);
+        assert_eq!(().variance_dof(1.0), None);
+    }
+}
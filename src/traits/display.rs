@@ -0,0 +1,85 @@
+use core::fmt;
+
+/// Displays an uncertainty after the value `val` of a `ValUnc`
+///
+/// Each component is written as ` ± u`, using the precision of the formatter, so that a
+/// `ValUnc` with no uncertainty components is displayed as just its value.
+pub trait UncDisplay<V> {
+    fn fmt_unc(&self, val: &V, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+/// Writes `u` followed by ` (label)`, e.g. ` ± 0.1 (stat)`.
+pub fn fmt_labeled<U, V>(u: &U, val: &V, label: &str, f: &mut fmt::Formatter) -> fmt::Result
+where
+    U: UncDisplay<V> + ?Sized,
+{
+    u.fmt_unc(val, f)?;
+    write!(f, " ({})", label)
+}
+
+/// Writes ` ± u`.
+pub(crate) fn plus_minus<T>(u: &T, f: &mut fmt::Formatter) -> fmt::Result
+where
+    T: fmt::Display + ?Sized,
+{
+    f.write_str(" ± ")?;
+    fmt::Display::fmt(u, f)
+}
+
+macro_rules! unc_display_impl {
+    ($($T:ty),+) => {$(
+        impl<V> UncDisplay<V> for $T {
+            fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+                plus_minus(self, f)
+            }
+        }
+    )+}
+}
+
+unc_display_impl!(f32, f64);
+
+impl<V, U, const N: usize> UncDisplay<V> for [U; N]
+where
+    U: UncDisplay<V>,
+{
+    fn fmt_unc(&self, val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        self.iter().try_for_each(|u| u.fmt_unc(val, f))
+    }
+}
+
+macro_rules! unc_display_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<V, $($T),*> UncDisplay<V> for ($($T,)*)
+        where
+            $($T: UncDisplay<V>),*
+        {
+            #[allow(unused_variables)]
+            fn fmt_unc(&self, val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+                $(self.$idx.fmt_unc(val, f)?;)*
+                Ok(())
+            }
+        }
+    )+}
+}
+
+unc_display_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
@@ -0,0 +1,110 @@
+/// Access to a component of an uncertainty by its type rather than its position
+///
+/// `I` is a type-level index that disambiguates between the implementations (see [`index`]). It
+/// should be left to be inferred, e.g. `GetUnc::<StatUnc, _>::get_unc(&unc)`, which only succeeds
+/// if the type appears exactly once, so reordering the components of a tuple doesn't break
+/// anything. See also [`ValUnc::unc_component`].
+///
+/// [`ValUnc::unc_component`]: crate::ValUnc::unc_component
+///
+/// ```
+/// use val_unc::{GetUnc, RelUnc, Unc};
+///
+/// let mut unc = (Unc(0.5), RelUnc(0.1));
+/// assert_eq!(GetUnc::<RelUnc<f64>, _>::get_unc(&unc), &RelUnc(0.1));
+///
+/// GetUnc::<Unc<f64>, _>::get_unc_mut(&mut unc).0 = 0.25;
+/// assert_eq!(unc.0, Unc(0.25));
+/// ```
+pub trait GetUnc<T, I> {
+    fn get_unc(&self) -> &T;
+    fn get_unc_mut(&mut self) -> &mut T;
+}
+
+/// Type-level indices for [`GetUnc`]
+pub mod index {
+    /// The index of an uncertainty that isn't a tuple
+    pub enum Itself {}
+    /// The index of component 0 of a tuple
+    pub enum I0 {}
+    /// The index of component 1 of a tuple
+    pub enum I1 {}
+    /// The index of component 2 of a tuple
+    pub enum I2 {}
+    /// The index of component 3 of a tuple
+    pub enum I3 {}
+    /// The index of component 4 of a tuple
+    pub enum I4 {}
+    /// The index of component 5 of a tuple
+    pub enum I5 {}
+    /// The index of component 6 of a tuple
+    pub enum I6 {}
+    /// The index of component 7 of a tuple
+    pub enum I7 {}
+    /// The index of component 8 of a tuple
+    pub enum I8 {}
+    /// The index of component 9 of a tuple
+    pub enum I9 {}
+    /// The index of component 10 of a tuple
+    pub enum I10 {}
+    /// The index of component 11 of a tuple
+    pub enum I11 {}
+}
+
+use index::*;
+
+impl<T> GetUnc<T, Itself> for T {
+    fn get_unc(&self) -> &T {
+        self
+    }
+
+    fn get_unc_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+macro_rules! get_unc_tuple {
+    (($($T:ident),*), $idx:tt, $U:ident, $I:ident) => {
+        impl<$($T),*> GetUnc<$U, $I> for ($($T,)*) {
+            fn get_unc(&self) -> &$U {
+                &self.$idx
+            }
+
+            fn get_unc_mut(&mut self) -> &mut $U {
+                &mut self.$idx
+            }
+        }
+    };
+}
+
+// This implements `GetUnc` for each component of tuples (up to 12-tuples).
+macro_rules! get_unc_tuples {
+    ($({
+        $Ts:tt $(($idx:tt, $U:ident, $I:ident))*
+    })+) => {$($(
+        get_unc_tuple!($Ts, $idx, $U, $I);
+    )*)+}
+}
+
+get_unc_tuples!(
+    {(U0) (0, U0, I0)}
+    {(U0, U1) (0, U0, I0) (1, U1, I1)}
+    {(U0, U1, U2) (0, U0, I0) (1, U1, I1) (2, U2, I2)}
+    {(U0, U1, U2, U3) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3)}
+    {(U0, U1, U2, U3, U4) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3) (4, U4, I4)}
+    {(U0, U1, U2, U3, U4, U5) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3) (4, U4, I4)
+        (5, U5, I5)}
+    {(U0, U1, U2, U3, U4, U5, U6) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3) (4, U4, I4)
+        (5, U5, I5) (6, U6, I6)}
+    {(U0, U1, U2, U3, U4, U5, U6, U7) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3) (4, U4, I4)
+        (5, U5, I5) (6, U6, I6) (7, U7, I7)}
+    {(U0, U1, U2, U3, U4, U5, U6, U7, U8) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3)
+        (4, U4, I4) (5, U5, I5) (6, U6, I6) (7, U7, I7) (8, U8, I8)}
+    {(U0, U1, U2, U3, U4, U5, U6, U7, U8, U9) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3)
+        (4, U4, I4) (5, U5, I5) (6, U6, I6) (7, U7, I7) (8, U8, I8) (9, U9, I9)}
+    {(U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10) (0, U0, I0) (1, U1, I1) (2, U2, I2) (3, U3, I3)
+        (4, U4, I4) (5, U5, I5) (6, U6, I6) (7, U7, I7) (8, U8, I8) (9, U9, I9) (10, U10, I10)}
+    {(U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11) (0, U0, I0) (1, U1, I1) (2, U2, I2)
+        (3, U3, I3) (4, U4, I4) (5, U5, I5) (6, U6, I6) (7, U7, I7) (8, U8, I8) (9, U9, I9)
+        (10, U10, I10) (11, U11, I11)}
+);
@@ -0,0 +1,61 @@
+use crate::traits::{real::Real, total::UncTotal};
+
+/// The interval around a value that is covered by an uncertainty
+///
+/// For most uncertainty types, this is `k` times the total uncertainty (see [`UncTotal`]) on
+/// either side of the value. Other types, e.g. worst-case bounds, define their own.
+pub trait UncInterval<V> {
+    /// Returns the lower and upper bounds of the interval around `val` with coverage factor `k`.
+    fn interval(&self, val: V, k: V) -> (V, V);
+}
+
+/// Returns the interval `[val - half_width, val + half_width]`.
+pub(crate) fn symmetric<V: Real>(val: V, half_width: V) -> (V, V) {
+    (val.clone() - half_width.clone(), val + half_width)
+}
+
+impl<V, U, const N: usize> UncInterval<V> for [U; N]
+where
+    V: Real,
+    U: UncTotal<V>,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        symmetric(val.clone(), k * self.total(val))
+    }
+}
+
+macro_rules! unc_interval_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<V, $($T),*> UncInterval<V> for ($($T,)*)
+        where
+            V: Real,
+            $($T: UncTotal<V>),*
+        {
+            fn interval(&self, val: V, k: V) -> (V, V) {
+                symmetric(val.clone(), k * self.total(val))
+            }
+        }
+    )+}
+}
+
+unc_interval_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
@@ -1,4 +1,4 @@
-pub use num_traits::{Float, Pow, Zero};
+pub use num_traits::{Float, One, Pow, Signed, Zero};
 
 pub trait Sqrt {
     fn sqrt(self) -> Self;
@@ -9,7 +9,7 @@ where
     T: Float,
 {
     fn sqrt(self) -> Self {
-        self.sqrt()
+        Float::sqrt(self)
     }
 }
 
@@ -52,6 +52,23 @@ impl UncZero for () {
     fn set_zero(&mut self) {}
 }
 
+impl<U, const N: usize> UncZero for [U; N]
+where
+    U: UncZero,
+{
+    fn zero() -> Self {
+        core::array::from_fn(|_| U::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.iter().all(UncZero::is_zero)
+    }
+
+    fn set_zero(&mut self) {
+        self.iter_mut().for_each(UncZero::set_zero)
+    }
+}
+
 macro_rules! unc_num_tuples {
     ($({
         $(($idx:tt, $T:ident)),*
@@ -24,71 +24,72 @@ macro_rules! unc_ops_tuples {
     ($({
         $(($idx:tt, $T:ident)),*
     })+) => {$(
-        // In the following impls, the compiler complains about `other` not being used.
+        // In the following impls, the compiler complains about `other` not being used, and clippy
+        // complains about the unit expression.
         // This is only for the `()` impl
-        // That's why there's the allow.
+        // That's why there are the allows.
 
-        #[allow(unused_variables)]
+        #[allow(unused_variables, clippy::unused_unit)]
         impl<V, $($T),*> UncAdd<V> for ($($T,)*)
         where
-            V: Copy,
+            V: Clone,
             $($T: UncAdd<V>),*
         {
             fn unc_add(self, self_val: V, other: Self, other_val: V) -> Self {
                 ($(
-                    self.$idx.unc_add(self_val, other.$idx, other_val),
+                    self.$idx.unc_add(self_val.clone(), other.$idx, other_val.clone()),
                 )*)
             }
         }
 
-        #[allow(unused_variables)]
+        #[allow(unused_variables, clippy::unused_unit)]
         impl<V, $($T),*> UncDiv<V> for ($($T,)*)
         where
-            V: Copy,
+            V: Clone,
             $($T: UncDiv<V>),*
         {
             fn unc_div(self, self_val: V, other: Self, other_val: V) -> Self {
                 ($(
-                    self.$idx.unc_div(self_val, other.$idx, other_val),
+                    self.$idx.unc_div(self_val.clone(), other.$idx, other_val.clone()),
                 )*)
             }
         }
 
-        #[allow(unused_variables)]
+        #[allow(unused_variables, clippy::unused_unit)]
         impl<V, $($T),*> UncMul<V> for ($($T,)*)
         where
-            V: Copy,
+            V: Clone,
             $($T: UncMul<V>),*
         {
             fn unc_mul(self, self_val: V, other: Self, other_val: V) -> Self {
                 ($(
-                    self.$idx.unc_mul(self_val, other.$idx, other_val),
+                    self.$idx.unc_mul(self_val.clone(), other.$idx, other_val.clone()),
                 )*)
             }
         }
 
-        #[allow(unused_variables)]
+        #[allow(unused_variables, clippy::unused_unit)]
         impl<V, $($T),*> UncNeg<V> for ($($T,)*)
         where
-            V: Copy,
+            V: Clone,
             $($T: UncNeg<V>),*
         {
             fn unc_neg(self, self_val: V) -> Self {
                 ($(
-                    self.$idx.unc_neg(self_val),
+                    self.$idx.unc_neg(self_val.clone()),
                 )*)
             }
         }
 
-        #[allow(unused_variables)]
+        #[allow(unused_variables, clippy::unused_unit)]
         impl<V, $($T),*> UncSub<V> for ($($T,)*)
         where
-            V: Copy,
+            V: Clone,
             $($T: UncSub<V>),*
         {
             fn unc_sub(self, self_val: V, other: Self, other_val: V) -> Self {
                 ($(
-                    self.$idx.unc_sub(self_val, other.$idx, other_val),
+                    self.$idx.unc_sub(self_val.clone(), other.$idx, other_val.clone()),
                 )*)
             }
         }
@@ -114,3 +115,40 @@ unc_ops_tuples!(
     {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
         (8, U8), (9, U9), (10, U10), (11, U11)}
 );
+
+// This implements the crate::ops traits for arrays of types that implement those traits.
+macro_rules! unc_ops_array {
+    ($(($Tr:ident, $f:ident)),+) => {$(
+        impl<V, U, const N: usize> $Tr<V> for [U; N]
+        where
+            V: Clone,
+            U: $Tr<V>,
+        {
+            fn $f(self, self_val: V, other: Self, other_val: V) -> Self {
+                let mut it = IntoIterator::into_iter(self).zip(IntoIterator::into_iter(other));
+                core::array::from_fn(|_| {
+                    let (s, o) = it.next().unwrap();
+                    s.$f(self_val.clone(), o, other_val.clone())
+                })
+            }
+        }
+    )+}
+}
+
+unc_ops_array!(
+    (UncAdd, unc_add),
+    (UncDiv, unc_div),
+    (UncMul, unc_mul),
+    (UncSub, unc_sub)
+);
+
+impl<V, U, const N: usize> UncNeg<V> for [U; N]
+where
+    V: Clone,
+    U: UncNeg<V>,
+{
+    fn unc_neg(self, self_val: V) -> Self {
+        let mut it = IntoIterator::into_iter(self);
+        core::array::from_fn(|_| it.next().unwrap().unc_neg(self_val.clone()))
+    }
+}
@@ -0,0 +1,74 @@
+use core::ops::Neg;
+use num_traits::{Float, FromPrimitive, Num, ToPrimitive};
+
+/// The floating point operations used by the bundled uncertainty types
+///
+/// This is implemented for every [`Float`], e.g. `f32` and `f64`, and for `BigFloat`, a wrapper
+/// around an arbitrary precision float, with the `dashu` feature. Implementing it for another
+/// number type allows it to be used with the bundled uncertainty types.
+pub trait Real:
+    Clone + PartialOrd + Num + FromPrimitive + ToPrimitive + Neg<Output = Self>
+{
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn abs(self) -> Self;
+    fn round(self) -> Self;
+    fn infinity() -> Self;
+    fn is_nan(&self) -> bool;
+    fn is_finite(&self) -> bool;
+}
+
+impl<T> Real for T
+where
+    T: Float + FromPrimitive,
+{
+    fn sqrt(self) -> Self {
+        Float::sqrt(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        Float::powi(self, n)
+    }
+
+    fn abs(self) -> Self {
+        Float::abs(self)
+    }
+
+    fn round(self) -> Self {
+        Float::round(self)
+    }
+
+    fn infinity() -> Self {
+        Float::infinity()
+    }
+
+    fn is_nan(&self) -> bool {
+        Float::is_nan(*self)
+    }
+
+    fn is_finite(&self) -> bool {
+        Float::is_finite(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::Signed, BoundUnc, RelUnc, Unc, ValUnc};
+
+    type Mixed<T> = ValUnc<T, (Unc<T>, BoundUnc<T>, RelUnc<T>)>;
+
+    // Any `Float` can be used with the bundled uncertainty types, not just `f32` and `f64`
+    fn propagate<T>(a: Mixed<T>) -> T
+    where
+        T: Float + FromPrimitive + Signed,
+    {
+        (a * a - a).total_unc()
+    }
+
+    #[test]
+    fn float() {
+        let a = ValUnc::new(2.0f32, (Unc(0.0), BoundUnc(0.0), RelUnc(0.0)));
+        assert_eq!(propagate(a), 0.0);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::traits::real::Real;
+
+/// Rounds an uncertainty numerically, e.g. to the precision that it is presented with
+///
+/// See [`ValUnc::rounded`](crate::ValUnc::rounded).
+pub trait UncRound {
+    /// Rounds each component to `places` decimal places (negative to the left of the decimal
+    /// point).
+    fn round_at(&self, places: i32) -> Self;
+}
+
+/// Rounds `x` to `places` decimal places (negative to the left of the decimal point).
+pub fn round_float<T>(x: T, places: i32) -> T
+where
+    T: Real,
+{
+    let scale = T::from_i32(10).unwrap().powi(places.abs());
+    if places >= 0 {
+        (x * scale.clone()).round() / scale
+    } else {
+        (x / scale.clone()).round() * scale
+    }
+}
+
+macro_rules! unc_round_impl {
+    ($($T:ty),+) => {$(
+        impl UncRound for $T {
+            fn round_at(&self, places: i32) -> Self {
+                round_float(*self, places)
+            }
+        }
+    )+}
+}
+
+unc_round_impl!(f32, f64);
+
+impl<U, const N: usize> UncRound for [U; N]
+where
+    U: UncRound,
+{
+    fn round_at(&self, places: i32) -> Self {
+        core::array::from_fn(|i| self[i].round_at(places))
+    }
+}
+
+macro_rules! unc_round_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<$($T),*> UncRound for ($($T,)*)
+        where
+            $($T: UncRound),*
+        {
+            #[allow(clippy::unused_unit, unused_variables)]
+            fn round_at(&self, places: i32) -> Self {
+                ($(self.$idx.round_at(places),)*)
+            }
+        }
+    )+}
+}
+
+unc_round_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
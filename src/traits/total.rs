@@ -0,0 +1,59 @@
+use crate::traits::real::Real;
+
+/// Combines an uncertainty into a single, total uncertainty in the units of the value
+///
+/// Tuples and arrays are combined in quadrature, which assumes that their components are
+/// independent.
+pub trait UncTotal<V> {
+    fn total(&self, val: V) -> V;
+}
+
+impl<V, U, const N: usize> UncTotal<V> for [U; N]
+where
+    V: Real,
+    U: UncTotal<V>,
+{
+    fn total(&self, val: V) -> V {
+        self.iter()
+            .fold(V::zero(), |sum, u| sum + u.total(val.clone()).powi(2))
+            .sqrt()
+    }
+}
+
+macro_rules! unc_total_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<V, $($T),*> UncTotal<V> for ($($T,)*)
+        where
+            V: Real,
+            $($T: UncTotal<V>),*
+        {
+            #[allow(unused_variables)]
+            fn total(&self, val: V) -> V {
+                let sum_sq = V::zero() $(+ self.$idx.total(val.clone()).powi(2))*;
+                sum_sq.sqrt()
+            }
+        }
+    )+}
+}
+
+unc_total_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
@@ -0,0 +1,80 @@
+/// Checks that an uncertainty is valid, i.e. that no component is negative or NaN
+pub trait UncValidate {
+    fn is_valid(&self) -> bool;
+
+    /// Sets the invalid components to zero, and keeps the valid ones.
+    fn clamp_invalid(&mut self);
+}
+
+macro_rules! unc_validate_impl {
+    ($($T:ty),+) => {$(
+        impl UncValidate for $T {
+            #[allow(unused_comparisons)]
+            fn is_valid(&self) -> bool {
+                // This is false for NaN
+                *self >= (0 as $T)
+            }
+
+            fn clamp_invalid(&mut self) {
+                if !self.is_valid() {
+                    *self = 0 as $T;
+                }
+            }
+        }
+    )+}
+}
+
+unc_validate_impl!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f32, f64);
+
+impl<U, const N: usize> UncValidate for [U; N]
+where
+    U: UncValidate,
+{
+    fn is_valid(&self) -> bool {
+        self.iter().all(UncValidate::is_valid)
+    }
+
+    fn clamp_invalid(&mut self) {
+        self.iter_mut().for_each(UncValidate::clamp_invalid)
+    }
+}
+
+macro_rules! unc_validate_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<$($T),*> UncValidate for ($($T,)*)
+        where
+            $($T: UncValidate),*
+        {
+            #[allow(clippy::unused_unit)]
+            fn is_valid(&self) -> bool {
+                true $(&& self.$idx.is_valid())*
+            }
+
+            fn clamp_invalid(&mut self) {
+                $(self.$idx.clamp_invalid();)*
+            }
+        }
+    )+}
+}
+
+unc_validate_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
@@ -0,0 +1,57 @@
+use crate::traits::{Real, UncTotal};
+
+/// The weight of a measurement when averaging
+///
+/// By default, the weight is the inverse of the square of the total uncertainty. Types that know
+/// their variance directly, or that should be weighted differently, override [`weight`].
+///
+/// [`weight`]: UncWeight::weight
+pub trait UncWeight<V>: UncTotal<V>
+where
+    V: Real,
+{
+    /// Returns the weight of a measurement with value `val` and this uncertainty.
+    fn weight(&self, val: V) -> V {
+        V::one() / self.total(val).powi(2)
+    }
+}
+
+impl<V, U, const N: usize> UncWeight<V> for [U; N]
+where
+    V: Real,
+    U: UncTotal<V>,
+{
+}
+
+macro_rules! unc_weight_tuples {
+    ($({
+        $(($idx:tt, $T:ident)),*
+    })+) => {$(
+        impl<V, $($T),*> UncWeight<V> for ($($T,)*)
+        where
+            V: Real,
+            $($T: UncTotal<V>),*
+        {
+        }
+    )+}
+}
+
+unc_weight_tuples!(
+    {}
+    {(0, U0)}
+    {(0, U0), (1, U1)}
+    {(0, U0), (1, U1), (2, U2)}
+    {(0, U0), (1, U1), (2, U2), (3, U3)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10)}
+    {(0, U0), (1, U1), (2, U2), (3, U3), (4, U4), (5, U5), (6, U6), (7, U7),
+        (8, U8), (9, U9), (10, U10), (11, U11)}
+);
@@ -1,11 +1,46 @@
-use crate::traits::*;
+pub use bound::*;
+#[cfg(feature = "complex")]
+pub use complex::*;
+pub use dof::*;
+pub use expanded::*;
+#[cfg(feature = "alloc")]
+pub use map::*;
+pub use moments::*;
+pub use nonneg::*;
+pub use rel::*;
+pub use resolution::*;
+pub use scale::*;
+pub use tagged::*;
+pub use zero::*;
+
+pub mod bound;
+#[cfg(feature = "complex")]
+pub mod complex;
+pub mod dof;
+pub mod expanded;
+#[cfg(feature = "alloc")]
+pub mod map;
+pub mod moments;
+pub mod nonneg;
+pub mod rel;
+pub mod resolution;
+pub mod scale;
+pub mod tagged;
+pub mod zero;
+
+use crate::traits::{display::plus_minus, interval::symmetric, round::round_float, *};
+use core::{
+    fmt,
+    ops::{Div, Mul},
+};
+use num_traits::ToPrimitive;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::ops::{Add, Div, Mul};
 
 /// An example implementation of an uncertatinty type
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Unc<T>(pub T);
 
@@ -37,50 +72,119 @@ where
 
 impl<V, U> UncAdd<V> for Unc<U>
 where
-    U: Pow<u8, Output = U> + Sqrt + Add<U, Output = U>,
+    U: Real,
 {
     fn unc_add(self, _self_val: V, other: Unc<U>, _other_val: V) -> Unc<U> {
-        Unc((self.0.pow(2) + other.0.pow(2)).sqrt())
+        Unc((self.0.powi(2) + other.0.powi(2)).sqrt())
+    }
+}
+
+impl<T> UncCoverage for Unc<T>
+where
+    T: ToPrimitive,
+{
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        self.0.to_f64().map(|u| (u * u, f64::INFINITY))
+    }
+}
+
+impl<V, T> UncDisplay<V> for Unc<T>
+where
+    T: fmt::Display,
+{
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(&self.0, f)
     }
 }
 
 impl<V, U> UncDiv<V> for Unc<U>
 where
-    U: Pow<u8, Output = U> + Sqrt + Add<U, Output = U> + Div<V, Output = U> + Mul<V, Output = U>,
+    U: Real + Div<V, Output = U> + Mul<V, Output = U>,
     V: Clone,
 {
     fn unc_div(self, self_val: V, other: Unc<U>, other_val: V) -> Unc<U> {
         Unc(
-            ((self.0 / self_val.clone()).pow(2) + (other.0 / other_val.clone()).pow(2)).sqrt()
+            ((self.0 / self_val.clone()).powi(2) + (other.0 / other_val.clone()).powi(2)).sqrt()
                 * self_val
                 / other_val,
         )
     }
 }
 
+impl<V> UncInterval<V> for Unc<V>
+where
+    V: Real,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        symmetric(val.clone(), k * self.total(val))
+    }
+}
+
 impl<V, U> UncMul<V> for Unc<U>
 where
-    U: Pow<u8, Output = U> + Sqrt + Add<U, Output = U> + Div<V, Output = U> + Mul<V, Output = U>,
+    U: Real + Div<V, Output = U> + Mul<V, Output = U>,
     V: Clone,
 {
     fn unc_mul(self, self_val: V, other: Unc<U>, other_val: V) -> Unc<U> {
         Unc(
-            ((self.0 / self_val.clone()).pow(2) + (other.0 / other_val.clone()).pow(2)).sqrt()
+            ((self.0 / self_val.clone()).powi(2) + (other.0 / other_val.clone()).powi(2)).sqrt()
                 * self_val
                 * other_val,
         )
     }
 }
 
+impl<V, U> UncNeg<V> for Unc<U> {
+    fn unc_neg(self, _self_val: V) -> Unc<U> {
+        self
+    }
+}
+
+impl<T> UncRound for Unc<T>
+where
+    T: Real,
+{
+    fn round_at(&self, places: i32) -> Self {
+        Self(round_float(self.0.clone(), places))
+    }
+}
+
 impl<V, U> UncSub<V> for Unc<U>
 where
-    U: Pow<u8, Output = U> + Sqrt + Add<U, Output = U>,
+    U: Real,
 {
     fn unc_sub(self, _self_val: V, other: Unc<U>, _other_val: V) -> Unc<U> {
-        Unc((self.0.pow(2) + other.0.pow(2)).sqrt())
+        Unc((self.0.powi(2) + other.0.powi(2)).sqrt())
+    }
+}
+
+impl<V> UncTotal<V> for Unc<V>
+where
+    V: Clone,
+{
+    fn total(&self, _val: V) -> V {
+        self.0.clone()
+    }
+}
+
+impl<T> UncValidate for Unc<T>
+where
+    T: PartialOrd + Zero,
+{
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.0 >= T::zero()
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.0 = T::zero();
+        }
     }
 }
 
+impl<V> UncWeight<V> for Unc<V> where V: Real {}
+
 impl<T> UncZero for Unc<T>
 where
     T: Zero,
@@ -106,18 +210,34 @@ mod tests {
         let unc_1 = Unc(3.0f64);
         let unc_2 = Unc(4.0f64);
 
-        assert!(
-            f64::abs(UncAdd::<f64>::unc_add(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= std::f64::EPSILON
-        );
-        assert!(
-            f64::abs(UncDiv::<f64>::unc_div(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= std::f64::EPSILON
-        );
-        assert!(
-            f64::abs(UncMul::<f64>::unc_mul(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= std::f64::EPSILON
-        );
-        assert!(
-            f64::abs(UncSub::<f64>::unc_sub(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= std::f64::EPSILON
-        );
-        assert!(f64::abs(<Unc<f64>>::zero().0 - 0.0) <= std::f64::EPSILON);
+        assert!(f64::abs(UncAdd::<f64>::unc_add(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(UncDiv::<f64>::unc_div(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(UncMul::<f64>::unc_mul(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(UncSub::<f64>::unc_sub(unc_1, 1.0, unc_2, 1.0).0 - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(<Unc<f64>>::zero().0 - 0.0) <= f64::EPSILON);
+    }
+
+    #[test]
+    fn unc_array() {
+        let sys_1 = [Unc(3.0f64); 20];
+        let sys_2 = [Unc(4.0f64); 20];
+
+        let sum = UncAdd::<f64>::unc_add(sys_1, 1.0, sys_2, 1.0);
+        assert!(sum.iter().all(|u| f64::abs(u.0 - 5.0) <= f64::EPSILON));
+        assert!(<[Unc<f64>; 20]>::zero().is_zero());
+    }
+
+    #[test]
+    fn unc_total() {
+        let t = (Unc(3.0), ZeroUnc, RelUnc(0.5));
+        assert!(f64::abs(t.total(8.0) - 5.0) <= f64::EPSILON);
+
+        let a = [Unc(1.0f64); 4];
+        assert!(f64::abs(a.total(0.0) - 2.0) <= f64::EPSILON);
+
+        let m: UncMap<Unc<f64>> = vec![("a".to_string(), Unc(3.0)), ("b".to_string(), Unc(4.0))]
+            .into_iter()
+            .collect();
+        assert!(f64::abs(m.total(0.0) - 5.0) <= f64::EPSILON);
     }
 }
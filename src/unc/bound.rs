@@ -0,0 +1,178 @@
+use crate::traits::{display::plus_minus, interval::symmetric, round::round_float, *};
+use core::{fmt, ops::Add};
+use num_traits::ToPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A worst-case bound on the value, propagated with interval arithmetic
+///
+/// The uncertainty is the half-width of the interval `[val - unc, val + unc]`, which is
+/// guaranteed to contain the true value. Unlike [`Unc`](crate::Unc), bounds are added linearly,
+/// not in quadrature.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct BoundUnc<T>(pub T);
+
+impl<V, U> UncAdd<V> for BoundUnc<U>
+where
+    U: Add<U, Output = U>,
+{
+    fn unc_add(self, _self_val: V, other: BoundUnc<U>, _other_val: V) -> BoundUnc<U> {
+        BoundUnc(self.0 + other.0)
+    }
+}
+
+impl<T> UncCoverage for BoundUnc<T>
+where
+    T: ToPrimitive,
+{
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        self.0.to_f64().map(|u| (u * u, f64::INFINITY))
+    }
+}
+
+impl<V, T> UncDisplay<V> for BoundUnc<T>
+where
+    T: fmt::Display,
+{
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(&self.0, f)
+    }
+}
+
+impl<T> UncDiv<T> for BoundUnc<T>
+where
+    T: Real,
+{
+    /// If the interval of the divisor contains zero, the bound is infinite.
+    fn unc_div(self, self_val: T, other: BoundUnc<T>, other_val: T) -> BoundUnc<T> {
+        let (x, y) = (self_val.abs(), other_val.abs());
+        if y <= other.0 {
+            BoundUnc(T::infinity())
+        } else {
+            BoundUnc((self.0 * y.clone() + x * other.0.clone()) / (y.clone() * (y - other.0)))
+        }
+    }
+}
+
+/// The bounds are guaranteed, so the coverage factor is ignored.
+impl<V> UncInterval<V> for BoundUnc<V>
+where
+    V: Real,
+{
+    fn interval(&self, val: V, _k: V) -> (V, V) {
+        symmetric(val, self.0.clone())
+    }
+}
+
+impl<T> UncMul<T> for BoundUnc<T>
+where
+    T: Signed + Clone,
+{
+    fn unc_mul(self, self_val: T, other: BoundUnc<T>, other_val: T) -> BoundUnc<T> {
+        BoundUnc(
+            self_val.abs() * other.0.clone() + other_val.abs() * self.0.clone() + self.0 * other.0,
+        )
+    }
+}
+
+impl<V, U> UncNeg<V> for BoundUnc<U> {
+    fn unc_neg(self, _self_val: V) -> BoundUnc<U> {
+        self
+    }
+}
+
+impl<T> UncRound for BoundUnc<T>
+where
+    T: Real,
+{
+    fn round_at(&self, places: i32) -> Self {
+        Self(round_float(self.0.clone(), places))
+    }
+}
+
+impl<V, U> UncSub<V> for BoundUnc<U>
+where
+    U: Add<U, Output = U>,
+{
+    fn unc_sub(self, _self_val: V, other: BoundUnc<U>, _other_val: V) -> BoundUnc<U> {
+        BoundUnc(self.0 + other.0)
+    }
+}
+
+impl<V> UncTotal<V> for BoundUnc<V>
+where
+    V: Clone,
+{
+    fn total(&self, _val: V) -> V {
+        self.0.clone()
+    }
+}
+
+impl<T> UncValidate for BoundUnc<T>
+where
+    T: PartialOrd + Zero,
+{
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.0 >= T::zero()
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.0 = T::zero();
+        }
+    }
+}
+
+impl<V> UncWeight<V> for BoundUnc<V> where V: Real {}
+
+impl<T> UncZero for BoundUnc<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        BoundUnc(Zero::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(&self.0)
+    }
+
+    fn set_zero(&mut self) {
+        Zero::set_zero(&mut self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValUnc;
+
+    #[test]
+    fn bound_unc_f64() {
+        let v1 = ValUnc::new(4.0f64, BoundUnc(1.0f64));
+        let v2 = ValUnc::new(2.0f64, BoundUnc(0.5f64));
+
+        let sum = v1 + v2;
+        assert!(f64::abs(sum.lower(1.0) - 4.5) <= f64::EPSILON);
+        assert!(f64::abs(sum.upper(1.0) - 7.5) <= f64::EPSILON);
+
+        let diff = v1 - v2;
+        assert!(f64::abs(diff.unc.0 - 1.5) <= f64::EPSILON);
+
+        // [3, 5] * [1.5, 2.5] = [4.5, 12.5]
+        let prod = v1 * v2;
+        assert!(f64::abs(prod.val + prod.unc.0 - 12.5) <= f64::EPSILON);
+
+        // [3, 5] / [1.5, 2.5] = [1.2, 3.333...]
+        let quot = v1 / v2;
+        assert!(f64::abs(quot.upper(1.0) - 5.0 / 1.5) <= 1e-12);
+        assert!(quot.lower(1.0) <= 1.2);
+
+        let inf = v1 / ValUnc::new(0.5, BoundUnc(1.0));
+        assert!(inf.unc.0.is_infinite());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    traits::{
+        display::plus_minus, Float, Real, UncAdd, UncDisplay, UncDiv, UncMul, UncNeg, UncSub,
+        UncValidate, UncZero, Zero,
+    },
+    unc::Unc,
+    val_unc::ValUnc,
+};
+use core::fmt;
+use num_complex::Complex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An uncertainty on a complex value, with independent uncertainties on the real and imaginary
+/// parts
+///
+/// The uncertainties are propagated to first order through the complex derivatives of each
+/// operation. The real and imaginary parts of the result are treated as independent, which
+/// neglects the correlation that `Mul` and `Div` introduce between them.
+///
+/// ```
+/// use num_complex::Complex;
+/// use val_unc::{ComplexUnc, ValUnc};
+///
+/// // An impedance and a current
+/// let z = ValUnc::new(Complex::new(50.0, 0.0), ComplexUnc::new(0.5, 0.0));
+/// let i = ValUnc::new(Complex::new(0.0, 0.1), ComplexUnc::new(0.0, 0.002));
+/// let v = z * i;
+///
+/// assert_eq!(v.val, Complex::new(0.0, 5.0));
+/// assert!(f64::abs(v.unc.im - f64::hypot(0.05, 0.1)) < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ComplexUnc<T> {
+    /// The uncertainty on the real part
+    pub re: T,
+    /// The uncertainty on the imaginary part
+    pub im: T,
+}
+
+impl<T> ComplexUnc<T> {
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T> ComplexUnc<T>
+where
+    T: Real,
+{
+    /// The uncertainty of a holomorphic function of two independent complex quantities with
+    /// uncertainties `u1` and `u2`, given its derivatives `d1` and `d2` with respect to them.
+    ///
+    /// The derivative with respect to the imaginary part of an argument is `i` times the complex
+    /// derivative.
+    fn holomorphic(d1: Complex<T>, u1: Self, d2: Complex<T>, u2: Self) -> Self {
+        let sq = |d: &T, u: &T| (d.clone() * u.clone()).powi(2);
+        let re = sq(&d1.re, &u1.re) + sq(&d1.im, &u1.im) + sq(&d2.re, &u2.re) + sq(&d2.im, &u2.im);
+        let im = sq(&d1.im, &u1.re) + sq(&d1.re, &u1.im) + sq(&d2.im, &u2.re) + sq(&d2.re, &u2.im);
+        Self::new(re.sqrt(), im.sqrt())
+    }
+}
+
+impl<T> ValUnc<Complex<T>, ComplexUnc<T>>
+where
+    T: Float,
+{
+    /// The magnitude, `|z|`, with its uncertainty.
+    pub fn norm(&self) -> ValUnc<T, Unc<T>> {
+        let Complex { re, im } = self.val;
+        let r = re.hypot(im);
+        let unc = (re * self.unc.re).hypot(im * self.unc.im) / r;
+        ValUnc::new(r, Unc(unc))
+    }
+
+    /// The phase, `arg(z)`, in radians, with its uncertainty.
+    pub fn arg(&self) -> ValUnc<T, Unc<T>> {
+        let Complex { re, im } = self.val;
+        let r2 = re.powi(2) + im.powi(2);
+        let unc = (im * self.unc.re).hypot(re * self.unc.im) / r2;
+        ValUnc::new(im.atan2(re), Unc(unc))
+    }
+}
+
+impl<V, T> UncAdd<V> for ComplexUnc<T>
+where
+    T: Real,
+{
+    fn unc_add(self, _self_val: V, other: Self, _other_val: V) -> Self {
+        Self::holomorphic(
+            Complex::new(T::one(), T::zero()),
+            self,
+            Complex::new(T::one(), T::zero()),
+            other,
+        )
+    }
+}
+
+impl<V, T> UncDisplay<V> for ComplexUnc<T>
+where
+    T: fmt::Display,
+{
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(&self.re, f)?;
+        f.write_str(" (re)")?;
+        plus_minus(&self.im, f)?;
+        f.write_str(" (im)")
+    }
+}
+
+impl<T> UncDiv<Complex<T>> for ComplexUnc<T>
+where
+    T: Real,
+{
+    fn unc_div(self, self_val: Complex<T>, other: Self, other_val: Complex<T>) -> Self {
+        let d1 = other_val.inv();
+        let d2 = -self_val * d1.clone() * d1.clone();
+        Self::holomorphic(d1, self, d2, other)
+    }
+}
+
+impl<T> UncMul<Complex<T>> for ComplexUnc<T>
+where
+    T: Real,
+{
+    fn unc_mul(self, self_val: Complex<T>, other: Self, other_val: Complex<T>) -> Self {
+        Self::holomorphic(other_val, self, self_val, other)
+    }
+}
+
+impl<V, T> UncNeg<V> for ComplexUnc<T> {
+    fn unc_neg(self, _self_val: V) -> Self {
+        self
+    }
+}
+
+impl<V, T> UncSub<V> for ComplexUnc<T>
+where
+    T: Real,
+{
+    fn unc_sub(self, _self_val: V, other: Self, _other_val: V) -> Self {
+        Self::holomorphic(
+            Complex::new(T::one(), T::zero()),
+            self,
+            Complex::new(-T::one(), T::zero()),
+            other,
+        )
+    }
+}
+
+impl<T> UncValidate for ComplexUnc<T>
+where
+    T: PartialOrd + Zero,
+{
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.re >= T::zero() && self.im >= T::zero()
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.set_zero();
+        }
+    }
+}
+
+impl<T> UncZero for ComplexUnc<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    fn set_zero(&mut self) {
+        self.re.set_zero();
+        self.im.set_zero();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!(f64::abs(a - b) <= 4.0 * f64::EPSILON, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn ops() {
+        let a = ValUnc::new(Complex::new(3.0, 4.0), ComplexUnc::new(0.3, 0.4));
+        let b = ValUnc::new(Complex::new(1.0, -2.0), ComplexUnc::new(0.1, 0.2));
+
+        let sum = a + b;
+        assert_eq!(sum.val, Complex::new(4.0, 2.0));
+        assert_close(sum.unc.re, f64::hypot(0.3, 0.1));
+        assert_close(sum.unc.im, f64::hypot(0.4, 0.2));
+        assert_eq!((a - b).unc, sum.unc);
+        assert_eq!((-a).unc, a.unc);
+
+        // (3 + 4i)(1 - 2i) = 11 - 2i
+        let prod = a * b;
+        assert_eq!(prod.val, Complex::new(11.0, -2.0));
+        assert_close(prod.unc.re, f64::sqrt(0.09 + 0.64 + 0.09 + 0.64));
+        assert_close(prod.unc.im, f64::sqrt(0.36 + 0.16 + 0.16 + 0.36));
+
+        // 1 / (1 - 2i) = 0.2 + 0.4i
+        let quot = prod / ValUnc::new(b.val, ComplexUnc::zero());
+        assert_close(quot.val.re, 3.0);
+        assert_close(quot.val.im, 4.0);
+        assert_close(
+            quot.unc.re,
+            f64::hypot(0.2 * prod.unc.re, 0.4 * prod.unc.im),
+        );
+        assert_close(
+            quot.unc.im,
+            f64::hypot(0.4 * prod.unc.re, 0.2 * prod.unc.im),
+        );
+    }
+
+    #[test]
+    fn polar() {
+        let z = ValUnc::new(Complex::new(3.0, 4.0), ComplexUnc::new(0.5, 0.5));
+
+        let norm = z.norm();
+        assert_close(norm.val, 5.0);
+        assert_close(norm.unc.0, 0.5);
+
+        let arg = z.arg();
+        assert_close(arg.val, f64::atan2(4.0, 3.0));
+        assert_close(arg.unc.0, 0.1);
+
+        assert_eq!(
+            format!(
+                "{}",
+                ValUnc::new(Complex::new(1.0, 2.0), ComplexUnc::new(0.5, 0.25))
+            ),
+            "1+2i ± 0.5 (re) ± 0.25 (im)"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    traits::{
+        display::plus_minus, interval::symmetric, round::round_float, UncAdd, UncCoverage,
+        UncDisplay, UncDiv, UncInterval, UncMul, UncNeg, UncRound, UncSub, UncTotal, UncValidate,
+        UncWeight, UncZero,
+    },
+    val_unc::ValUnc,
+};
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A standard uncertainty `u` with its (effective) degrees of freedom `nu`
+///
+/// The uncertainties are combined in quadrature, like [`Unc`](crate::Unc), and the effective
+/// degrees of freedom of the result are calculated with the Welch–Satterthwaite formula. An
+/// uncertainty that is known exactly, e.g. a Type B evaluation, has infinite degrees of freedom.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DofUnc {
+    pub u: f64,
+    pub nu: f64,
+}
+
+impl DofUnc {
+    pub const fn new(u: f64, nu: f64) -> Self {
+        Self { u, nu }
+    }
+
+    /// Combines the contributions `c1` and `c2` to the uncertainty of the result.
+    fn combine(c1: f64, nu1: f64, c2: f64, nu2: f64) -> Self {
+        let u = f64::sqrt(c1.powi(2) + c2.powi(2));
+        let denom = c1.powi(4) / nu1 + c2.powi(4) / nu2;
+        let nu = if denom == 0.0 {
+            f64::INFINITY
+        } else {
+            u.powi(4) / denom
+        };
+        Self { u, nu }
+    }
+}
+
+impl Default for DofUnc {
+    fn default() -> Self {
+        UncZero::zero()
+    }
+}
+
+impl ValUnc<f64, DofUnc> {
+    /// The mean of repeated readings `samples`, with the standard error of the mean and `n - 1`
+    /// degrees of freedom (a Type A evaluation), or `None` if there are fewer than two samples.
+    ///
+    /// ```
+    /// use val_unc::{DofUnc, ValUnc};
+    ///
+    /// let v = ValUnc::from_samples(&[9.0, 10.0, 11.0, 10.0]).unwrap();
+    ///
+    /// assert_eq!(v.val, 10.0);
+    /// assert!(f64::abs(v.unc.u - f64::sqrt(2.0 / 12.0)) <= 1e-12);
+    /// assert_eq!(v.unc.nu, 3.0);
+    /// ```
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        let n = samples.len() as f64;
+        if samples.len() < 2 {
+            return None;
+        }
+        let mean = samples.iter().sum::<f64>() / n;
+        let ss = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+        Some(ValUnc::new(
+            mean,
+            DofUnc::new(f64::sqrt(ss / (n - 1.0) / n), n - 1.0),
+        ))
+    }
+
+    /// The weighted mean of repeated readings `samples`, with the standard error of the mean, or
+    /// `None` if there are fewer than two samples with nonzero weights.
+    ///
+    /// The weights are reliability weights, i.e. they are relative and only their ratios matter.
+    /// The variance is estimated without bias, and the number of samples is replaced by the
+    /// effective number `(Σw)² / Σw²`, so equal weights give the same result as
+    /// [`from_samples`](Self::from_samples).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths are different.
+    pub fn from_samples_weighted(samples: &[f64], weights: &[f64]) -> Option<Self> {
+        assert_eq!(samples.len(), weights.len(), "the lengths are different");
+        if weights.iter().filter(|&&w| w != 0.0).count() < 2 {
+            return None;
+        }
+        let sum_w = weights.iter().sum::<f64>();
+        let sum_w2 = weights.iter().map(|w| w * w).sum::<f64>();
+        let pairs = || samples.iter().zip(weights);
+        let mean = pairs().map(|(x, w)| w * x).sum::<f64>() / sum_w;
+        let ss = pairs().map(|(x, w)| w * (x - mean).powi(2)).sum::<f64>();
+        let n_eff = sum_w.powi(2) / sum_w2;
+        let var = ss / (sum_w - sum_w2 / sum_w);
+        Some(ValUnc::new(
+            mean,
+            DofUnc::new(f64::sqrt(var / n_eff), n_eff - 1.0),
+        ))
+    }
+}
+
+impl UncAdd<f64> for DofUnc {
+    fn unc_add(self, _self_val: f64, other: DofUnc, _other_val: f64) -> DofUnc {
+        Self::combine(self.u, self.nu, other.u, other.nu)
+    }
+}
+
+/// The coverage factor is from Student's t distribution with `nu` degrees of freedom.
+impl UncCoverage for DofUnc {
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        Some((self.u * self.u, self.nu))
+    }
+}
+
+impl<V> UncDisplay<V> for DofUnc {
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(&self.u, f)
+    }
+}
+
+impl UncDiv<f64> for DofUnc {
+    fn unc_div(self, self_val: f64, other: DofUnc, other_val: f64) -> DofUnc {
+        Self::combine(
+            self.u / other_val.abs(),
+            self.nu,
+            other.u * self_val.abs() / other_val.powi(2),
+            other.nu,
+        )
+    }
+}
+
+impl UncInterval<f64> for DofUnc {
+    fn interval(&self, val: f64, k: f64) -> (f64, f64) {
+        symmetric(val, k * self.u)
+    }
+}
+
+impl UncMul<f64> for DofUnc {
+    fn unc_mul(self, self_val: f64, other: DofUnc, other_val: f64) -> DofUnc {
+        Self::combine(
+            self.u * other_val.abs(),
+            self.nu,
+            other.u * self_val.abs(),
+            other.nu,
+        )
+    }
+}
+
+impl UncNeg<f64> for DofUnc {
+    fn unc_neg(self, _self_val: f64) -> DofUnc {
+        self
+    }
+}
+
+/// The degrees of freedom are kept as they are.
+impl UncRound for DofUnc {
+    fn round_at(&self, places: i32) -> Self {
+        Self::new(round_float(self.u, places), self.nu)
+    }
+}
+
+impl UncSub<f64> for DofUnc {
+    fn unc_sub(self, _self_val: f64, other: DofUnc, _other_val: f64) -> DofUnc {
+        Self::combine(self.u, self.nu, other.u, other.nu)
+    }
+}
+
+impl UncTotal<f64> for DofUnc {
+    fn total(&self, _val: f64) -> f64 {
+        self.u
+    }
+}
+
+impl UncValidate for DofUnc {
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.u >= 0.0 && self.nu > 0.0
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.set_zero();
+        }
+    }
+}
+
+impl UncWeight<f64> for DofUnc {}
+
+impl UncZero for DofUnc {
+    fn zero() -> Self {
+        Self {
+            u: 0.0,
+            nu: f64::INFINITY,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.u == 0.0
+    }
+
+    fn set_zero(&mut self) {
+        *self = Self::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn welch_satterthwaite() {
+        let v1 = ValUnc::new(1.0, DofUnc::new(3.0, 4.0));
+        let v2 = ValUnc::new(2.0, DofUnc::new(4.0, 9.0));
+        let v3 = ValUnc::new(3.0, DofUnc::new(0.0, f64::INFINITY));
+
+        // 5^4 / (3^4 / 4 + 4^4 / 9)
+        let ValUnc { unc, .. } = v1 + v2;
+        assert!(f64::abs(unc.u - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(unc.nu - 625.0 / (81.0 / 4.0 + 256.0 / 9.0)) <= 1e-12);
+
+        let ValUnc { unc, .. } = v1 - v3;
+        assert!(f64::abs(unc.u - 3.0) <= f64::EPSILON);
+        assert!(f64::abs(unc.nu - 4.0) <= 1e-12);
+
+        let ValUnc { unc, .. } = v3 - ValUnc::new(1.0, DofUnc::default());
+        assert!(unc.nu.is_infinite());
+    }
+
+    #[test]
+    fn samples() {
+        let x = [1.0, 2.0, 4.0, 7.0, 11.0];
+        let v = ValUnc::from_samples(&x).unwrap();
+
+        // The mean is 5 and the sum of squares is 16 + 9 + 1 + 4 + 36
+        assert_eq!(v.val, 5.0);
+        assert!(f64::abs(v.unc.u - f64::sqrt(66.0 / 4.0 / 5.0)) <= 1e-12);
+        assert_eq!(v.unc.nu, 4.0);
+        assert_eq!(ValUnc::from_samples(&x[..1]), None);
+
+        let w = ValUnc::from_samples_weighted(&x, &[2.0; 5]).unwrap();
+        assert!(f64::abs(w.val - v.val) <= 1e-12);
+        assert!(f64::abs(w.unc.u - v.unc.u) <= 1e-12);
+        assert!(f64::abs(w.unc.nu - v.unc.nu) <= 1e-12);
+
+        // A zero weight is the same as leaving the sample out
+        let w = ValUnc::from_samples_weighted(&x, &[1.0, 1.0, 1.0, 1.0, 0.0]).unwrap();
+        let v = ValUnc::from_samples(&x[..4]).unwrap();
+        assert!(f64::abs(w.val - v.val) <= 1e-12);
+        assert!(f64::abs(w.unc.u - v.unc.u) <= 1e-12);
+        assert!(f64::abs(w.unc.nu - v.unc.nu) <= 1e-12);
+        assert_eq!(
+            ValUnc::from_samples_weighted(&x, &[0.0, 0.0, 1.0, 0.0, 0.0]),
+            None
+        );
+    }
+}
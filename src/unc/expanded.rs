@@ -0,0 +1,255 @@
+use crate::{
+    traits::{display::plus_minus, interval::symmetric, *},
+    unc::Unc,
+    val_unc::ValUnc,
+};
+use core::{
+    fmt,
+    ops::{Div, Mul},
+};
+use num_traits::ToPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An expanded uncertainty `U = k·u`, with standard uncertainty `u` and coverage factor `k`
+///
+/// The standard uncertainties are propagated like [`Unc`]. Since there is no single correct
+/// coverage factor for a combination, the larger of the two is kept.
+///
+/// It is displayed as the expanded uncertainty followed by the coverage factor, e.g.
+/// `0.2 (k = 2)`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExpandedUnc<T> {
+    pub u: T,
+    pub k: T,
+}
+
+impl<T> ExpandedUnc<T> {
+    pub const fn new(u: T, k: T) -> Self {
+        Self { u, k }
+    }
+
+    /// Creates an `ExpandedUnc` from an expanded uncertainty `U` and its coverage factor, as
+    /// stated on e.g. a calibration certificate.
+    pub fn from_expanded(expanded: T, k: T) -> Self
+    where
+        T: Div<T, Output = T> + Clone,
+    {
+        Self {
+            u: expanded / k.clone(),
+            k,
+        }
+    }
+
+    /// The expanded uncertainty `U = k·u`.
+    pub fn expanded(&self) -> T
+    where
+        T: Mul<T, Output = T> + Clone,
+    {
+        self.k.clone() * self.u.clone()
+    }
+
+    fn max_k(a: T, b: T) -> T
+    where
+        T: PartialOrd,
+    {
+        if b > a {
+            b
+        } else {
+            a
+        }
+    }
+}
+
+impl<V, T> ValUnc<V, Unc<T>> {
+    /// Converts a standard uncertainty to an expanded uncertainty with coverage factor `k`.
+    pub fn expand(self, k: T) -> ValUnc<V, ExpandedUnc<T>> {
+        ValUnc::new(self.val, ExpandedUnc::new(self.unc.0, k))
+    }
+}
+
+impl<T> fmt::Display for ExpandedUnc<T>
+where
+    T: fmt::Display + Mul<T, Output = T> + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*} (k = {})", p, self.expanded(), self.k),
+            None => write!(f, "{} (k = {})", self.expanded(), self.k),
+        }
+    }
+}
+
+impl<V, U> UncAdd<V> for ExpandedUnc<U>
+where
+    U: Real,
+{
+    fn unc_add(self, self_val: V, other: ExpandedUnc<U>, other_val: V) -> ExpandedUnc<U> {
+        ExpandedUnc {
+            u: Unc(self.u).unc_add(self_val, Unc(other.u), other_val).0,
+            k: Self::max_k(self.k, other.k),
+        }
+    }
+}
+
+/// The coverage factor for a confidence level is calculated anew from the standard uncertainty,
+/// rather than using `k`.
+impl<T> UncCoverage for ExpandedUnc<T>
+where
+    T: ToPrimitive,
+{
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        self.u.to_f64().map(|u| (u * u, f64::INFINITY))
+    }
+}
+
+impl<V, T> UncDisplay<V> for ExpandedUnc<T>
+where
+    T: fmt::Display + Mul<T, Output = T> + Clone,
+{
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(self, f)
+    }
+}
+
+impl<V, U> UncDiv<V> for ExpandedUnc<U>
+where
+    U: Real + Div<V, Output = U> + Mul<V, Output = U> + PartialOrd,
+    V: Clone,
+{
+    fn unc_div(self, self_val: V, other: ExpandedUnc<U>, other_val: V) -> ExpandedUnc<U> {
+        ExpandedUnc {
+            u: Unc(self.u).unc_div(self_val, Unc(other.u), other_val).0,
+            k: Self::max_k(self.k, other.k),
+        }
+    }
+}
+
+impl<V> UncInterval<V> for ExpandedUnc<V>
+where
+    V: Real,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        symmetric(val.clone(), k * self.total(val))
+    }
+}
+
+impl<V, U> UncMul<V> for ExpandedUnc<U>
+where
+    U: Real + Div<V, Output = U> + Mul<V, Output = U> + PartialOrd,
+    V: Clone,
+{
+    fn unc_mul(self, self_val: V, other: ExpandedUnc<U>, other_val: V) -> ExpandedUnc<U> {
+        ExpandedUnc {
+            u: Unc(self.u).unc_mul(self_val, Unc(other.u), other_val).0,
+            k: Self::max_k(self.k, other.k),
+        }
+    }
+}
+
+impl<V, U> UncNeg<V> for ExpandedUnc<U> {
+    fn unc_neg(self, _self_val: V) -> ExpandedUnc<U> {
+        self
+    }
+}
+
+impl<V, U> UncSub<V> for ExpandedUnc<U>
+where
+    U: Real,
+{
+    fn unc_sub(self, self_val: V, other: ExpandedUnc<U>, other_val: V) -> ExpandedUnc<U> {
+        ExpandedUnc {
+            u: Unc(self.u).unc_sub(self_val, Unc(other.u), other_val).0,
+            k: Self::max_k(self.k, other.k),
+        }
+    }
+}
+
+impl<V> UncTotal<V> for ExpandedUnc<V>
+where
+    V: Clone,
+{
+    fn total(&self, _val: V) -> V {
+        self.u.clone()
+    }
+}
+
+impl<T> UncValidate for ExpandedUnc<T>
+where
+    T: PartialOrd + Zero + One,
+{
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.u >= T::zero() && self.k > T::zero()
+    }
+
+    fn clamp_invalid(&mut self) {
+        // These are false for NaN
+        let u_valid = self.u >= T::zero();
+        let k_valid = self.k > T::zero();
+        if !u_valid {
+            self.u = T::zero();
+        }
+        if !k_valid {
+            self.k = T::one();
+        }
+    }
+}
+
+impl<V> UncWeight<V> for ExpandedUnc<V> where V: Real {}
+
+impl<T> UncZero for ExpandedUnc<T>
+where
+    T: Zero + One,
+{
+    fn zero() -> Self {
+        ExpandedUnc {
+            u: Zero::zero(),
+            k: One::one(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(&self.u)
+    }
+
+    fn set_zero(&mut self) {
+        Zero::set_zero(&mut self.u)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expanded_unc_f64() {
+        let v1 = ValUnc::new(10.0f64, ExpandedUnc::from_expanded(0.6f64, 2.0));
+        let v2 = ValUnc::new(5.0f64, Unc(0.4f64)).expand(1.0);
+
+        let ValUnc { val, unc } = v1 + v2;
+        assert!(f64::abs(val - 15.0) <= f64::EPSILON);
+        assert!(f64::abs(unc.u - 0.5) <= f64::EPSILON);
+        assert!(f64::abs(unc.k - 2.0) <= f64::EPSILON);
+        assert!(f64::abs(unc.expanded() - 1.0) <= f64::EPSILON);
+        assert_eq!(format!("{:.2}", unc), "1.00 (k = 2)");
+    }
+
+    #[test]
+    fn clamp_invalid() {
+        let mut bad_k = ExpandedUnc::new(0.5f64, f64::NAN);
+        bad_k.clamp_invalid();
+        assert_eq!(bad_k, ExpandedUnc::new(0.5, 1.0));
+
+        let mut bad_u = ExpandedUnc::new(-0.5f64, 2.0);
+        bad_u.clamp_invalid();
+        assert_eq!(bad_u, ExpandedUnc::new(0.0, 2.0));
+
+        let mut both = ExpandedUnc::new(f64::NAN, -1.0f64);
+        both.clamp_invalid();
+        assert!(both.is_valid());
+        assert_eq!(both, ExpandedUnc::new(0.0, 1.0));
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    traits::{coverage::welch_satterthwaite, display::fmt_labeled, interval::symmetric, *},
+    val_unc::ValUnc,
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{fmt, iter::FromIterator};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Uncertainties keyed by name
+///
+/// Operations are applied key-wise. A key that is only present in one of the operands is treated
+/// as a zero uncertainty in the other.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct UncMap<U>(pub BTreeMap<String, U>);
+
+impl<U> UncMap<U> {
+    pub const fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Combines `self` and `other` key-wise, filling in missing keys with zero.
+    fn zip_with<F>(mut self, mut other: Self, mut f: F) -> Self
+    where
+        U: UncZero,
+        F: FnMut(U, U) -> U,
+    {
+        let mut out = BTreeMap::new();
+        for (k, u) in core::mem::take(&mut self.0) {
+            let o = other.0.remove(&k).unwrap_or_else(U::zero);
+            out.insert(k, f(u, o));
+        }
+        for (k, o) in other.0 {
+            out.insert(k, f(U::zero(), o));
+        }
+        Self(out)
+    }
+
+    /// Returns the keys that are only present in one of `self` and `other`.
+    pub fn key_mismatch(&self, other: &Self) -> KeyMismatch {
+        KeyMismatch {
+            only_left: self
+                .0
+                .keys()
+                .filter(|k| !other.0.contains_key(*k))
+                .cloned()
+                .collect(),
+            only_right: other
+                .0
+                .keys()
+                .filter(|k| !self.0.contains_key(*k))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// The keys that are only present in one of two [`UncMap`]s
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct KeyMismatch {
+    pub only_left: Vec<String>,
+    pub only_right: Vec<String>,
+}
+
+impl KeyMismatch {
+    /// Returns `true` if both operands have the same keys.
+    pub fn is_empty(&self) -> bool {
+        self.only_left.is_empty() && self.only_right.is_empty()
+    }
+}
+
+impl<V, U> ValUnc<V, UncMap<U>> {
+    /// Combines `self` and `other` with `f`, and also reports the keys that are only present in
+    /// one of them, which would otherwise be silently treated as zero.
+    ///
+    /// ```
+    /// use core::iter::FromIterator;
+    /// use val_unc::{Unc, UncMap, ValUnc};
+    ///
+    /// let a = ValUnc::new(1.0, UncMap::from_iter(vec![("stat", Unc(0.1)), ("lumi", Unc(0.2))]));
+    /// let b = ValUnc::new(2.0, UncMap::from_iter(vec![("stat", Unc(0.1)), ("beam", Unc(0.3))]));
+    ///
+    /// let (_sum, mismatch) = a.zip_report(b, |a, b| a + b);
+    /// assert_eq!(mismatch.only_left, vec!["lumi".to_string()]);
+    /// assert_eq!(mismatch.only_right, vec!["beam".to_string()]);
+    /// ```
+    pub fn zip_report<F>(self, other: Self, f: F) -> (Self, KeyMismatch)
+    where
+        F: FnOnce(Self, Self) -> Self,
+    {
+        let mismatch = self.unc.key_mismatch(&other.unc);
+        (f(self, other), mismatch)
+    }
+}
+
+impl<K, U> FromIterator<(K, U)> for UncMap<U>
+where
+    K: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, U)>>(iter: I) -> Self {
+        Self(iter.into_iter().map(|(k, u)| (k.into(), u)).collect())
+    }
+}
+
+impl<V, U> UncAdd<V> for UncMap<U>
+where
+    V: Clone,
+    U: UncAdd<V> + UncZero,
+{
+    fn unc_add(self, self_val: V, other: Self, other_val: V) -> Self {
+        self.zip_with(other, |u, o| {
+            u.unc_add(self_val.clone(), o, other_val.clone())
+        })
+    }
+}
+
+impl<U> UncCoverage for UncMap<U>
+where
+    U: UncCoverage,
+{
+    fn variance_dof(&self, val: f64) -> Option<(f64, f64)> {
+        welch_satterthwaite(self.0.values().map(|u| u.variance_dof(val)))
+    }
+}
+
+/// Each component is followed by its key, e.g. `1.2 ± 0.1 (stat) ± 0.2 (sys)`.
+impl<V, U> UncDisplay<V> for UncMap<U>
+where
+    U: UncDisplay<V>,
+{
+    fn fmt_unc(&self, val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|(k, u)| fmt_labeled(u, val, k, f))
+    }
+}
+
+impl<V, U> UncDiv<V> for UncMap<U>
+where
+    V: Clone,
+    U: UncDiv<V> + UncZero,
+{
+    fn unc_div(self, self_val: V, other: Self, other_val: V) -> Self {
+        self.zip_with(other, |u, o| {
+            u.unc_div(self_val.clone(), o, other_val.clone())
+        })
+    }
+}
+
+impl<V, U> UncInterval<V> for UncMap<U>
+where
+    V: Real,
+    U: UncTotal<V>,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        symmetric(val.clone(), k * self.total(val))
+    }
+}
+
+impl<V, U> UncMul<V> for UncMap<U>
+where
+    V: Clone,
+    U: UncMul<V> + UncZero,
+{
+    fn unc_mul(self, self_val: V, other: Self, other_val: V) -> Self {
+        self.zip_with(other, |u, o| {
+            u.unc_mul(self_val.clone(), o, other_val.clone())
+        })
+    }
+}
+
+impl<V, U> UncNeg<V> for UncMap<U>
+where
+    V: Clone,
+    U: UncNeg<V>,
+{
+    fn unc_neg(self, self_val: V) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .map(|(k, u)| (k, u.unc_neg(self_val.clone())))
+                .collect(),
+        )
+    }
+}
+
+impl<U> UncRound for UncMap<U>
+where
+    U: UncRound,
+{
+    fn round_at(&self, places: i32) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|(k, u)| (k.clone(), u.round_at(places)))
+                .collect(),
+        )
+    }
+}
+
+impl<V, U> UncSub<V> for UncMap<U>
+where
+    V: Clone,
+    U: UncSub<V> + UncZero,
+{
+    fn unc_sub(self, self_val: V, other: Self, other_val: V) -> Self {
+        self.zip_with(other, |u, o| {
+            u.unc_sub(self_val.clone(), o, other_val.clone())
+        })
+    }
+}
+
+impl<V, U> UncTotal<V> for UncMap<U>
+where
+    V: Real,
+    U: UncTotal<V>,
+{
+    fn total(&self, val: V) -> V {
+        self.0
+            .values()
+            .fold(V::zero(), |sum, u| sum + u.total(val.clone()).powi(2))
+            .sqrt()
+    }
+}
+
+impl<U> UncValidate for UncMap<U>
+where
+    U: UncValidate,
+{
+    fn is_valid(&self) -> bool {
+        self.0.values().all(UncValidate::is_valid)
+    }
+
+    fn clamp_invalid(&mut self) {
+        self.0.values_mut().for_each(UncValidate::clamp_invalid)
+    }
+}
+
+impl<V, U> UncWeight<V> for UncMap<U>
+where
+    V: Real,
+    U: UncTotal<V>,
+{
+}
+
+impl<U> UncZero for UncMap<U>
+where
+    U: UncZero,
+{
+    fn zero() -> Self {
+        Self::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.values().all(UncZero::is_zero)
+    }
+
+    fn set_zero(&mut self) {
+        self.0.values_mut().for_each(UncZero::set_zero)
+    }
+}
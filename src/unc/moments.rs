@@ -0,0 +1,269 @@
+use crate::{
+    dist::normal_quantile,
+    traits::{
+        display::plus_minus, interval::symmetric, UncAdd, UncCoverage, UncDisplay, UncDiv,
+        UncInterval, UncMul, UncNeg, UncSub, UncTotal, UncValidate, UncWeight, UncZero,
+    },
+    val_unc::ValUnc,
+};
+use core::{fmt, ops::Mul};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An uncertainty described by the second, third, and fourth central moments
+///
+/// This allows non-Gaussian uncertainties to be propagated. The moments are propagated through
+/// the linear approximation of each operation, using the fact that the cumulants of independent
+/// quantities add.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MomentsUnc {
+    /// The variance (second central moment)
+    pub var: f64,
+    /// The third central moment
+    pub m3: f64,
+    /// The fourth central moment
+    pub m4: f64,
+}
+
+impl MomentsUnc {
+    pub const fn new(var: f64, m3: f64, m4: f64) -> Self {
+        Self { var, m3, m4 }
+    }
+
+    /// Creates the moments of a Gaussian with standard deviation `sigma`.
+    pub fn gaussian(sigma: f64) -> Self {
+        let var = sigma.powi(2);
+        Self {
+            var,
+            m3: 0.0,
+            m4: 3.0 * var.powi(2),
+        }
+    }
+
+    /// The standard deviation.
+    pub fn std_dev(&self) -> f64 {
+        self.var.sqrt()
+    }
+
+    /// The skewness, which is zero if there is no uncertainty.
+    pub fn skewness(&self) -> f64 {
+        if self.var == 0.0 {
+            return 0.0;
+        }
+        self.m3 / self.var.powf(1.5)
+    }
+
+    /// The excess kurtosis, which is zero for a Gaussian and if there is no uncertainty.
+    pub fn excess_kurtosis(&self) -> f64 {
+        if self.var == 0.0 {
+            return 0.0;
+        }
+        self.m4 / self.var.powi(2) - 3.0
+    }
+
+    /// The `p` quantile relative to the mean, using the Cornish–Fisher expansion.
+    ///
+    /// This corrects the Gaussian quantile for the skewness and kurtosis. It is only accurate
+    /// for moderately non-Gaussian distributions. Every quantile is zero if there is no
+    /// uncertainty.
+    pub fn quantile_cornish_fisher(&self, p: f64) -> f64 {
+        if self.var == 0.0 {
+            return 0.0;
+        }
+        let z = normal_quantile(p);
+        let g1 = self.skewness();
+        let g2 = self.excess_kurtosis();
+        let w = z + (z.powi(2) - 1.0) * g1 / 6.0 + (z.powi(3) - 3.0 * z) * g2 / 24.0
+            - (2.0 * z.powi(3) - 5.0 * z) * g1.powi(2) / 36.0;
+        w * self.std_dev()
+    }
+
+    /// The moments of `c` times the quantity.
+    pub fn scale(self, c: f64) -> Self {
+        Self {
+            var: self.var * c.powi(2),
+            m3: self.m3 * c.powi(3),
+            m4: self.m4 * c.powi(4),
+        }
+    }
+
+    /// The moments of `a + b` for independent `a` and `b`.
+    fn sum(a: Self, b: Self) -> Self {
+        let var = a.var + b.var;
+        let k4 = (a.m4 - 3.0 * a.var.powi(2)) + (b.m4 - 3.0 * b.var.powi(2));
+        Self {
+            var,
+            m3: a.m3 + b.m3,
+            m4: k4 + 3.0 * var.powi(2),
+        }
+    }
+}
+
+impl ValUnc<f64, MomentsUnc> {
+    /// The central interval containing a fraction `p` of the distribution, using the
+    /// Cornish–Fisher expansion. Unlike a Gaussian interval, this is not symmetric about `val`
+    /// when the distribution is skewed.
+    pub fn interval_cornish_fisher(&self, p: f64) -> (f64, f64) {
+        (
+            self.val + self.unc.quantile_cornish_fisher((1.0 - p) / 2.0),
+            self.val + self.unc.quantile_cornish_fisher((1.0 + p) / 2.0),
+        )
+    }
+}
+
+impl Mul<f64> for MomentsUnc {
+    type Output = Self;
+    fn mul(self, other: f64) -> Self {
+        self.scale(other)
+    }
+}
+
+impl UncAdd<f64> for MomentsUnc {
+    fn unc_add(self, _self_val: f64, other: MomentsUnc, _other_val: f64) -> MomentsUnc {
+        Self::sum(self, other)
+    }
+}
+
+impl UncCoverage for MomentsUnc {
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        Some((self.var, f64::INFINITY))
+    }
+}
+
+/// The standard deviation is displayed.
+impl<V> UncDisplay<V> for MomentsUnc {
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(&self.var.sqrt(), f)
+    }
+}
+
+impl UncDiv<f64> for MomentsUnc {
+    fn unc_div(self, self_val: f64, other: MomentsUnc, other_val: f64) -> MomentsUnc {
+        Self::sum(
+            self.scale(1.0 / other_val),
+            other.scale(-self_val / other_val.powi(2)),
+        )
+    }
+}
+
+impl UncInterval<f64> for MomentsUnc {
+    fn interval(&self, val: f64, k: f64) -> (f64, f64) {
+        symmetric(val, k * self.std_dev())
+    }
+}
+
+impl UncMul<f64> for MomentsUnc {
+    fn unc_mul(self, self_val: f64, other: MomentsUnc, other_val: f64) -> MomentsUnc {
+        Self::sum(self.scale(other_val), other.scale(self_val))
+    }
+}
+
+impl UncNeg<f64> for MomentsUnc {
+    fn unc_neg(self, _self_val: f64) -> MomentsUnc {
+        self.scale(-1.0)
+    }
+}
+
+impl UncSub<f64> for MomentsUnc {
+    fn unc_sub(self, _self_val: f64, other: MomentsUnc, _other_val: f64) -> MomentsUnc {
+        Self::sum(self, other.scale(-1.0))
+    }
+}
+
+impl UncTotal<f64> for MomentsUnc {
+    fn total(&self, _val: f64) -> f64 {
+        self.std_dev()
+    }
+}
+
+impl UncValidate for MomentsUnc {
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.var >= 0.0 && self.m4 >= 0.0 && !self.m3.is_nan()
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.set_zero();
+        }
+    }
+}
+
+/// The weight is the inverse of the variance.
+impl UncWeight<f64> for MomentsUnc {
+    fn weight(&self, _val: f64) -> f64 {
+        1.0 / self.var
+    }
+}
+
+impl UncZero for MomentsUnc {
+    fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.var == 0.0 && self.m3 == 0.0 && self.m4 == 0.0
+    }
+
+    fn set_zero(&mut self) {
+        *self = Self::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ValUnc;
+
+    #[test]
+    fn moments_unc() {
+        // An exponential distribution with rate 1 has variance 1, m3 = 2, and m4 = 9.
+        let exp = ValUnc::new(1.0, MomentsUnc::new(1.0, 2.0, 9.0));
+        let gauss = ValUnc::new(2.0, MomentsUnc::gaussian(1.0));
+
+        // The sum of two exponentials is a gamma distribution with shape 2.
+        let ValUnc { unc, .. } = exp + exp;
+        assert!(f64::abs(unc.var - 2.0) <= f64::EPSILON);
+        assert!(f64::abs(unc.skewness() - f64::sqrt(2.0)) <= 1e-12);
+        assert!(f64::abs(unc.excess_kurtosis() - 3.0) <= 1e-12);
+
+        let ValUnc { unc, .. } = gauss - exp;
+        assert!(f64::abs(unc.m3 + 2.0) <= f64::EPSILON);
+
+        let ValUnc { unc, .. } = gauss + gauss;
+        assert!(f64::abs(unc.skewness()) <= f64::EPSILON);
+        assert!(f64::abs(unc.excess_kurtosis()) <= 1e-12);
+
+        let scaled = exp.unc * 2.0;
+        assert!(f64::abs(scaled.var - 4.0) <= f64::EPSILON);
+        assert!(f64::abs(scaled.skewness() - exp.unc.skewness()) <= 1e-12);
+    }
+
+    #[test]
+    fn cornish_fisher() {
+        let gauss = ValUnc::new(1.0, MomentsUnc::gaussian(2.0));
+        let (lower, upper) = gauss.interval_cornish_fisher(0.95);
+        assert!(f64::abs(lower - (1.0 - 2.0 * 1.959_964)) <= 1e-5);
+        assert!(f64::abs(upper - (1.0 + 2.0 * 1.959_964)) <= 1e-5);
+
+        // A gamma distribution with shape 10 and scale 1 has a 95% central interval of about
+        // [4.795, 17.085].
+        let k = 10.0;
+        let gamma = ValUnc::new(k, MomentsUnc::new(k, 2.0 * k, 3.0 * k * k + 6.0 * k));
+        let (lower, upper) = gamma.interval_cornish_fisher(0.95);
+        assert!(f64::abs(lower - 4.795) <= 0.05);
+        assert!(f64::abs(upper - 17.085) <= 0.05);
+    }
+
+    #[test]
+    fn exact() {
+        let exact = ValUnc::new(3.0, MomentsUnc::gaussian(0.0));
+        assert_eq!(exact.unc.skewness(), 0.0);
+        assert_eq!(exact.unc.excess_kurtosis(), 0.0);
+        assert_eq!(exact.interval_cornish_fisher(0.95), (3.0, 3.0));
+    }
+}
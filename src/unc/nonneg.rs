@@ -0,0 +1,203 @@
+use crate::{policy::InvalidUnc, traits::*};
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+/// An uncertainty that is checked to be valid (see [`UncValidate`]) when it is constructed
+///
+/// Operations delegate to the wrapped uncertainty, and debug builds assert that their results are
+/// still valid. Deserialization fails for an invalid uncertainty.
+///
+/// ```
+/// use val_unc::{NonNegUnc, Unc, ValUnc};
+///
+/// assert!(NonNegUnc::new(Unc(-1.0)).is_err());
+///
+/// let v1 = ValUnc::new(1.0, NonNegUnc::new(Unc(0.3)).unwrap());
+/// let v2 = ValUnc::new(2.0, NonNegUnc::new(Unc(0.4)).unwrap());
+/// assert!(f64::abs((v1 + v2).unc.get().0 - 0.5) <= f64::EPSILON);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct NonNegUnc<U>(U);
+
+impl<U> NonNegUnc<U>
+where
+    U: UncValidate,
+{
+    pub fn new(unc: U) -> Result<Self, InvalidUnc> {
+        if unc.is_valid() {
+            Ok(Self(unc))
+        } else {
+            Err(InvalidUnc)
+        }
+    }
+
+    fn checked(unc: U) -> Self {
+        debug_assert!(unc.is_valid(), "{}", InvalidUnc);
+        Self(unc)
+    }
+}
+
+impl<U> NonNegUnc<U> {
+    pub fn get(&self) -> &U {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> U {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<U> Serialize for NonNegUnc<U>
+where
+    U: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, U> Deserialize<'de> for NonNegUnc<U>
+where
+    U: Deserialize<'de> + UncValidate,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(U::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+impl<V, U> UncAdd<V> for NonNegUnc<U>
+where
+    U: UncAdd<V> + UncValidate,
+{
+    fn unc_add(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::checked(self.0.unc_add(self_val, other.0, other_val))
+    }
+}
+
+impl<U> UncCoverage for NonNegUnc<U>
+where
+    U: UncCoverage,
+{
+    fn coverage_factor(&self, val: f64, confidence: f64) -> f64 {
+        self.0.coverage_factor(val, confidence)
+    }
+
+    fn variance_dof(&self, val: f64) -> Option<(f64, f64)> {
+        self.0.variance_dof(val)
+    }
+}
+
+impl<V, U> UncDisplay<V> for NonNegUnc<U>
+where
+    U: UncDisplay<V>,
+{
+    fn fmt_unc(&self, val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_unc(val, f)
+    }
+}
+
+impl<V, U> UncDiv<V> for NonNegUnc<U>
+where
+    U: UncDiv<V> + UncValidate,
+{
+    fn unc_div(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::checked(self.0.unc_div(self_val, other.0, other_val))
+    }
+}
+
+impl<V, U> UncInterval<V> for NonNegUnc<U>
+where
+    U: UncInterval<V>,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        self.0.interval(val, k)
+    }
+}
+
+impl<V, U> UncMul<V> for NonNegUnc<U>
+where
+    U: UncMul<V> + UncValidate,
+{
+    fn unc_mul(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::checked(self.0.unc_mul(self_val, other.0, other_val))
+    }
+}
+
+impl<V, U> UncNeg<V> for NonNegUnc<U>
+where
+    U: UncNeg<V> + UncValidate,
+{
+    fn unc_neg(self, self_val: V) -> Self {
+        Self::checked(self.0.unc_neg(self_val))
+    }
+}
+
+impl<U> UncRound for NonNegUnc<U>
+where
+    U: UncRound + UncValidate,
+{
+    fn round_at(&self, places: i32) -> Self {
+        Self::checked(self.0.round_at(places))
+    }
+}
+
+impl<V, U> UncSub<V> for NonNegUnc<U>
+where
+    U: UncSub<V> + UncValidate,
+{
+    fn unc_sub(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::checked(self.0.unc_sub(self_val, other.0, other_val))
+    }
+}
+
+impl<V, U> UncTotal<V> for NonNegUnc<U>
+where
+    U: UncTotal<V>,
+{
+    fn total(&self, val: V) -> V {
+        self.0.total(val)
+    }
+}
+
+impl<U> UncValidate for NonNegUnc<U>
+where
+    U: UncValidate,
+{
+    fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    fn clamp_invalid(&mut self) {
+        self.0.clamp_invalid()
+    }
+}
+
+impl<V, U> UncWeight<V> for NonNegUnc<U>
+where
+    V: Real,
+    U: UncWeight<V>,
+{
+    fn weight(&self, val: V) -> V {
+        self.0.weight(val)
+    }
+}
+
+impl<U> UncZero for NonNegUnc<U>
+where
+    U: UncZero,
+{
+    fn zero() -> Self {
+        Self(U::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    fn set_zero(&mut self) {
+        self.0.set_zero()
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{
+    traits::{interval::symmetric, *},
+    unc::Unc,
+    val_unc::ValUnc,
+};
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Sub},
+};
+use num_traits::ToPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A relative (fractional) uncertainty, i.e. the uncertainty divided by the value
+///
+/// Under `Mul` and `Div`, the relative uncertainties are added in quadrature. Under `Add` and
+/// `Sub`, they are converted to absolute uncertainties through the values, added in quadrature,
+/// and converted back.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct RelUnc<T>(pub T);
+
+impl<T> RelUnc<T> {
+    /// Creates a relative uncertainty from an absolute uncertainty on the value `val`.
+    pub fn from_abs<V>(unc: Unc<T>, val: V) -> Self
+    where
+        T: Div<V, Output = T> + Signed,
+    {
+        Self((unc.0 / val).abs())
+    }
+
+    /// Converts to an absolute uncertainty on the value `val`.
+    pub fn to_abs<V>(self, val: V) -> Unc<T>
+    where
+        T: Mul<V, Output = T> + Signed,
+    {
+        Unc((self.0 * val).abs())
+    }
+
+    /// Creates a relative uncertainty from a percentage.
+    pub fn from_percent(percent: T) -> Self
+    where
+        T: Div<T, Output = T> + From<u8>,
+    {
+        Self(percent / T::from(100))
+    }
+
+    /// Returns the relative uncertainty as a percentage.
+    pub fn percent(self) -> T
+    where
+        T: Mul<T, Output = T> + From<u8>,
+    {
+        self.0 * T::from(100)
+    }
+}
+
+impl<V, T> UncFrom<Unc<T>, V> for RelUnc<T>
+where
+    T: Div<V, Output = T> + Signed,
+{
+    fn unc_from(unc: Unc<T>, val: V) -> Self {
+        Self::from_abs(unc, val)
+    }
+}
+
+impl<V, T> UncFrom<RelUnc<T>, V> for Unc<T>
+where
+    T: Mul<V, Output = T> + Signed,
+{
+    fn unc_from(unc: RelUnc<T>, val: V) -> Self {
+        unc.to_abs(val)
+    }
+}
+
+impl<V, T> From<ValUnc<V, Unc<T>>> for ValUnc<V, RelUnc<T>>
+where
+    V: Clone,
+    T: Div<V, Output = T> + Signed,
+{
+    fn from(v: ValUnc<V, Unc<T>>) -> Self {
+        Self {
+            unc: RelUnc::from_abs(v.unc, v.val.clone()),
+            val: v.val,
+        }
+    }
+}
+
+impl<V, T> From<ValUnc<V, RelUnc<T>>> for ValUnc<V, Unc<T>>
+where
+    V: Clone,
+    T: Mul<V, Output = T> + Signed,
+{
+    fn from(v: ValUnc<V, RelUnc<T>>) -> Self {
+        Self {
+            unc: v.unc.to_abs(v.val.clone()),
+            val: v.val,
+        }
+    }
+}
+
+impl<V, U> UncAdd<V> for RelUnc<U>
+where
+    U: Real + Div<V, Output = U> + Mul<V, Output = U> + Signed,
+    V: Add<V, Output = V> + Clone,
+{
+    fn unc_add(self, self_val: V, other: RelUnc<U>, other_val: V) -> RelUnc<U> {
+        RelUnc(
+            (((self.0 * self_val.clone()).powi(2) + (other.0 * other_val.clone()).powi(2)).sqrt()
+                / (self_val + other_val))
+                .abs(),
+        )
+    }
+}
+
+impl<T> UncCoverage for RelUnc<T>
+where
+    T: ToPrimitive,
+{
+    fn variance_dof(&self, val: f64) -> Option<(f64, f64)> {
+        let u = self.0.to_f64()? * val;
+        Some((u * u, f64::INFINITY))
+    }
+}
+
+/// The uncertainty is displayed as a percentage, e.g. `20 ± 2.5%`.
+impl<V, T> UncDisplay<V> for RelUnc<T>
+where
+    T: fmt::Display + Mul<T, Output = T> + From<u8> + Clone,
+{
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(" ± ")?;
+        fmt::Display::fmt(&self.clone().percent(), f)?;
+        f.write_str("%")
+    }
+}
+
+impl<V, U> UncDiv<V> for RelUnc<U>
+where
+    U: Real,
+{
+    fn unc_div(self, _self_val: V, other: RelUnc<U>, _other_val: V) -> RelUnc<U> {
+        RelUnc((self.0.powi(2) + other.0.powi(2)).sqrt())
+    }
+}
+
+impl<V> UncInterval<V> for RelUnc<V>
+where
+    V: Real + Signed,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        symmetric(val.clone(), k * self.total(val))
+    }
+}
+
+impl<V, U> UncMul<V> for RelUnc<U>
+where
+    U: Real,
+{
+    fn unc_mul(self, _self_val: V, other: RelUnc<U>, _other_val: V) -> RelUnc<U> {
+        RelUnc((self.0.powi(2) + other.0.powi(2)).sqrt())
+    }
+}
+
+impl<V, U> UncNeg<V> for RelUnc<U> {
+    fn unc_neg(self, _self_val: V) -> RelUnc<U> {
+        self
+    }
+}
+
+impl<V, U> UncSub<V> for RelUnc<U>
+where
+    U: Real + Div<V, Output = U> + Mul<V, Output = U> + Signed,
+    V: Sub<V, Output = V> + Clone,
+{
+    fn unc_sub(self, self_val: V, other: RelUnc<U>, other_val: V) -> RelUnc<U> {
+        RelUnc(
+            (((self.0 * self_val.clone()).powi(2) + (other.0 * other_val.clone()).powi(2)).sqrt()
+                / (self_val - other_val))
+                .abs(),
+        )
+    }
+}
+
+impl<V> UncTotal<V> for RelUnc<V>
+where
+    V: Signed + Clone,
+{
+    fn total(&self, val: V) -> V {
+        (self.0.clone() * val).abs()
+    }
+}
+
+impl<T> UncValidate for RelUnc<T>
+where
+    T: PartialOrd + Zero,
+{
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.0 >= T::zero()
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.0 = T::zero();
+        }
+    }
+}
+
+impl<V> UncWeight<V> for RelUnc<V> where V: Real + Signed {}
+
+impl<T> UncZero for RelUnc<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        RelUnc(Zero::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(&self.0)
+    }
+
+    fn set_zero(&mut self) {
+        Zero::set_zero(&mut self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn rel_unc_f64() {
+        let unc_1 = RelUnc(0.03f64);
+        let unc_2 = RelUnc(0.04f64);
+
+        assert!(
+            f64::abs(UncAdd::<f64>::unc_add(unc_1, 100.0, unc_2, 100.0).0 - 0.025) <= f64::EPSILON
+        );
+        assert!(f64::abs(UncDiv::<f64>::unc_div(unc_1, 2.0, unc_2, 3.0).0 - 0.05) <= f64::EPSILON);
+        assert!(f64::abs(UncMul::<f64>::unc_mul(unc_1, 2.0, unc_2, 3.0).0 - 0.05) <= f64::EPSILON);
+        assert!(
+            f64::abs(UncSub::<f64>::unc_sub(unc_1, 100.0, unc_2, 50.0).0 - f64::sqrt(13.0) / 50.0)
+                <= f64::EPSILON
+        );
+        assert!(f64::abs(<RelUnc<f64>>::zero().0 - 0.0) <= f64::EPSILON);
+    }
+
+    #[test]
+    fn conversions() {
+        let abs = ValUnc::new(-20.0f64, Unc(0.5f64));
+        let rel: ValUnc<f64, RelUnc<f64>> = abs.into();
+        assert!(f64::abs(rel.unc.0 - 0.025) <= f64::EPSILON);
+        assert!(f64::abs(rel.unc.percent() - 2.5) <= f64::EPSILON);
+
+        let abs: ValUnc<f64, Unc<f64>> = rel.into();
+        assert!(f64::abs(abs.unc.0 - 0.5) <= f64::EPSILON);
+
+        assert!(f64::abs(RelUnc::from_percent(2.5f64).0 - 0.025) <= f64::EPSILON);
+        assert_eq!(format!("{:.1}", rel), "-20.0 ± 2.5%");
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{
+    traits::{display::plus_minus, interval::symmetric, round::round_float, *},
+    unc::Unc,
+};
+use core::{
+    fmt,
+    ops::{Div, Mul},
+};
+use num_traits::ToPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The uncertainty due to the finite resolution of a digital readout
+///
+/// A reading with resolution `r` (the value of the least significant digit) is uniformly
+/// distributed within `±r/2`, so the standard uncertainty is `u = r / √12`. The standard
+/// uncertainty is stored and propagated like [`Unc`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ResolutionUnc<T> {
+    pub u: T,
+}
+
+impl<T> ResolutionUnc<T>
+where
+    T: Real,
+{
+    /// Creates the uncertainty of a readout with resolution `resolution`.
+    pub fn new(resolution: T) -> Self {
+        Self {
+            u: resolution / Self::sqrt_12(),
+        }
+    }
+
+    /// The resolution that corresponds to this uncertainty.
+    pub fn resolution(&self) -> T {
+        self.u.clone() * Self::sqrt_12()
+    }
+
+    fn sqrt_12() -> T {
+        T::from_i32(12).unwrap().sqrt()
+    }
+}
+
+impl<T> From<ResolutionUnc<T>> for Unc<T> {
+    fn from(unc: ResolutionUnc<T>) -> Self {
+        Unc(unc.u)
+    }
+}
+
+impl<V, U> UncAdd<V> for ResolutionUnc<U>
+where
+    U: Real,
+{
+    fn unc_add(self, self_val: V, other: ResolutionUnc<U>, other_val: V) -> ResolutionUnc<U> {
+        ResolutionUnc {
+            u: Unc(self.u).unc_add(self_val, Unc(other.u), other_val).0,
+        }
+    }
+}
+
+/// The distribution is uniform, so the interval covering `confidence` is that fraction of its
+/// width, which is `√12` standard uncertainties.
+impl<T> UncCoverage for ResolutionUnc<T>
+where
+    T: ToPrimitive,
+{
+    fn coverage_factor(&self, _val: f64, confidence: f64) -> f64 {
+        confidence * <f64 as Float>::sqrt(3.0)
+    }
+
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        self.u.to_f64().map(|u| (u * u, f64::INFINITY))
+    }
+}
+
+impl<V, T> UncDisplay<V> for ResolutionUnc<T>
+where
+    T: fmt::Display,
+{
+    fn fmt_unc(&self, _val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(&self.u, f)
+    }
+}
+
+impl<V, U> UncDiv<V> for ResolutionUnc<U>
+where
+    U: Real + Div<V, Output = U> + Mul<V, Output = U>,
+    V: Clone,
+{
+    fn unc_div(self, self_val: V, other: ResolutionUnc<U>, other_val: V) -> ResolutionUnc<U> {
+        ResolutionUnc {
+            u: Unc(self.u).unc_div(self_val, Unc(other.u), other_val).0,
+        }
+    }
+}
+
+impl<V> UncInterval<V> for ResolutionUnc<V>
+where
+    V: Real,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        symmetric(val.clone(), k * self.total(val))
+    }
+}
+
+impl<V, U> UncMul<V> for ResolutionUnc<U>
+where
+    U: Real + Div<V, Output = U> + Mul<V, Output = U>,
+    V: Clone,
+{
+    fn unc_mul(self, self_val: V, other: ResolutionUnc<U>, other_val: V) -> ResolutionUnc<U> {
+        ResolutionUnc {
+            u: Unc(self.u).unc_mul(self_val, Unc(other.u), other_val).0,
+        }
+    }
+}
+
+impl<V, U> UncNeg<V> for ResolutionUnc<U> {
+    fn unc_neg(self, _self_val: V) -> ResolutionUnc<U> {
+        self
+    }
+}
+
+impl<T> UncRound for ResolutionUnc<T>
+where
+    T: Real,
+{
+    fn round_at(&self, places: i32) -> Self {
+        Self {
+            u: round_float(self.u.clone(), places),
+        }
+    }
+}
+
+impl<V, U> UncSub<V> for ResolutionUnc<U>
+where
+    U: Real,
+{
+    fn unc_sub(self, self_val: V, other: ResolutionUnc<U>, other_val: V) -> ResolutionUnc<U> {
+        ResolutionUnc {
+            u: Unc(self.u).unc_sub(self_val, Unc(other.u), other_val).0,
+        }
+    }
+}
+
+impl<V> UncTotal<V> for ResolutionUnc<V>
+where
+    V: Clone,
+{
+    fn total(&self, _val: V) -> V {
+        self.u.clone()
+    }
+}
+
+impl<T> UncValidate for ResolutionUnc<T>
+where
+    T: PartialOrd + Zero,
+{
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.u >= T::zero()
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.u = T::zero();
+        }
+    }
+}
+
+impl<V> UncWeight<V> for ResolutionUnc<V> where V: Real {}
+
+impl<T> UncZero for ResolutionUnc<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        ResolutionUnc { u: Zero::zero() }
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(&self.u)
+    }
+
+    fn set_zero(&mut self) {
+        Zero::set_zero(&mut self.u)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolution_unc_f64() {
+        let unc = ResolutionUnc::new(0.01f64);
+        assert!(f64::abs(unc.u - 0.01 / f64::sqrt(12.0)) <= f64::EPSILON);
+        assert!(f64::abs(unc.resolution() - 0.01) <= f64::EPSILON);
+
+        let sum = UncAdd::<f64>::unc_add(unc, 1.0, unc, 1.0);
+        assert!(f64::abs(sum.u - 0.01 / f64::sqrt(6.0)) <= f64::EPSILON);
+        assert!(f64::abs(Unc::<f64>::from(sum).0 - sum.u) <= f64::EPSILON);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::traits::{display::plus_minus, interval::symmetric, *};
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Sub},
+};
+use num_traits::ToPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A fully correlated, multiplicative uncertainty, e.g. on a luminosity or a gain
+///
+/// The uncertainty is stored as a fraction of the value. Since every quantity is scaled by the
+/// same (unknown) factor, the uncertainties are combined linearly rather than in quadrature: the
+/// absolute uncertainties add under `Add`, and the fractions add under `Mul` and cancel under
+/// `Div`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ScaleUnc<T>(pub T);
+
+impl<V, U> UncAdd<V> for ScaleUnc<U>
+where
+    U: Add<U, Output = U> + Div<V, Output = U> + Mul<V, Output = U> + Signed,
+    V: Add<V, Output = V> + Clone,
+{
+    fn unc_add(self, self_val: V, other: ScaleUnc<U>, other_val: V) -> ScaleUnc<U> {
+        ScaleUnc(
+            ((self.0 * self_val.clone() + other.0 * other_val.clone()) / (self_val + other_val))
+                .abs(),
+        )
+    }
+}
+
+impl<T> UncCoverage for ScaleUnc<T>
+where
+    T: ToPrimitive,
+{
+    fn variance_dof(&self, val: f64) -> Option<(f64, f64)> {
+        let u = self.0.to_f64()? * val;
+        Some((u * u, f64::INFINITY))
+    }
+}
+
+/// The uncertainty is displayed as an absolute uncertainty, i.e. scaled by the value.
+impl<V, T> UncDisplay<V> for ScaleUnc<T>
+where
+    T: fmt::Display + Mul<V, Output = T> + Signed + Clone,
+    V: Clone,
+{
+    fn fmt_unc(&self, val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        plus_minus(&(self.0.clone() * val.clone()).abs(), f)
+    }
+}
+
+impl<V, U> UncDiv<V> for ScaleUnc<U>
+where
+    U: Sub<U, Output = U> + Signed,
+{
+    fn unc_div(self, _self_val: V, other: ScaleUnc<U>, _other_val: V) -> ScaleUnc<U> {
+        ScaleUnc((self.0 - other.0).abs())
+    }
+}
+
+impl<V> UncInterval<V> for ScaleUnc<V>
+where
+    V: Real + Signed,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        symmetric(val.clone(), k * self.total(val))
+    }
+}
+
+impl<V, U> UncMul<V> for ScaleUnc<U>
+where
+    U: Add<U, Output = U>,
+{
+    fn unc_mul(self, _self_val: V, other: ScaleUnc<U>, _other_val: V) -> ScaleUnc<U> {
+        ScaleUnc(self.0 + other.0)
+    }
+}
+
+impl<V, U> UncNeg<V> for ScaleUnc<U> {
+    fn unc_neg(self, _self_val: V) -> ScaleUnc<U> {
+        self
+    }
+}
+
+/// The fraction doesn't have the decimal places of the value, so it is left as is.
+impl<T> UncRound for ScaleUnc<T>
+where
+    T: Clone,
+{
+    fn round_at(&self, _places: i32) -> Self {
+        self.clone()
+    }
+}
+
+impl<V, U> UncSub<V> for ScaleUnc<U>
+where
+    U: Sub<U, Output = U> + Div<V, Output = U> + Mul<V, Output = U> + Signed,
+    V: Sub<V, Output = V> + Clone,
+{
+    fn unc_sub(self, self_val: V, other: ScaleUnc<U>, other_val: V) -> ScaleUnc<U> {
+        ScaleUnc(
+            ((self.0 * self_val.clone() - other.0 * other_val.clone()) / (self_val - other_val))
+                .abs(),
+        )
+    }
+}
+
+impl<V> UncTotal<V> for ScaleUnc<V>
+where
+    V: Signed + Clone,
+{
+    fn total(&self, val: V) -> V {
+        (self.0.clone() * val).abs()
+    }
+}
+
+impl<T> UncValidate for ScaleUnc<T>
+where
+    T: PartialOrd + Zero,
+{
+    fn is_valid(&self) -> bool {
+        // This is false for NaN
+        self.0 >= T::zero()
+    }
+
+    fn clamp_invalid(&mut self) {
+        if !self.is_valid() {
+            self.0 = T::zero();
+        }
+    }
+}
+
+impl<V> UncWeight<V> for ScaleUnc<V> where V: Real + Signed {}
+
+impl<T> UncZero for ScaleUnc<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        ScaleUnc(Zero::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(&self.0)
+    }
+
+    fn set_zero(&mut self) {
+        Zero::set_zero(&mut self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stats::weighted_mean, BoundUnc, Unc, ValUnc};
+
+    #[test]
+    fn scale_unc_f64() {
+        let v1 = ValUnc::new(30.0f64, (Unc(3.0f64), ScaleUnc(0.1f64)));
+        let v2 = ValUnc::new(10.0f64, (Unc(4.0f64), ScaleUnc(0.1f64)));
+
+        let ValUnc {
+            val,
+            unc: (stat, scale),
+        } = v1 + v2;
+        assert!(f64::abs(val - 40.0) <= f64::EPSILON);
+        assert!(f64::abs(stat.0 - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(scale.0 - 0.1) <= f64::EPSILON);
+        assert_eq!(format!("{:.1}", v1 + v2), "40.0 ± 5.0 ± 4.0");
+
+        let ValUnc {
+            unc: (_, scale), ..
+        } = v1 * v2;
+        assert!(f64::abs(scale.0 - 0.2) <= f64::EPSILON);
+
+        let ValUnc {
+            unc: (_, scale), ..
+        } = v1 / v2;
+        assert!(f64::abs(scale.0) <= f64::EPSILON);
+    }
+
+    #[test]
+    fn mixed() {
+        let v = ValUnc::new(40.0f64, (Unc(3.0f64), ScaleUnc(0.1f64)));
+
+        assert!(f64::abs(v.total_unc() - 5.0) <= f64::EPSILON);
+        let (lo, hi) = v.interval(1.0);
+        assert!(f64::abs(lo - 35.0) <= f64::EPSILON && f64::abs(hi - 45.0) <= f64::EPSILON);
+        assert!(f64::abs(v.at_confidence(0.6827).1 - 45.0) < 1e-3);
+        assert_eq!(
+            ValUnc::new(40.04, (Unc(3.04), ScaleUnc(0.1))).round_to_unc(1),
+            ValUnc::new(40.0, (Unc(3.0), ScaleUnc(0.1)))
+        );
+
+        let b = ValUnc::new(40.0f64, (BoundUnc(3.0f64), ScaleUnc(0.1f64)));
+        assert!(f64::abs(b.total_unc() - 5.0) <= f64::EPSILON);
+        assert!(f64::abs(b.unc.weight(b.val) - 1.0 / 25.0) <= f64::EPSILON);
+
+        let w = ValUnc::new(40.0f64, (Unc(4.0f64), ScaleUnc(0.075f64)));
+        let mean = weighted_mean([v, w]).unwrap();
+        assert!(f64::abs(mean.val - 40.0) <= f64::EPSILON);
+        assert!(f64::abs(mean.unc.0 - 5.0 / f64::sqrt(2.0)) <= 1e-12);
+    }
+}
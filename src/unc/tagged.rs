@@ -0,0 +1,285 @@
+use crate::traits::*;
+use core::{cmp::Ordering, fmt, hash, marker::PhantomData};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An uncertainty tagged with a dimension marker type `D`
+///
+/// The tag has no effect on propagation, but because `ValUnc` operations require both operands
+/// to have the same uncertainty type, uncertainties with different tags can not be mixed.
+///
+/// ```compile_fail
+/// use val_unc::{Tagged, Unc, ValUnc};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+/// struct Meters;
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+/// struct Seconds;
+///
+/// let length = ValUnc::new(2.0, Tagged::<_, Meters>::new(Unc(0.1)));
+/// let time = ValUnc::new(3.0, Tagged::<_, Seconds>::new(Unc(0.2)));
+/// let _ = length + time;
+/// ```
+///
+/// The standard traits, e.g. `Clone` and `PartialEq`, only require `U` to implement them, so the
+/// marker type can be a bare `struct Meters;`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Tagged<U, D> {
+    pub unc: U,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dim: PhantomData<D>,
+}
+
+impl<U, D> Tagged<U, D> {
+    pub const fn new(unc: U) -> Self {
+        Self {
+            unc,
+            dim: PhantomData,
+        }
+    }
+}
+
+impl<U, D> From<U> for Tagged<U, D> {
+    fn from(unc: U) -> Self {
+        Self::new(unc)
+    }
+}
+
+// These are implemented by hand, since the derives would require `D` to implement them too.
+
+impl<U, D> fmt::Debug for Tagged<U, D>
+where
+    U: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Tagged").field(&self.unc).finish()
+    }
+}
+
+impl<U, D> Clone for Tagged<U, D>
+where
+    U: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.unc.clone())
+    }
+}
+
+impl<U, D> Copy for Tagged<U, D> where U: Copy {}
+
+impl<U, D> PartialEq for Tagged<U, D>
+where
+    U: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.unc == other.unc
+    }
+}
+
+impl<U, D> Eq for Tagged<U, D> where U: Eq {}
+
+impl<U, D> PartialOrd for Tagged<U, D>
+where
+    U: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.unc.partial_cmp(&other.unc)
+    }
+}
+
+impl<U, D> Ord for Tagged<U, D>
+where
+    U: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.unc.cmp(&other.unc)
+    }
+}
+
+impl<U, D> hash::Hash for Tagged<U, D>
+where
+    U: hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.unc.hash(state)
+    }
+}
+
+impl<U, D> Default for Tagged<U, D>
+where
+    U: Default,
+{
+    fn default() -> Self {
+        Self::new(U::default())
+    }
+}
+
+impl<V, U, D> UncAdd<V> for Tagged<U, D>
+where
+    U: UncAdd<V>,
+{
+    fn unc_add(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::new(self.unc.unc_add(self_val, other.unc, other_val))
+    }
+}
+
+impl<U, D> UncCoverage for Tagged<U, D>
+where
+    U: UncCoverage,
+{
+    fn coverage_factor(&self, val: f64, confidence: f64) -> f64 {
+        self.unc.coverage_factor(val, confidence)
+    }
+
+    fn variance_dof(&self, val: f64) -> Option<(f64, f64)> {
+        self.unc.variance_dof(val)
+    }
+}
+
+impl<V, U, D> UncDisplay<V> for Tagged<U, D>
+where
+    U: UncDisplay<V>,
+{
+    fn fmt_unc(&self, val: &V, f: &mut fmt::Formatter) -> fmt::Result {
+        self.unc.fmt_unc(val, f)
+    }
+}
+
+impl<V, U, D> UncDiv<V> for Tagged<U, D>
+where
+    U: UncDiv<V>,
+{
+    fn unc_div(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::new(self.unc.unc_div(self_val, other.unc, other_val))
+    }
+}
+
+impl<V, U, D> UncInterval<V> for Tagged<U, D>
+where
+    U: UncInterval<V>,
+{
+    fn interval(&self, val: V, k: V) -> (V, V) {
+        self.unc.interval(val, k)
+    }
+}
+
+impl<V, U, D> UncMul<V> for Tagged<U, D>
+where
+    U: UncMul<V>,
+{
+    fn unc_mul(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::new(self.unc.unc_mul(self_val, other.unc, other_val))
+    }
+}
+
+impl<V, U, D> UncNeg<V> for Tagged<U, D>
+where
+    U: UncNeg<V>,
+{
+    fn unc_neg(self, self_val: V) -> Self {
+        Self::new(self.unc.unc_neg(self_val))
+    }
+}
+
+impl<U, D> UncRound for Tagged<U, D>
+where
+    U: UncRound,
+{
+    fn round_at(&self, places: i32) -> Self {
+        Self::new(self.unc.round_at(places))
+    }
+}
+
+impl<V, U, D> UncSub<V> for Tagged<U, D>
+where
+    U: UncSub<V>,
+{
+    fn unc_sub(self, self_val: V, other: Self, other_val: V) -> Self {
+        Self::new(self.unc.unc_sub(self_val, other.unc, other_val))
+    }
+}
+
+impl<V, U, D> UncTotal<V> for Tagged<U, D>
+where
+    U: UncTotal<V>,
+{
+    fn total(&self, val: V) -> V {
+        self.unc.total(val)
+    }
+}
+
+impl<U, D> UncValidate for Tagged<U, D>
+where
+    U: UncValidate,
+{
+    fn is_valid(&self) -> bool {
+        self.unc.is_valid()
+    }
+
+    fn clamp_invalid(&mut self) {
+        self.unc.clamp_invalid()
+    }
+}
+
+impl<V, U, D> UncWeight<V> for Tagged<U, D>
+where
+    V: Real,
+    U: UncWeight<V>,
+{
+    fn weight(&self, val: V) -> V {
+        self.unc.weight(val)
+    }
+}
+
+impl<U, D> UncZero for Tagged<U, D>
+where
+    U: UncZero,
+{
+    fn zero() -> Self {
+        Self::new(U::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.unc.is_zero()
+    }
+
+    fn set_zero(&mut self) {
+        self.unc.set_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Unc, ValUnc};
+
+    // The marker doesn't implement any traits
+    struct Meters;
+
+    #[test]
+    fn same_tag() {
+        let a = ValUnc::new(2.0, Tagged::<_, Meters>::new(Unc(0.3)));
+        let b = ValUnc::new(1.0, Tagged::<_, Meters>::new(Unc(0.4)));
+
+        assert_eq!(a + b, ValUnc::new(3.0, Tagged::new(Unc(0.5))));
+        assert_eq!((a - b).unc, (a + b).unc);
+        assert_eq!(
+            (a * b).unc.unc,
+            (ValUnc::new(2.0, Unc(0.3)) * ValUnc::new(1.0, Unc(0.4))).unc
+        );
+        assert_eq!(format!("{:?}", a.unc), "Tagged(Unc(0.3))");
+        assert_eq!(a.to_string(), "2 ± 0.3");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let v = ValUnc::new(2.0, Tagged::<_, Meters>::new(Unc(0.3)));
+        let json = serde_json::to_string(&v).unwrap();
+
+        assert_eq!(json, r#"{"val":2.0,"unc":0.3}"#);
+        assert_eq!(serde_json::from_str::<ValUnc<_, _>>(&json).unwrap(), v);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::traits::*;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An uncertainty that is always zero, for exact values
+///
+/// It takes up no space, so it can be used for exact constants, e.g. `ValUnc<f64, ZeroUnc>`, or
+/// as a component of a tuple of uncertainties.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ZeroUnc;
+
+impl<V> UncAdd<V> for ZeroUnc {
+    fn unc_add(self, _self_val: V, _other: ZeroUnc, _other_val: V) -> ZeroUnc {
+        ZeroUnc
+    }
+}
+
+impl UncCoverage for ZeroUnc {
+    fn variance_dof(&self, _val: f64) -> Option<(f64, f64)> {
+        Some((0.0, f64::INFINITY))
+    }
+}
+
+impl<V> UncDisplay<V> for ZeroUnc {
+    fn fmt_unc(&self, _val: &V, _f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl<V> UncDiv<V> for ZeroUnc {
+    fn unc_div(self, _self_val: V, _other: ZeroUnc, _other_val: V) -> ZeroUnc {
+        ZeroUnc
+    }
+}
+
+impl<V> UncInterval<V> for ZeroUnc
+where
+    V: Clone,
+{
+    fn interval(&self, val: V, _k: V) -> (V, V) {
+        (val.clone(), val)
+    }
+}
+
+impl<V> UncMul<V> for ZeroUnc {
+    fn unc_mul(self, _self_val: V, _other: ZeroUnc, _other_val: V) -> ZeroUnc {
+        ZeroUnc
+    }
+}
+
+impl<V> UncNeg<V> for ZeroUnc {
+    fn unc_neg(self, _self_val: V) -> ZeroUnc {
+        ZeroUnc
+    }
+}
+
+impl UncRound for ZeroUnc {
+    fn round_at(&self, _places: i32) -> Self {
+        ZeroUnc
+    }
+}
+
+impl<V> UncSub<V> for ZeroUnc {
+    fn unc_sub(self, _self_val: V, _other: ZeroUnc, _other_val: V) -> ZeroUnc {
+        ZeroUnc
+    }
+}
+
+impl<V> UncTotal<V> for ZeroUnc
+where
+    V: Zero,
+{
+    fn total(&self, _val: V) -> V {
+        V::zero()
+    }
+}
+
+impl UncValidate for ZeroUnc {
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    fn clamp_invalid(&mut self) {}
+}
+
+impl<V> UncWeight<V> for ZeroUnc where V: Real {}
+
+impl UncZero for ZeroUnc {
+    fn zero() -> Self {
+        ZeroUnc
+    }
+
+    fn is_zero(&self) -> bool {
+        true
+    }
+
+    fn set_zero(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Unc, ValUnc};
+    use core::mem::size_of;
+
+    #[test]
+    fn zero() {
+        let mut u = <ZeroUnc as UncZero>::zero();
+        assert!(u.is_zero());
+        u.set_zero();
+        assert_eq!(u, ZeroUnc);
+        assert!(u.is_valid());
+        assert_eq!(size_of::<ValUnc<f64, ZeroUnc>>(), size_of::<f64>());
+
+        // A tuple with an exact component is zero when the others are
+        let mut t = (Unc(0.5), ZeroUnc);
+        assert!(!t.is_zero());
+        t.set_zero();
+        assert!(t.is_zero());
+    }
+
+    #[test]
+    fn ops() {
+        let a = ValUnc::new(2.0, ZeroUnc);
+        let b = ValUnc::new(4.0, ZeroUnc);
+        assert_eq!(a + b, ValUnc::new(6.0, ZeroUnc));
+        assert_eq!(a * b, ValUnc::new(8.0, ZeroUnc));
+        assert_eq!(-a, ValUnc::new(-2.0, ZeroUnc));
+        assert_eq!(a.unc.total(a.val), 0.0);
+        assert_eq!(a.to_string(), "2");
+
+        // The exact component doesn't change the other
+        let c = ValUnc::new(3.0, (Unc(0.3), ZeroUnc)) + ValUnc::new(1.0, (Unc(0.4), ZeroUnc));
+        assert_eq!(c, ValUnc::new(4.0, (Unc(0.5), ZeroUnc)));
+    }
+}
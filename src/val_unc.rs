@@ -1,22 +1,277 @@
-use crate::traits::*;
+use crate::{traits::*, unc::Unc};
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// A type with a value and uncertainties.
+///
+/// The uncertainty defaults to an [`Unc`], so that `ValUnc<f64>` is a `ValUnc<f64, Unc<f64>>`.
+/// Defaults only apply to types, though, so `ValUnc::new(1.2, 0.1)` is still a
+/// `ValUnc<f64, f64>`.
+///
+/// The operators only require the value to be `Clone`, so heap-allocated, arbitrary precision
+/// values can be used, and are also implemented for references, e.g. `&a + &b`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct ValUnc<V, U> {
+pub struct ValUnc<V, U = Unc<V>> {
     pub val: V,
     pub unc: U,
 }
 
+/// A `ValUnc` with a single [`Unc`]
+pub type SimpleValUnc<V> = ValUnc<V, Unc<V>>;
+
 impl<V, U> ValUnc<V, U> {
-    pub fn new(val: V, unc: U) -> Self {
+    /// Creates a `ValUnc`.
+    ///
+    /// This is a `const fn`, so tables of constants can be defined without lazy initialization.
+    ///
+    /// ```
+    /// use val_unc::{Unc, ValUnc};
+    ///
+    /// const CONSTANTS: [(&str, ValUnc<f64>); 2] = [
+    ///     ("G", ValUnc::new(6.67430e-11, Unc(0.00015e-11))),
+    ///     ("m_e", ValUnc::new(9.1093837139e-31, Unc(0.0000000028e-31))),
+    /// ];
+    ///
+    /// assert_eq!(CONSTANTS[0].1.val, 6.67430e-11);
+    /// ```
+    pub const fn new(val: V, unc: U) -> Self {
         Self { val, unc }
     }
+
+    /// Splits into the value and the uncertainty.
+    pub fn into_parts(self) -> (V, U) {
+        (self.val, self.unc)
+    }
+
+    /// Borrows the value and the uncertainty.
+    ///
+    /// ```
+    /// use val_unc::{Unc, ValUnc};
+    ///
+    /// let v = vec![ValUnc::new(1.0, Unc(0.1)), ValUnc::new(2.0, Unc(0.2))];
+    /// let vals: Vec<&f64> = v.iter().map(|x| x.as_ref().val).collect();
+    ///
+    /// assert_eq!(vals, vec![&1.0, &2.0]);
+    /// ```
+    pub const fn as_ref(&self) -> ValUnc<&V, &U> {
+        ValUnc::new(&self.val, &self.unc)
+    }
+
+    /// Mutably borrows the value and the uncertainty.
+    pub fn as_mut(&mut self) -> ValUnc<&mut V, &mut U> {
+        ValUnc::new(&mut self.val, &mut self.unc)
+    }
+
+    /// Converts the uncertainty to another type.
+    ///
+    /// ```
+    /// use val_unc::{RelUnc, Unc, ValUnc};
+    ///
+    /// let v = ValUnc::new(20.0, (Unc(3.0), RelUnc(0.2)));
+    /// let total = v.convert_unc::<Unc<f64>>();
+    ///
+    /// assert!(f64::abs(total.unc.0 - 5.0) <= f64::EPSILON);
+    /// ```
+    pub fn convert_unc<U2>(self) -> ValUnc<V, U2>
+    where
+        V: Clone,
+        U2: UncFrom<U, V>,
+    {
+        ValUnc {
+            unc: U2::unc_from(self.unc, self.val.clone()),
+            val: self.val,
+        }
+    }
+
+    /// Returns the total uncertainty, combining all of the components (see [`UncTotal`]).
+    ///
+    /// ```
+    /// use val_unc::{RelUnc, Unc, ValUnc};
+    ///
+    /// let v = ValUnc::new(20.0, (Unc(3.0), RelUnc(0.2)));
+    ///
+    /// assert!(f64::abs(v.total_unc() - 5.0) <= f64::EPSILON);
+    /// ```
+    pub fn total_unc(&self) -> V
+    where
+        V: Clone,
+        U: UncTotal<V>,
+    {
+        self.unc.total(self.val.clone())
+    }
+
+    /// Returns the interval covered by `k` times the uncertainty (see [`UncInterval`]).
+    ///
+    /// ```
+    /// use val_unc::{BoundUnc, Unc, ValUnc};
+    ///
+    /// let v = ValUnc::new(10.0, Unc(0.5));
+    /// assert_eq!(v.interval(2.0), (9.0, 11.0));
+    /// assert!(v.contains(&10.8, 2.0));
+    /// assert!(!v.contains(&10.8, 1.0));
+    ///
+    /// // Bounds are guaranteed, so `k` is ignored
+    /// let b = ValUnc::new(10.0, BoundUnc(0.5));
+    /// assert_eq!(b.upper(2.0), 10.5);
+    /// ```
+    pub fn interval(&self, k: V) -> (V, V)
+    where
+        V: Clone,
+        U: UncInterval<V>,
+    {
+        self.unc.interval(self.val.clone(), k)
+    }
+
+    /// Returns the lower bound of [`interval`](Self::interval).
+    pub fn lower(&self, k: V) -> V
+    where
+        V: Clone,
+        U: UncInterval<V>,
+    {
+        self.interval(k).0
+    }
+
+    /// Returns the upper bound of [`interval`](Self::interval).
+    pub fn upper(&self, k: V) -> V
+    where
+        V: Clone,
+        U: UncInterval<V>,
+    {
+        self.interval(k).1
+    }
+
+    /// Returns `true` if `value` is in [`interval`](Self::interval), including the bounds.
+    pub fn contains(&self, value: &V, k: V) -> bool
+    where
+        V: Clone + PartialOrd,
+        U: UncInterval<V>,
+    {
+        let (lower, upper) = self.interval(k);
+        lower <= *value && *value <= upper
+    }
+
+    /// Returns the interval covering the fraction `confidence`, e.g. 0.95, of the distribution
+    /// (see [`UncCoverage`]).
+    ///
+    /// ```
+    /// use val_unc::{DofUnc, Unc, ValUnc};
+    ///
+    /// let (_, upper) = ValUnc::new(10.0, Unc(0.5)).at_confidence(0.95);
+    /// assert!(f64::abs(upper - 10.98) <= 1e-3);
+    ///
+    /// // With few degrees of freedom, the interval is wider
+    /// let (_, upper) = ValUnc::new(10.0, DofUnc::new(0.5, 4.0)).at_confidence(0.95);
+    /// assert!(f64::abs(upper - 11.388) <= 1e-3);
+    /// ```
+    pub fn at_confidence(&self, confidence: f64) -> (V, V)
+    where
+        V: Float,
+        U: UncInterval<V> + UncCoverage,
+    {
+        let k = self
+            .unc
+            .coverage_factor(self.val.to_f64().unwrap_or(f64::NAN), confidence);
+        // Every float can represent (an approximation of) any other float
+        self.interval(<V as num_traits::NumCast>::from(k).unwrap())
+    }
+
+    /// Returns the component of the uncertainty with type `T` (see [`GetUnc`]).
+    ///
+    /// ```
+    /// use val_unc::{RelUnc, Unc, ValUnc};
+    ///
+    /// let v = ValUnc::new(2.0, (Unc(0.5), RelUnc(0.1)));
+    ///
+    /// assert_eq!(v.unc_component::<RelUnc<f64>, _>(), &RelUnc(0.1));
+    /// ```
+    pub fn unc_component<T, I>(&self) -> &T
+    where
+        U: GetUnc<T, I>,
+    {
+        self.unc.get_unc()
+    }
+
+    /// Mutably returns the component of the uncertainty with type `T` (see [`GetUnc`]).
+    pub fn unc_component_mut<T, I>(&mut self) -> &mut T
+    where
+        U: GetUnc<T, I>,
+    {
+        self.unc.get_unc_mut()
+    }
+
+    /// Applies `f` to the value, keeping the uncertainty.
+    ///
+    /// Note that the uncertainty is not propagated through `f`, so this is meant for e.g. unit
+    /// conversions where the uncertainty is converted separately. See [`propagate`] otherwise.
+    ///
+    /// [`propagate`]: crate::propagate()
+    pub fn map_val<V2, F>(self, f: F) -> ValUnc<V2, U>
+    where
+        F: FnOnce(V) -> V2,
+    {
+        ValUnc::new(f(self.val), self.unc)
+    }
+
+    /// Applies `f` to the uncertainty, keeping the value.
+    pub fn map_unc<U2, F>(self, f: F) -> ValUnc<V, U2>
+    where
+        F: FnOnce(U) -> U2,
+    {
+        ValUnc::new(self.val, f(self.unc))
+    }
+
+    /// Applies `f_val` to the value and `f_unc` to the uncertainty.
+    ///
+    /// ```
+    /// use val_unc::{Unc, ValUnc};
+    ///
+    /// let m = ValUnc::new(1.5, Unc(0.25));
+    /// let mm = m.map(|v| v * 1000.0, |u| u * 1000.0);
+    ///
+    /// assert_eq!(mm, ValUnc::new(1500.0, Unc(250.0)));
+    /// ```
+    pub fn map<V2, U2, F, G>(self, f_val: F, f_unc: G) -> ValUnc<V2, U2>
+    where
+        F: FnOnce(V) -> V2,
+        G: FnOnce(U) -> U2,
+    {
+        ValUnc::new(f_val(self.val), f_unc(self.unc))
+    }
+
+    /// Applies the fallible `f` to the value, keeping the uncertainty.
+    pub fn try_map_val<V2, E, F>(self, f: F) -> Result<ValUnc<V2, U>, E>
+    where
+        F: FnOnce(V) -> Result<V2, E>,
+    {
+        Ok(ValUnc::new(f(self.val)?, self.unc))
+    }
+
+    /// Applies the fallible `f` to the uncertainty, keeping the value.
+    pub fn try_map_unc<U2, E, F>(self, f: F) -> Result<ValUnc<V, U2>, E>
+    where
+        F: FnOnce(U) -> Result<U2, E>,
+    {
+        Ok(ValUnc::new(self.val, f(self.unc)?))
+    }
+
+    /// Applies the fallible `f_val` to the value and `f_unc` to the uncertainty.
+    pub fn try_map<V2, U2, E, F, G>(self, f_val: F, f_unc: G) -> Result<ValUnc<V2, U2>, E>
+    where
+        F: FnOnce(V) -> Result<V2, E>,
+        G: FnOnce(U) -> Result<U2, E>,
+    {
+        Ok(ValUnc::new(f_val(self.val)?, f_unc(self.unc)?))
+    }
 }
 
+/// Creates a `ValUnc` with the default, i.e. zero, uncertainty.
+///
+/// Trait methods can't be `const`, so use [`ValUnc::new`] in constants instead.
 impl<V, U> From<V> for ValUnc<V, U>
 where
     U: Default,
@@ -29,16 +284,37 @@ where
     }
 }
 
+/// Displays the value followed by each uncertainty component, e.g. `12.34 ± 0.05`. The precision
+/// applies to the value and to the uncertainties.
+///
+/// ```
+/// use val_unc::{Unc, ValUnc};
+///
+/// let v = ValUnc::new(12.3449, (Unc(0.0512), Unc(0.02)));
+/// assert_eq!(format!("{:.2}", v), "12.34 ± 0.05 ± 0.02");
+/// ```
+impl<V, U> fmt::Display for ValUnc<V, U>
+where
+    V: fmt::Display,
+    U: UncDisplay<V>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.val, f)?;
+        self.unc.fmt_unc(&self.val, f)
+    }
+}
+
 impl<V, U> Add for ValUnc<V, U>
 where
-    V: Add<V, Output = V> + Copy,
+    V: Add<V, Output = V> + Clone,
     U: UncAdd<V>,
 {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
+        let val = self.val.clone().add(other.val.clone());
         Self {
-            val: self.val.add(other.val),
+            val,
             unc: self.unc.unc_add(self.val, other.unc, other.val),
         }
     }
@@ -46,14 +322,14 @@ where
 
 impl<V, U> Div for ValUnc<V, U>
 where
-    V: Div<V, Output = V> + Copy,
+    V: Div<V, Output = V> + Clone,
     U: UncDiv<V>,
 {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
         Self {
-            val: self.val.div(other.val),
+            val: self.val.clone().div(other.val.clone()),
             unc: self.unc.unc_div(self.val, other.unc, other.val),
         }
     }
@@ -61,14 +337,14 @@ where
 
 impl<V, U> Mul for ValUnc<V, U>
 where
-    V: Mul<V, Output = V> + Copy,
+    V: Mul<V, Output = V> + Clone,
     U: UncMul<V>,
 {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
         Self {
-            val: self.val.mul(other.val),
+            val: self.val.clone().mul(other.val.clone()),
             unc: self.unc.unc_mul(self.val, other.unc, other.val),
         }
     }
@@ -76,14 +352,14 @@ where
 
 impl<V, U> Neg for ValUnc<V, U>
 where
-    V: Neg<Output = V> + Copy,
+    V: Neg<Output = V> + Clone,
     U: UncNeg<V>,
 {
     type Output = Self;
 
     fn neg(self) -> Self {
         Self {
-            val: self.val.neg(),
+            val: self.val.clone().neg(),
             unc: self.unc.unc_neg(self.val),
         }
     }
@@ -91,15 +367,223 @@ where
 
 impl<V, U> Sub for ValUnc<V, U>
 where
-    V: Sub<V, Output = V> + Copy,
+    V: Sub<V, Output = V> + Clone,
     U: UncSub<V>,
 {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
+        let val = self.val.clone().sub(other.val.clone());
         Self {
-            val: self.val.sub(other.val),
+            val,
             unc: self.unc.unc_sub(self.val, other.unc, other.val),
         }
     }
 }
+
+// This implements the binary operators for references, by cloning the operands, so that values
+// that aren't `Copy`, e.g. arbitrary precision floats, can be used without explicit clones.
+macro_rules! ref_ops {
+    ($(($Tr:ident, $f:ident)),+) => {$(
+        impl<V, U> $Tr<&ValUnc<V, U>> for &ValUnc<V, U>
+        where
+            ValUnc<V, U>: $Tr<Output = ValUnc<V, U>> + Clone,
+        {
+            type Output = ValUnc<V, U>;
+
+            fn $f(self, other: &ValUnc<V, U>) -> ValUnc<V, U> {
+                self.clone().$f(other.clone())
+            }
+        }
+    )+}
+}
+
+ref_ops!((Add, add), (Div, div), (Mul, mul), (Sub, sub));
+
+impl<V, U> Neg for &ValUnc<V, U>
+where
+    ValUnc<V, U>: Neg<Output = ValUnc<V, U>> + Clone,
+{
+    type Output = ValUnc<V, U>;
+
+    fn neg(self) -> ValUnc<V, U> {
+        self.clone().neg()
+    }
+}
+
+// This implements the compound assignment operators in terms of the binary operators, e.g. for
+// generic code like `nalgebra`'s matrix multiplication that accumulates with `+=`.
+macro_rules! assign_ops {
+    ($(($Tr:ident, $f:ident, $Op:ident, $op:ident)),+) => {$(
+        impl<V, U> $Tr for ValUnc<V, U>
+        where
+            ValUnc<V, U>: $Op<Output = ValUnc<V, U>> + Clone,
+        {
+            fn $f(&mut self, other: Self) {
+                *self = self.clone().$op(other);
+            }
+        }
+    )+}
+}
+
+assign_ops!(
+    (AddAssign, add_assign, Add, add),
+    (DivAssign, div_assign, Div, div),
+    (MulAssign, mul_assign, Mul, mul),
+    (SubAssign, sub_assign, Sub, sub)
+);
+
+impl<V, U> Zero for ValUnc<V, U>
+where
+    V: Zero + Clone,
+    U: UncAdd<V> + UncZero,
+{
+    fn zero() -> Self {
+        Self::new(V::zero(), U::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.val.is_zero() && self.unc.is_zero()
+    }
+}
+
+impl<V, U> One for ValUnc<V, U>
+where
+    V: One + Clone,
+    U: UncMul<V> + UncZero,
+{
+    fn one() -> Self {
+        Self::new(V::one(), U::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DofUnc, UncMap, ZeroUnc};
+    use core::iter::FromIterator;
+
+    #[test]
+    fn display() {
+        assert_eq!(ValUnc::new(1.5, Unc(0.25)).to_string(), "1.5 ± 0.25");
+        assert_eq!(ValUnc::new(1.5, ()).to_string(), "1.5");
+        assert_eq!(
+            format!("{:.1}", ValUnc::new(1.5, (Unc(0.25), ZeroUnc))),
+            "1.5 ± 0.2"
+        );
+
+        let map = UncMap::from_iter(vec![
+            ("stat".to_string(), Unc(0.1)),
+            ("sys".to_string(), Unc(0.2)),
+        ]);
+        assert_eq!(
+            ValUnc::new(1.0, map).to_string(),
+            "1 ± 0.1 (stat) ± 0.2 (sys)"
+        );
+    }
+
+    #[test]
+    fn default_unc() {
+        let v: ValUnc<f64> = ValUnc::new(1.5, Unc(0.25));
+        let s: SimpleValUnc<f64> = v;
+        assert_eq!(s + v, ValUnc::new(3.0, Unc(f64::sqrt(0.125))));
+    }
+
+    #[test]
+    fn const_table() {
+        const TABLE: [ValUnc<f64, (Unc<f64>, DofUnc)>; 2] = [
+            ValUnc::new(1.0, (Unc(0.1), DofUnc::new(0.2, 4.0))),
+            ValUnc::new(2.0, (Unc(0.3), DofUnc::new(0.1, f64::INFINITY))),
+        ];
+        static EXACT: ValUnc<f64> = val_unc!(3.0 + -0.0);
+
+        assert_eq!(TABLE[0].as_ref().val, &1.0);
+        assert_eq!(EXACT.unc, Unc(0.0));
+    }
+
+    #[test]
+    fn at_confidence_nan() {
+        let (lower, upper) = ValUnc::new(1.0, Unc(0.1)).at_confidence(f64::NAN);
+        assert!(f64::is_nan(lower) && f64::is_nan(upper));
+        let (lower, upper) = ValUnc::new(1.0, DofUnc::new(0.1, 4.0)).at_confidence(f64::NAN);
+        assert!(f64::is_nan(lower) && f64::is_nan(upper));
+    }
+
+    #[test]
+    fn not_copy() {
+        // A stand-in for a heap-allocated, arbitrary precision value
+        #[derive(Debug, Clone, PartialEq)]
+        struct Big(Box<f64>);
+
+        impl Add for Big {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Big(Box::new(*self.0 + *other.0))
+            }
+        }
+
+        impl Mul for Big {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Big(Box::new(*self.0 * *other.0))
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct BigUnc(Box<f64>);
+
+        impl UncAdd<Big> for BigUnc {
+            fn unc_add(self, _self_val: Big, other: Self, _other_val: Big) -> Self {
+                BigUnc(Box::new(f64::hypot(*self.0, *other.0)))
+            }
+        }
+
+        impl UncMul<Big> for BigUnc {
+            fn unc_mul(self, self_val: Big, other: Self, other_val: Big) -> Self {
+                BigUnc(Box::new(f64::hypot(
+                    *self.0 * *other_val.0,
+                    *other.0 * *self_val.0,
+                )))
+            }
+        }
+
+        let a = ValUnc::new(Big(Box::new(3.0)), (BigUnc(Box::new(0.3)),));
+        let b = ValUnc::new(Big(Box::new(4.0)), (BigUnc(Box::new(0.4)),));
+
+        assert_eq!(
+            &a + &b,
+            ValUnc::new(Big(Box::new(7.0)), (BigUnc(Box::new(0.5)),))
+        );
+        assert_eq!(
+            a * b,
+            ValUnc::new(
+                Big(Box::new(12.0)),
+                (BigUnc(Box::new(f64::hypot(1.2, 1.2))),)
+            )
+        );
+    }
+
+    #[test]
+    fn zero_one() {
+        let v = ValUnc::new(2.0, (Unc(0.5), Unc(0.25)));
+        type T = ValUnc<f64, (Unc<f64>, Unc<f64>)>;
+
+        assert!(T::zero().is_zero());
+        assert!(!v.is_zero());
+        assert_eq!(v + T::zero(), v);
+        assert_eq!(v * T::one(), v);
+        assert_eq!(
+            vec![v, v].into_iter().fold(T::zero(), |a, b| a + b).val,
+            4.0
+        );
+
+        let mut w = v;
+        w += v;
+        w -= v;
+        w *= T::one();
+        w /= T::one();
+        assert_eq!(w.val, v.val);
+    }
+}
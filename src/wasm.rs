@@ -0,0 +1,136 @@
+//! A JavaScript API for `ValUnc`s with `wasm-bindgen`
+//!
+//! [`JsValUnc`] wraps a `ValUnc<f64, Unc<f64>>` and is exported to JavaScript as the class
+//! `ValUnc`, with `val` and `unc` getters, methods for the arithmetic, parsing with
+//! [`parse_val_unc`], and formatting with `Display` or an [`UncFormatter`].
+//!
+//! ```js
+//! import { ValUnc } from "./val_unc.js";
+//!
+//! const a = new ValUnc(1.5, 0.3);
+//! const b = ValUnc.parse("2.0(4)");
+//! console.log(a.add(b).format(false)); // "3.5 ± 0.5"
+//! ```
+
+use crate::{
+    fmt::{Notation, UncFormatter},
+    parse::parse_val_unc,
+    unc::Unc,
+    val_unc::ValUnc,
+};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+/// A `ValUnc<f64, Unc<f64>>` for JavaScript
+///
+/// See the [module documentation](self).
+#[wasm_bindgen(js_name = ValUnc)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JsValUnc(ValUnc<f64, Unc<f64>>);
+
+// The arithmetic methods are the JavaScript API, since it doesn't have operator overloading.
+#[allow(clippy::should_implement_trait)]
+#[wasm_bindgen(js_class = ValUnc)]
+impl JsValUnc {
+    #[wasm_bindgen(constructor)]
+    pub fn new(val: f64, unc: f64) -> Self {
+        Self(ValUnc::new(val, Unc(unc)))
+    }
+
+    /// Parses a `ValUnc` like `1.23 ± 0.04` or `1.23(4)` with [`parse_val_unc`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` isn't a valid `ValUnc`.
+    pub fn parse(s: &str) -> Result<JsValUnc, JsError> {
+        Ok(Self(parse_val_unc(s)?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn val(&self) -> f64 {
+        self.0.val
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn unc(&self) -> f64 {
+        self.0.unc.0
+    }
+
+    pub fn add(&self, other: &JsValUnc) -> JsValUnc {
+        Self(self.0 + other.0)
+    }
+
+    pub fn sub(&self, other: &JsValUnc) -> JsValUnc {
+        Self(self.0 - other.0)
+    }
+
+    pub fn mul(&self, other: &JsValUnc) -> JsValUnc {
+        Self(self.0 * other.0)
+    }
+
+    pub fn div(&self, other: &JsValUnc) -> JsValUnc {
+        Self(self.0 / other.0)
+    }
+
+    pub fn neg(&self) -> JsValUnc {
+        Self(-self.0)
+    }
+
+    /// Formats the value and the uncertainty as they are, e.g. `1.2345 ± 0.0671`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Formats the value rounded to the precision of the uncertainty (see [`UncFormatter`]),
+    /// e.g. `1.23 ± 0.07`, or `1.23(7)` if `parenthesis` is `true`.
+    pub fn format(&self, parenthesis: bool) -> String {
+        let notation = if parenthesis {
+            Notation::Parenthesis
+        } else {
+            Notation::PlusMinus
+        };
+        UncFormatter::new()
+            .notation(notation)
+            .display(&self.0)
+            .to_string()
+    }
+}
+
+impl From<ValUnc<f64, Unc<f64>>> for JsValUnc {
+    fn from(v: ValUnc<f64, Unc<f64>>) -> Self {
+        Self(v)
+    }
+}
+
+impl From<JsValUnc> for ValUnc<f64, Unc<f64>> {
+    fn from(v: JsValUnc) -> Self {
+        v.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = JsValUnc::new(1.5, 0.3);
+        let b = JsValUnc::parse("2.0(4)").unwrap();
+        assert_eq!((b.val(), b.unc()), (2.0, 0.4));
+
+        let sum = a.add(&b);
+        assert_eq!((sum.val(), sum.unc()), (3.5, 0.5));
+        assert_eq!(a.sub(&b).add(&b).val(), 1.5);
+        assert_eq!(a.neg().val(), -1.5);
+        assert_eq!(a.mul(&b).0, a.0 * b.0);
+        assert_eq!(a.div(&b).0, a.0 / b.0);
+    }
+
+    #[test]
+    fn format() {
+        let v = JsValUnc::new(1.234_567_8, 0.000_067_1);
+        assert_eq!(v.to_js_string(), v.0.to_string());
+        assert_eq!(v.format(false), "1.234568 ± 0.000067");
+        assert_eq!(v.format(true), "1.234568(67)");
+    }
+}
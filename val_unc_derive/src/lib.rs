@@ -0,0 +1,236 @@
+//! Derive macros for [`val_unc`](https://docs.rs/val_unc).
+//!
+//! These are re-exported by `val_unc` when its `derive` feature is enabled, and should be used
+//! from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Member};
+
+/// Implements `UncAdd`, `UncDiv`, `UncMul`, `UncNeg`, `UncSub`, and `UncValidate` for a struct by
+/// applying them to each field.
+///
+/// `UncDisplay` is also implemented, displaying each field, labeled by its name for named structs,
+/// e.g. `1.2 ± 0.1 (stat) ± 0.2 (sys)`.
+///
+/// `UncZero` is only implemented with the `#[unc(zero)]` attribute, since not every uncertainty
+/// type implements it, and a struct with a field that doesn't would fail to compile.
+///
+/// Every field type must implement the traits being used.
+#[proc_macro_derive(UncOps, attributes(unc))]
+pub fn derive_unc_ops(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match unc_ops(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn unc_ops(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unit => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "UncOps can not be derived for unit structs",
+                ))
+            }
+            fields => fields,
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "UncOps can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut derive_zero = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("unc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("zero") {
+                derive_zero = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported unc attribute, expected `zero`"))
+            }
+        })?;
+    }
+
+    let members: Vec<Member> = fields.members().collect();
+    let tys: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+
+    let binary = |tr: &str| {
+        let tr = format_ident!("{}", tr);
+        let method = format_ident!("unc_{}", tr.to_string()[3..].to_lowercase());
+        let ident = &input.ident;
+        let mut generics = input.generics.clone();
+        generics.params.push(parse_quote!(__V));
+        {
+            let where_clause = generics.make_where_clause();
+            where_clause.predicates.push(parse_quote!(__V: Clone));
+            for ty in &tys {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::val_unc::#tr<__V>));
+            }
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::val_unc::#tr<__V> for #ident #ty_generics #where_clause {
+                fn #method(self, self_val: __V, other: Self, other_val: __V) -> Self {
+                    Self {
+                        #(#members: ::val_unc::#tr::#method(
+                            self.#members,
+                            ::core::clone::Clone::clone(&self_val),
+                            other.#members,
+                            ::core::clone::Clone::clone(&other_val),
+                        ),)*
+                    }
+                }
+            }
+        }
+    };
+
+    let add = binary("UncAdd");
+    let div = binary("UncDiv");
+    let mul = binary("UncMul");
+    let sub = binary("UncSub");
+
+    let ident = &input.ident;
+
+    let neg = {
+        let mut generics = input.generics.clone();
+        generics.params.push(parse_quote!(__V));
+        {
+            let where_clause = generics.make_where_clause();
+            where_clause.predicates.push(parse_quote!(__V: Clone));
+            for ty in &tys {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::val_unc::UncNeg<__V>));
+            }
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::val_unc::UncNeg<__V> for #ident #ty_generics #where_clause {
+                fn unc_neg(self, self_val: __V) -> Self {
+                    Self {
+                        #(#members: ::val_unc::UncNeg::unc_neg(
+                            self.#members,
+                            ::core::clone::Clone::clone(&self_val),
+                        ),)*
+                    }
+                }
+            }
+        }
+    };
+
+    let zero = if derive_zero {
+        let mut generics = input.generics.clone();
+        {
+            let where_clause = generics.make_where_clause();
+            for ty in &tys {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::val_unc::UncZero));
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::val_unc::UncZero for #ident #ty_generics #where_clause {
+                fn zero() -> Self {
+                    Self {
+                        #(#members: ::val_unc::UncZero::zero(),)*
+                    }
+                }
+
+                fn is_zero(&self) -> bool {
+                    true #(&& ::val_unc::UncZero::is_zero(&self.#members))*
+                }
+
+                fn set_zero(&mut self) {
+                    #(::val_unc::UncZero::set_zero(&mut self.#members);)*
+                }
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let validate = {
+        let mut generics = input.generics.clone();
+        {
+            let where_clause = generics.make_where_clause();
+            for ty in &tys {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::val_unc::UncValidate));
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::val_unc::UncValidate for #ident #ty_generics #where_clause {
+                fn is_valid(&self) -> bool {
+                    true #(&& ::val_unc::UncValidate::is_valid(&self.#members))*
+                }
+
+                fn clamp_invalid(&mut self) {
+                    #(::val_unc::UncValidate::clamp_invalid(&mut self.#members);)*
+                }
+            }
+        }
+    };
+
+    let display = {
+        let mut generics = input.generics.clone();
+        generics.params.push(parse_quote!(__V));
+        {
+            let where_clause = generics.make_where_clause();
+            for ty in &tys {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::val_unc::UncDisplay<__V>));
+            }
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let fields = members.iter().map(|member| match member {
+            Member::Named(name) => {
+                let label = name.to_string();
+                quote! {
+                    ::val_unc::traits::display::fmt_labeled(&self.#member, val, #label, f)?;
+                }
+            }
+            Member::Unnamed(_) => quote! {
+                ::val_unc::UncDisplay::fmt_unc(&self.#member, val, f)?;
+            },
+        });
+        quote! {
+            impl #impl_generics ::val_unc::UncDisplay<__V> for #ident #ty_generics #where_clause {
+                fn fmt_unc(
+                    &self,
+                    val: &__V,
+                    f: &mut ::core::fmt::Formatter,
+                ) -> ::core::fmt::Result {
+                    #(#fields)*
+                    ::core::result::Result::Ok(())
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #add
+        #display
+        #div
+        #mul
+        #neg
+        #sub
+        #validate
+        #zero
+    })
+}
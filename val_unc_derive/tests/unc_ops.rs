@@ -0,0 +1,94 @@
+use val_unc::{BoundUnc, Unc, UncNeg, UncOps, UncZero, ValUnc};
+
+#[derive(Debug, Clone, Copy, PartialEq, UncOps)]
+#[unc(zero)]
+struct MyUnc<T> {
+    stat: Unc<T>,
+    sys: BoundUnc<T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, UncOps)]
+struct TupleUnc(BoundUnc<f64>, BoundUnc<f64>);
+
+// Without `#[unc(zero)]`, `UncZero` isn't implemented, so the fields don't need to implement it
+#[derive(Debug, Clone, Copy, PartialEq, UncOps)]
+struct NestedUnc {
+    inner: TupleUnc,
+}
+
+#[test]
+fn named() {
+    let v1 = ValUnc::new(
+        10.2,
+        MyUnc {
+            stat: Unc(4.0),
+            sys: BoundUnc(1.25),
+        },
+    );
+    let v2 = ValUnc::new(
+        8.5,
+        MyUnc {
+            stat: Unc(3.0),
+            sys: BoundUnc(1.25),
+        },
+    );
+    let ValUnc {
+        val,
+        unc: MyUnc { stat, sys },
+    } = v1 + v2;
+
+    assert!(f64::abs(val - 18.7) <= f64::EPSILON);
+    assert!(f64::abs(stat.0 - 5.0) <= f64::EPSILON);
+    assert!(f64::abs(sys.0 - 2.5) <= f64::EPSILON);
+
+    let ValUnc { unc, .. } = v1 - v2;
+    assert!(f64::abs(unc.stat.0 - 5.0) <= f64::EPSILON);
+    assert!(f64::abs(unc.sys.0 - 2.5) <= f64::EPSILON);
+
+    assert_eq!(format!("{:.1}", v1 + v2), "18.7 ± 5.0 (stat) ± 2.5 (sys)");
+
+    let mut zero = MyUnc::<f64>::zero();
+    assert!(zero.is_zero());
+    zero.stat = Unc(1.0);
+    assert!(!zero.is_zero());
+    zero.set_zero();
+    assert!(zero.is_zero());
+}
+
+#[test]
+fn tuple() {
+    let v1 = ValUnc::new(4.0, TupleUnc(BoundUnc(1.0), BoundUnc(0.0)));
+    let v2 = ValUnc::new(2.0, TupleUnc(BoundUnc(0.5), BoundUnc(1.0)));
+
+    let ValUnc { unc, .. } = v1 * v2;
+    assert!(f64::abs(unc.0 .0 - 4.5) <= f64::EPSILON);
+    assert!(f64::abs(unc.1 .0 - 4.0) <= f64::EPSILON);
+
+    let ValUnc { unc, .. } = v1 / v2;
+    assert!(unc.0 .0 > 0.0);
+
+    assert_eq!(v1.unc.unc_neg(v1.val), v1.unc);
+    assert_eq!(v1.to_string(), "4 ± 1 ± 0");
+}
+
+#[test]
+fn nested() {
+    let v1 = ValUnc::new(
+        4.0,
+        NestedUnc {
+            inner: TupleUnc(BoundUnc(1.0), BoundUnc(0.0)),
+        },
+    );
+    let v2 = ValUnc::new(
+        2.0,
+        NestedUnc {
+            inner: TupleUnc(BoundUnc(0.5), BoundUnc(1.0)),
+        },
+    );
+
+    assert_eq!(
+        (v1 + v2).unc.inner,
+        (ValUnc::new(4.0, v1.unc.inner) + ValUnc::new(2.0, v2.unc.inner)).unc
+    );
+    assert_eq!(v1.to_string(), "4 ± 1 ± 0 (inner)");
+}